@@ -12,14 +12,28 @@ struct AsciiSettings {
     cell_size: vec2<f32>,
     // Screen resolution
     resolution: vec2<f32>,
-    // 0 = colored, 1 = monochrome green
+    // 0 = colored, 1 = monochrome (tinted with mono_color)
     monochrome: f32,
+    // Monochrome tint (linear RGB)
+    mono_color: vec3<f32>,
     // 0 = global pattern, 1 = per-object patterns
     per_object_mode: f32,
-    // Global pattern ID (0-3) used when per_object_mode is 0
+    // Bitmask of object categories that use their own pattern in per-object mode
+    per_object_mask: f32,
+    // Global pattern ID (0-6) used when per_object_mode is 0
     global_pattern: f32,
     // Animation time in seconds
     time: f32,
+    // Sobel outline strength, 0 = edge detection off
+    edge_strength: f32,
+    // CRT scanline darkening, 0 = off
+    scanline_intensity: f32,
+    // CRT barrel distortion, 0 = flat
+    curvature: f32,
+    // Number of glyphs in custom_ramp
+    custom_ramp_len: f32,
+    // Custom ramp glyphs: x = rows 0-5 (5 bits each), y = row 6
+    custom_ramp: array<vec4<u32>, 16>,
 }
 @group(0) @binding(2) var<uniform> settings: AsciiSettings;
 
@@ -120,8 +134,11 @@ fn get_char_pixel_pattern2(char_index: u32, local_x: u32, local_y: u32) -> f32 {
     return f32((row_bits >> bit_pos) & 1u);
 }
 
-// Pattern 3: Binary/Digital (0s and 1s feel)
-fn get_char_pixel_pattern3(char_index: u32, local_x: u32, local_y: u32) -> f32 {
+// Binary digits scroll down this many cells per second
+const BINARY_SCROLL_SPEED: f32 = 6.0;
+
+// Pattern 3: Binary/Digital (0s and 1s feel) - digits scroll downward over time
+fn get_char_pixel_pattern3(char_index: u32, local_x: u32, local_y: u32, cell_x: f32, cell_y: f32, time: f32) -> f32 {
     // 0, 1, and digital patterns
     let char0 = array<u32, 7>(0u, 0u, 0u, 0u, 0u, 0u, 0u);           // empty
     let char1 = array<u32, 7>(0u, 4u, 4u, 4u, 4u, 0u, 4u);           // !
@@ -134,16 +151,28 @@ fn get_char_pixel_pattern3(char_index: u32, local_x: u32, local_y: u32) -> f32 {
     let char8 = array<u32, 7>(14u, 17u, 31u, 17u, 17u, 17u, 14u);    // 8
     let char9 = array<u32, 7>(31u, 31u, 31u, 31u, 31u, 31u, 31u);    // solid
 
+    // Digits (2-8) are shifted along the set by a per-column offset that grows with time and
+    // shrinks down the column, so each digit appears to move down a cell at a time.
+    // Empty, "!" and solid stay put so brightness still reads the same.
+    var glyph = char_index;
+    if char_index >= 2u && char_index <= 8u {
+        let column_offset = floor(fract(sin(cell_x * 12.9898) * 43758.5453) * 7.0);
+        let scroll = floor(time * BINARY_SCROLL_SPEED - cell_y) + column_offset;
+        // Positive modulo - scroll is negative near the bottom of the screen early on
+        let shift = u32(scroll - 7.0 * floor(scroll / 7.0));
+        glyph = 2u + (char_index - 2u + shift) % 7u;
+    }
+
     var row_bits: u32 = 0u;
-    if char_index == 0u { row_bits = char0[local_y]; }
-    else if char_index == 1u { row_bits = char1[local_y]; }
-    else if char_index == 2u { row_bits = char2[local_y]; }
-    else if char_index == 3u { row_bits = char3[local_y]; }
-    else if char_index == 4u { row_bits = char4[local_y]; }
-    else if char_index == 5u { row_bits = char5[local_y]; }
-    else if char_index == 6u { row_bits = char6[local_y]; }
-    else if char_index == 7u { row_bits = char7[local_y]; }
-    else if char_index == 8u { row_bits = char8[local_y]; }
+    if glyph == 0u { row_bits = char0[local_y]; }
+    else if glyph == 1u { row_bits = char1[local_y]; }
+    else if glyph == 2u { row_bits = char2[local_y]; }
+    else if glyph == 3u { row_bits = char3[local_y]; }
+    else if glyph == 4u { row_bits = char4[local_y]; }
+    else if glyph == 5u { row_bits = char5[local_y]; }
+    else if glyph == 6u { row_bits = char6[local_y]; }
+    else if glyph == 7u { row_bits = char7[local_y]; }
+    else if glyph == 8u { row_bits = char8[local_y]; }
     else { row_bits = char9[local_y]; }
 
     let bit_pos = 4u - local_x;
@@ -259,6 +288,21 @@ fn get_char_pixel_pattern5(char_index: u32, local_x: u32, local_y: u32, cell_x:
     return pixel * trail_brightness;
 }
 
+// Pattern 6: Custom ramp uploaded from CustomRamp
+fn get_char_pixel_custom(char_index: u32, local_x: u32, local_y: u32) -> f32 {
+    // Spread the 10 brightness levels over however many glyphs the ramp has
+    let count = max(u32(settings.custom_ramp_len), 1u);
+    let glyph_index = min(char_index * count / 9u, count - 1u);
+    let glyph = settings.custom_ramp[glyph_index];
+
+    var row_bits: u32 = 0u;
+    if local_y < 6u { row_bits = (glyph.x >> (local_y * 5u)) & 31u; }
+    else { row_bits = glyph.y & 31u; }
+
+    let bit_pos = 4u - local_x;
+    return f32((row_bits >> bit_pos) & 1u);
+}
+
 // Get pixel from character bitmap based on pattern ID
 fn get_char_pixel(pattern_id: u32, char_index: u32, local_x: u32, local_y: u32, cell_x: f32, cell_y: f32, time: f32) -> f32 {
     let clamped_x = min(local_x, 4u);
@@ -269,11 +313,13 @@ fn get_char_pixel(pattern_id: u32, char_index: u32, local_x: u32, local_y: u32,
     } else if pattern_id == 2u {
         return get_char_pixel_pattern2(char_index, clamped_x, clamped_y);
     } else if pattern_id == 3u {
-        return get_char_pixel_pattern3(char_index, clamped_x, clamped_y);
+        return get_char_pixel_pattern3(char_index, clamped_x, clamped_y, cell_x, cell_y, time);
     } else if pattern_id == 4u {
         return get_char_pixel_pattern4(char_index, clamped_x, clamped_y, cell_x, cell_y, time);
     } else if pattern_id == 5u {
         return get_char_pixel_pattern5(char_index, clamped_x, clamped_y, cell_x, cell_y, time);
+    } else if pattern_id == 6u {
+        return get_char_pixel_custom(char_index, clamped_x, clamped_y);
     } else {
         return get_char_pixel_pattern0(char_index, clamped_x, clamped_y);
     }
@@ -351,25 +397,63 @@ fn luminance(color: vec3<f32>) -> f32 {
     return dot(color, vec3<f32>(0.299, 0.587, 0.114));
 }
 
+// Cell size stretched so a whole number of cells tiles the screen exactly -
+// fractional cell sizes stay aligned instead of leaving a partial cell at the edges
+fn aligned_cell_size() -> vec2<f32> {
+    let cells = max(floor(settings.resolution / settings.cell_size), vec2<f32>(1.0));
+    return settings.resolution / cells;
+}
+
+// Sobel gradient magnitude over the 3x3 neighbouring cells
+fn cell_edge(cell_center_uv: vec2<f32>, cell_size: vec2<f32>) -> f32 {
+    let step = cell_size / settings.resolution;
+    var lum: array<f32, 9>;
+    for (var y = 0; y < 3; y++) {
+        for (var x = 0; x < 3; x++) {
+            let offset = vec2<f32>(f32(x - 1), f32(y - 1)) * step;
+            lum[y * 3 + x] = luminance(textureSampleLevel(screen_texture, texture_sampler, cell_center_uv + offset, 0.0).rgb);
+        }
+    }
+
+    let gx = (lum[2] + 2.0 * lum[5] + lum[8]) - (lum[0] + 2.0 * lum[3] + lum[6]);
+    let gy = (lum[6] + 2.0 * lum[7] + lum[8]) - (lum[0] + 2.0 * lum[1] + lum[2]);
+    return clamp(length(vec2<f32>(gx, gy)), 0.0, 1.0);
+}
+
+// Barrel distortion: push UVs outward with distance from the screen center
+fn crt_curve(uv: vec2<f32>) -> vec2<f32> {
+    let centered = uv * 2.0 - 1.0;
+    let distorted = centered * (1.0 + settings.curvature * 0.25 * dot(centered, centered));
+    return distorted * 0.5 + 0.5;
+}
+
 @fragment
 fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
-    let pixel_coord = in.uv * settings.resolution;
+    var uv = in.uv;
+    if settings.curvature > 0.0 {
+        uv = crt_curve(in.uv);
+    }
+    // Blacked out past the curved screen edge (no early return - textureSample needs uniform control flow)
+    let on_screen = all(uv >= vec2<f32>(0.0)) && all(uv <= vec2<f32>(1.0));
+
+    let pixel_coord = uv * settings.resolution;
+    let cell_size = aligned_cell_size();
 
     // Which character cell are we in?
-    let cell_coord = floor(pixel_coord / settings.cell_size);
+    let cell_coord = floor(pixel_coord / cell_size);
 
     // Local position within the cell (0 to cell_size)
-    let local_pos = pixel_coord - cell_coord * settings.cell_size;
+    let local_pos = pixel_coord - cell_coord * cell_size;
 
     // Map to character bitmap coordinates (5x7)
-    let char_local_x = u32(local_pos.x / settings.cell_size.x * 5.0);
-    let char_local_y = u32(local_pos.y / settings.cell_size.y * 7.0);
+    let char_local_x = u32(local_pos.x / cell_size.x * 5.0);
+    let char_local_y = u32(local_pos.y / cell_size.y * 7.0);
 
     // Sample the center of this cell to get average color
-    let cell_center_uv = (cell_coord + 0.5) * settings.cell_size / settings.resolution;
+    let cell_center_uv = (cell_coord + 0.5) * cell_size / settings.resolution;
 
     // Sample multiple points for better average
-    let sample_offset = settings.cell_size / settings.resolution * 0.25;
+    let sample_offset = cell_size / settings.resolution * 0.25;
     var total_color = vec3<f32>(0.0);
     total_color += textureSample(screen_texture, texture_sampler, cell_center_uv).rgb;
     total_color += textureSample(screen_texture, texture_sampler, cell_center_uv + vec2(-sample_offset.x, -sample_offset.y)).rgb;
@@ -380,30 +464,48 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
 
     // Determine pattern ID
     var pattern_id: u32 = u32(settings.global_pattern);
+    var monochrome = settings.monochrome > 0.5;
 
     if settings.per_object_mode > 0.5 {
         // Per-object mode: sample pattern ID from pattern texture
-        // Pattern ID is encoded in the red channel as value / 6.0 (0-5 → 0.0-0.833)
+        // Pattern ID is encoded in the red channel as value / 6.0 (0-6 → 0.0-1.0)
         let pattern_sample = textureSample(pattern_texture, texture_sampler, cell_center_uv);
-        // Decode: multiply by 6 and round (add 0.5 for rounding)
-        pattern_id = u32(pattern_sample.r * 6.0 + 0.5);
+        // Category is encoded in the green channel as (category + 1) / 8, 0 = no object
+        let category = u32(pattern_sample.g * 8.0 + 0.5);
+        let mask = u32(settings.per_object_mask);
+        if category > 0u && ((mask >> (category - 1u)) & 1u) == 1u {
+            // Decode: multiply by 6 and round (add 0.5 for rounding)
+            pattern_id = u32(pattern_sample.r * 6.0 + 0.5);
+        }
+        // Objects flagged force_color (blue channel) stay colored whatever their category
+        if category > 0u && pattern_sample.b > 0.5 {
+            monochrome = false;
+        }
     }
 
     // Get brightness and map to character index (0-9)
     let brightness = luminance(avg_color);
     // Boost darker areas so patterns are visible even on dim surfaces
-    let boosted_brightness = pow(brightness, 0.7); // Gamma correction to lift shadows
+    var boosted_brightness = pow(brightness, 0.7); // Gamma correction to lift shadows
+
+    // Outlines: strong gradients get denser characters and a brighter color
+    var edge = 0.0;
+    if settings.edge_strength > 0.0 {
+        edge = cell_edge(cell_center_uv, cell_size) * settings.edge_strength;
+        boosted_brightness = max(boosted_brightness, edge);
+    }
+
     let char_index = u32(clamp(boosted_brightness * 10.0, 0.0, 9.0));
 
     // Render bitmap characters with scaled reference size
     // Smaller cells get proportionally smaller characters for that high-res feel
     var char_pixel: f32;
-    if settings.cell_size.x < 8.0 {
+    if cell_size.x < 8.0 {
         // Scale reference size based on cell size
         // Ultra (3x5) -> ~5x9, HighRes (5x9) -> ~6x11, approaching Classic (8x14)
         let min_ref = vec2<f32>(5.0, 9.0);   // Minimum readable size
         let max_ref = vec2<f32>(8.0, 14.0);  // Classic size
-        let t = (settings.cell_size.x - 3.0) / 5.0; // 0 at Ultra, 1 at Classic
+        let t = (cell_size.x - 3.0) / 5.0; // 0 at Ultra, 1 at Classic
         let reference_size = mix(min_ref, max_ref, clamp(t, 0.0, 1.0));
 
         let tiled_pos = fract(pixel_coord / reference_size) * reference_size;
@@ -416,18 +518,18 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
         char_pixel = get_char_pixel(pattern_id, char_index, char_local_x, char_local_y, cell_coord.x, cell_coord.y, settings.time);
     }
 
-    // Boost brightness for better visibility
-    let boosted_color = avg_color * 2.0;
+    // Boost brightness for better visibility (and more on outlines)
+    let boosted_color = avg_color * 2.0 * (1.0 + edge);
 
     // Output color
     var output_color: vec3<f32>;
     var bg_color: vec3<f32>;
 
-    if settings.monochrome > 0.5 {
-        // Classic green terminal look
-        let green = vec3<f32>(0.0, 1.0, 0.3);
-        output_color = green * char_pixel * brightness * 1.5;
-        bg_color = green * brightness * 0.1;
+    if monochrome {
+        // Single-color terminal look (green by default)
+        let tint = settings.mono_color;
+        output_color = tint * char_pixel * brightness * 1.5 * (1.0 + edge);
+        bg_color = tint * brightness * 0.1;
     } else {
         // Colored ASCII - use original color tinted by character
         // Higher contrast: brighter characters, darker background
@@ -438,5 +540,13 @@ fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
     // Background fill for non-character pixels
     output_color = mix(bg_color, output_color, char_pixel);
 
-    return vec4<f32>(output_color, 1.0);
+    // Scanlines on undistorted screen rows, so they stay one pixel tall at any cell size
+    if settings.scanline_intensity > 0.0 {
+        let row = u32(in.uv.y * settings.resolution.y);
+        if (row & 1u) == 1u {
+            output_color *= 1.0 - settings.scanline_intensity;
+        }
+    }
+
+    return vec4<f32>(select(vec3<f32>(0.0), output_color, on_screen), 1.0);
 }
@@ -5,6 +5,8 @@
 
 struct PatternIdUniform {
     pattern_id: f32,
+    category: f32,
+    force_color: f32,
 }
 
 @group(2) @binding(0) var<uniform> pattern: PatternIdUniform;
@@ -14,5 +16,8 @@ fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     // Encode pattern ID in red channel (0-5 mapped to 0.0-0.833 range)
     // We use 1/6 steps: 0=0.0, 1=0.167, 2=0.333, 3=0.5, 4=0.667, 5=0.833
     let pattern_value = pattern.pattern_id / 6.0;
-    return vec4<f32>(pattern_value, 0.0, 0.0, 1.0);
+    // Encode category in green channel as (category + 1) / 8 so that 0 means "no object"
+    let category_value = (pattern.category + 1.0) / 8.0;
+    // Force-color flag in the blue channel: 1 = skip monochrome for this object
+    return vec4<f32>(pattern_value, category_value, pattern.force_color, 1.0);
 }
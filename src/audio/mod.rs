@@ -0,0 +1,281 @@
+// Sound effects - synthesized at startup and played as one-shot audio entities
+
+use bevy::audio::{AddAudioSource, Volume};
+use bevy::prelude::*;
+
+use crate::player::{FootstepEvent, LandingEvent, PlayerCamera};
+use crate::rng::GameRng;
+use crate::GameState;
+
+mod synth;
+
+pub use synth::{SynthSound, Waveform};
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<SynthSound>()
+            .init_resource::<SoundEffects>()
+            .init_resource::<MasterVolume>()
+            .add_systems(
+                Update,
+                (add_spatial_listener, play_sound_emitters, play_movement_sounds.run_if(in_state(GameState::Playing))),
+            );
+    }
+}
+
+/// Volume multiplier applied to every sound effect (0 = muted, 1 = full)
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct MasterVolume(pub f32);
+
+impl Default for MasterVolume {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl MasterVolume {
+    /// Final playback volume for a sound at `volume`
+    pub fn scale(&self, volume: f32) -> Volume {
+        Volume::Linear(volume * self.0.clamp(0.0, 1.0))
+    }
+}
+
+/// Positional sound effects, played through a `SoundEmitter`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SoundEffect {
+    Machinegun,
+    Shotgun,
+    Rocket,
+    Grenade,
+    SwordSwing,
+    Explosion,
+    EnemyExplosion,
+}
+
+impl SoundEffect {
+    /// Volume before the master volume is applied
+    pub fn volume(&self) -> f32 {
+        match self {
+            SoundEffect::Machinegun => 0.35,
+            SoundEffect::Shotgun => 0.6,
+            SoundEffect::Rocket | SoundEffect::Grenade => 0.5,
+            SoundEffect::SwordSwing => 0.4,
+            SoundEffect::Explosion => 1.0,
+            SoundEffect::EnemyExplosion => 0.7,
+        }
+    }
+}
+
+/// Spawn with a `Transform` to play a sound effect from that position - the audio plugin
+/// attaches the audio player, and the entity despawns once the sound finishes
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SoundEmitter(pub SoundEffect);
+
+/// Handles to every sound effect
+#[derive(Resource)]
+pub struct SoundEffects {
+    pub footstep: Handle<SynthSound>,
+    pub land: Handle<SynthSound>,
+    pub machinegun: Handle<SynthSound>,
+    pub shotgun: Handle<SynthSound>,
+    pub rocket: Handle<SynthSound>,
+    pub grenade: Handle<SynthSound>,
+    pub sword_swing: Handle<SynthSound>,
+    pub explosion: Handle<SynthSound>,
+    pub enemy_explosion: Handle<SynthSound>,
+}
+
+impl SoundEffects {
+    pub fn get(&self, effect: SoundEffect) -> Handle<SynthSound> {
+        match effect {
+            SoundEffect::Machinegun => self.machinegun.clone(),
+            SoundEffect::Shotgun => self.shotgun.clone(),
+            SoundEffect::Rocket => self.rocket.clone(),
+            SoundEffect::Grenade => self.grenade.clone(),
+            SoundEffect::SwordSwing => self.sword_swing.clone(),
+            SoundEffect::Explosion => self.explosion.clone(),
+            SoundEffect::EnemyExplosion => self.enemy_explosion.clone(),
+        }
+    }
+}
+
+impl FromWorld for SoundEffects {
+    fn from_world(world: &mut World) -> Self {
+        let mut sounds = world.resource_mut::<Assets<SynthSound>>();
+        let mut add = |waveform, start_frequency, end_frequency, duration| {
+            sounds.add(SynthSound {
+                waveform,
+                start_frequency,
+                end_frequency,
+                duration,
+            })
+        };
+        Self {
+            // Short dull scuff
+            footstep: add(Waveform::Noise, 1800.0, 600.0, 0.07),
+            // Low thump
+            land: add(Waveform::Sine, 140.0, 45.0, 0.18),
+            // Tight buzzy crack
+            machinegun: add(Waveform::Square, 220.0, 70.0, 0.08),
+            // Wide noise blast
+            shotgun: add(Waveform::Noise, 3500.0, 300.0, 0.3),
+            // Launch whoosh
+            rocket: add(Waveform::Noise, 700.0, 200.0, 0.4),
+            // Hollow pop
+            grenade: add(Waveform::Sine, 320.0, 110.0, 0.12),
+            // Airy swish
+            sword_swing: add(Waveform::Noise, 5000.0, 1500.0, 0.15),
+            // Long low rumble
+            explosion: add(Waveform::Noise, 900.0, 40.0, 0.9),
+            // Shorter, brighter burst
+            enemy_explosion: add(Waveform::Noise, 1600.0, 120.0, 0.5),
+        }
+    }
+}
+
+/// Random playback speed around 1.0 so repeated sounds don't sound identical
+pub fn pitch_variation(rng: &mut GameRng) -> f32 {
+    rng.range(0.9, 1.1)
+}
+
+/// Positional sounds are heard from the player camera
+pub fn add_spatial_listener(
+    mut commands: Commands,
+    camera_query: Query<Entity, (With<PlayerCamera>, Without<SpatialListener>)>,
+) {
+    for camera in &camera_query {
+        commands.entity(camera).insert(SpatialListener::new(0.3));
+    }
+}
+
+/// Start playing newly spawned sound emitters, panned and attenuated by their position
+pub fn play_sound_emitters(
+    mut commands: Commands,
+    sounds: Res<SoundEffects>,
+    master_volume: Res<MasterVolume>,
+    emitter_query: Query<(Entity, &SoundEmitter), Added<SoundEmitter>>,
+    mut rng: ResMut<GameRng>,
+) {
+    for (entity, emitter) in &emitter_query {
+        commands.entity(entity).insert((
+            AudioPlayer(sounds.get(emitter.0)),
+            PlaybackSettings::DESPAWN
+                .with_spatial(true)
+                .with_volume(master_volume.scale(emitter.0.volume()))
+                .with_speed(pitch_variation(&mut rng)),
+        ));
+    }
+}
+
+/// Footsteps while walking, a thud on landing (louder for harder landings)
+pub fn play_movement_sounds(
+    mut commands: Commands,
+    sounds: Res<SoundEffects>,
+    master_volume: Res<MasterVolume>,
+    mut footsteps: EventReader<FootstepEvent>,
+    mut landings: EventReader<LandingEvent>,
+    mut rng: ResMut<GameRng>,
+) {
+    for _ in footsteps.read() {
+        commands.spawn((
+            AudioPlayer(sounds.footstep.clone()),
+            PlaybackSettings::DESPAWN
+                .with_volume(master_volume.scale(0.25))
+                .with_speed(pitch_variation(&mut rng)),
+        ));
+    }
+
+    for landing in landings.read() {
+        let volume = (landing.fall_speed / 20.0).clamp(0.2, 1.0);
+        commands.spawn((
+            AudioPlayer(sounds.land.clone()),
+            PlaybackSettings::DESPAWN
+                .with_volume(master_volume.scale(volume))
+                .with_speed(pitch_variation(&mut rng)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::player::movement::{MovementConfig, PlayerState, Velocity};
+    use crate::player::{update_view_sway, Ads, Player, PlayerCamera, ViewSway, FOOTSTEP_PHASE};
+
+    fn sound_count(app: &mut App, sound: &Handle<SynthSound>) -> usize {
+        let world = app.world_mut();
+        world
+            .query::<&AudioPlayer<SynthSound>>()
+            .iter(world)
+            .filter(|player| player.0 == *sound)
+            .count()
+    }
+
+    #[test]
+    fn test_footsteps_follow_view_bob() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(50))
+            .add_event::<FootstepEvent>()
+            .add_event::<LandingEvent>()
+            .init_resource::<Assets<SynthSound>>()
+            .init_resource::<SoundEffects>()
+            .init_resource::<MasterVolume>()
+            .init_resource::<MovementConfig>()
+            .insert_resource(GameRng::new(1))
+            .add_systems(Update, (update_view_sway, play_movement_sounds).chain());
+
+        app.world_mut().spawn((
+            Player,
+            Ads::default(),
+            Velocity(Vec3::new(5.0, 0.0, 0.0)),
+            PlayerState { grounded: true, ..default() },
+        ));
+        let camera = app
+            .world_mut()
+            .spawn((PlayerCamera::default(), ViewSway::default(), Transform::default()))
+            .id();
+        let footstep = app.world().resource::<SoundEffects>().footstep.clone();
+        let bob_time = |app: &App| app.world().get::<ViewSway>(camera).unwrap().bob_time;
+
+        // Walk for two seconds - bob_time grows 0.25 per frame
+        let mut steps = 0;
+        for _ in 0..40 {
+            app.update();
+            let expected = (bob_time(&app) / FOOTSTEP_PHASE) as usize;
+            assert_eq!(sound_count(&mut app, &footstep), expected);
+            steps = expected;
+        }
+        // Roughly three steps a second
+        assert!((5..=7).contains(&steps), "{steps} steps");
+
+        // No landing on flat ground
+        let land = app.world().resource::<SoundEffects>().land.clone();
+        assert_eq!(sound_count(&mut app, &land), 0);
+    }
+
+    #[test]
+    fn test_emitters_play_at_master_volume() {
+        let mut app = test_app();
+        app.init_resource::<Assets<SynthSound>>()
+            .init_resource::<SoundEffects>()
+            .insert_resource(MasterVolume(0.5))
+            .insert_resource(GameRng::new(1))
+            .add_systems(Update, play_sound_emitters);
+
+        let emitter = app
+            .world_mut()
+            .spawn((SoundEmitter(SoundEffect::Explosion), Transform::from_xyz(5.0, 0.0, 0.0)))
+            .id();
+        app.update();
+
+        let explosion = app.world().resource::<SoundEffects>().explosion.clone();
+        assert_eq!(app.world().get::<AudioPlayer<SynthSound>>(emitter).unwrap().0, explosion);
+        let settings = app.world().get::<PlaybackSettings>(emitter).unwrap();
+        assert!(settings.spatial);
+        assert_eq!(settings.volume.to_linear(), 0.5);
+        assert!((0.9..=1.1).contains(&settings.speed));
+    }
+}
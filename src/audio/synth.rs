@@ -0,0 +1,153 @@
+// Tiny runtime synthesizer - sound effects are generated from a few parameters instead of
+// shipping audio files
+
+use std::time::Duration;
+
+use bevy::audio::{Decodable, Source};
+use bevy::prelude::*;
+
+pub const SAMPLE_RATE: u32 = 44_100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Square,
+    /// White noise resampled at the sweep frequency - lower frequencies sound duller
+    Noise,
+}
+
+/// A one-shot sound: a waveform sweeping from `start_frequency` to `end_frequency`,
+/// with a short attack and a linear fade over `duration` seconds
+#[derive(Asset, TypePath, Debug, Clone, Copy)]
+pub struct SynthSound {
+    pub waveform: Waveform,
+    pub start_frequency: f32,
+    pub end_frequency: f32,
+    pub duration: f32,
+}
+
+impl SynthSound {
+    pub fn total_samples(&self) -> u32 {
+        (self.duration * SAMPLE_RATE as f32) as u32
+    }
+}
+
+/// Streams the samples of a SynthSound
+pub struct SynthDecoder {
+    sound: SynthSound,
+    sample: u32,
+    total: u32,
+    phase: f32,
+    noise_state: u32,
+    noise_value: f32,
+}
+
+impl SynthDecoder {
+    pub fn new(sound: SynthSound) -> Self {
+        Self {
+            sound,
+            sample: 0,
+            total: sound.total_samples(),
+            phase: 0.0,
+            noise_state: 0x9E37_79B9,
+            noise_value: 0.0,
+        }
+    }
+
+    /// Next white noise value in [-1, 1] (xorshift32)
+    fn next_noise(&mut self) -> f32 {
+        let mut x = self.noise_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.noise_state = x;
+        x as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if self.sample >= self.total {
+            return None;
+        }
+
+        let t = self.sample as f32 / self.total as f32;
+        let frequency = self.sound.start_frequency + (self.sound.end_frequency - self.sound.start_frequency) * t;
+        let next_phase = self.phase + frequency / SAMPLE_RATE as f32;
+        let wrapped = next_phase >= 1.0;
+        self.phase = next_phase.fract();
+
+        let value = match self.sound.waveform {
+            Waveform::Sine => (self.phase * std::f32::consts::TAU).sin(),
+            Waveform::Square => {
+                if self.phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Noise => {
+                if wrapped || self.sample == 0 {
+                    self.noise_value = self.next_noise();
+                }
+                self.noise_value
+            }
+        };
+
+        // Fast attack so the start doesn't click, then fade out to silence
+        let envelope = (t * 50.0).min(1.0) * (1.0 - t);
+        self.sample += 1;
+        Some(value * envelope)
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some((self.total - self.sample) as usize)
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(self.sound.duration))
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder::new(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoder_length_and_fade() {
+        let sound = SynthSound {
+            waveform: Waveform::Square,
+            start_frequency: 440.0,
+            end_frequency: 220.0,
+            duration: 0.1,
+        };
+        let samples: Vec<f32> = SynthDecoder::new(sound).collect();
+
+        assert_eq!(samples.len() as u32, SAMPLE_RATE / 10);
+        assert!(samples.iter().all(|sample| sample.abs() <= 1.0));
+        // Starts and ends (nearly) silent
+        assert_eq!(samples[0], 0.0);
+        assert!(samples.last().unwrap().abs() < 0.001);
+    }
+}
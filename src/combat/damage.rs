@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use super::effects::spawn_damage_number;
+use crate::player::Player;
+
 /// Health component for any entity that can take damage
 #[derive(Component)]
 pub struct Health {
@@ -44,6 +47,9 @@ pub struct Armor {
 }
 
 impl Armor {
+    /// Most armor pickups can stack up to
+    pub const PICKUP_MAX: f32 = 100.0;
+
     pub fn new(max: f32) -> Self {
         Self {
             current: max,
@@ -52,6 +58,16 @@ impl Armor {
         }
     }
 
+    /// Add armor from a pickup, capped at `PICKUP_MAX`. Returns false if already full.
+    pub fn top_up(&mut self, amount: f32) -> bool {
+        self.max = self.max.max(Self::PICKUP_MAX);
+        if self.current >= self.max {
+            return false;
+        }
+        self.current = (self.current + amount).min(self.max);
+        true
+    }
+
     /// Returns the amount of damage that passes through to health
     pub fn absorb(&mut self, damage: f32) -> f32 {
         if self.current <= 0.0 {
@@ -74,6 +90,90 @@ impl Default for Armor {
     }
 }
 
+/// Which side an entity fights on - decides who area damage hurts.
+/// Entities with `Health` but no faction are neutral and hurt by everything.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Faction {
+    Player,
+    Enemy,
+}
+
+impl Faction {
+    /// Fraction of damage the player takes from their own explosions (rocket jumping)
+    pub const SELF_DAMAGE_SCALE: f32 = 0.25;
+}
+
+/// Combat rules shared by every damage source
+#[derive(Resource)]
+pub struct CombatConfig {
+    /// Whether enemy explosions hurt other enemies.
+    /// The player always takes (reduced) damage from their own rockets.
+    pub friendly_fire: bool,
+    /// Hitscan damage multiplier for hits inside a `Headbox`
+    pub headshot_multiplier: f32,
+    /// Rockets steer toward enemies in front of them
+    pub homing_rockets: bool,
+}
+
+impl Default for CombatConfig {
+    fn default() -> Self {
+        Self {
+            friendly_fire: false,
+            headshot_multiplier: 2.0,
+            homing_rockets: false,
+        }
+    }
+}
+
+/// Weak spot for hitscan headshots - a sphere offset from the entity's center
+#[derive(Component, Clone, Copy)]
+pub struct Headbox {
+    /// Offset from the entity's position to the head center
+    pub offset: Vec3,
+    pub radius: f32,
+}
+
+impl Default for Headbox {
+    /// Head of the standard enemy capsule, around the eyes
+    fn default() -> Self {
+        Self {
+            offset: Vec3::new(0.0, 0.7, 0.0),
+            radius: 0.4,
+        }
+    }
+}
+
+/// Area damage from an explosion
+#[derive(Clone, Copy, Debug)]
+pub struct Blast {
+    pub center: Vec3,
+    pub radius: f32,
+    pub damage: f32,
+    /// Faction that caused the blast
+    pub faction: Faction,
+}
+
+impl Blast {
+    /// Damage dealt to a target at `position` - falls off linearly to 0 at the radius.
+    /// None if the target is out of range or protected by the faction rules.
+    pub fn damage_at(&self, position: Vec3, target: Option<Faction>, friendly_fire: bool) -> Option<f32> {
+        let scale = match target {
+            Some(target) if target == self.faction => match target {
+                Faction::Player => Faction::SELF_DAMAGE_SCALE,
+                Faction::Enemy if friendly_fire => 1.0,
+                Faction::Enemy => return None,
+            },
+            _ => 1.0,
+        };
+
+        let dist = position.distance(self.center);
+        if dist >= self.radius {
+            return None;
+        }
+        Some(self.damage * (1.0 - dist / self.radius) * scale)
+    }
+}
+
 /// Event fired when an entity takes damage
 #[derive(Event)]
 pub struct DamageEvent {
@@ -89,18 +189,16 @@ pub struct DeathEvent {
     pub killer: Option<Entity>,
 }
 
-/// Marker for entities that are dead (pending cleanup)
-#[derive(Component)]
-pub struct Dead;
-
 /// Process damage events - applies damage through armor to health
+#[allow(clippy::type_complexity)]
 pub fn process_damage_events(
     mut damage_events: EventReader<DamageEvent>,
     mut death_events: EventWriter<DeathEvent>,
-    mut query: Query<(&mut Health, Option<&mut Armor>)>,
+    mut query: Query<(&mut Health, Option<&mut Armor>, Option<&GlobalTransform>, Has<Player>)>,
+    mut commands: Commands,
 ) {
     for event in damage_events.read() {
-        let Ok((mut health, armor)) = query.get_mut(event.target) else {
+        let Ok((mut health, armor, transform, is_player)) = query.get_mut(event.target) else {
             continue;
         };
 
@@ -118,6 +216,11 @@ pub fn process_damage_events(
 
         health.take_damage(final_damage);
 
+        if final_damage > 0.0 {
+            let origin = transform.map_or(Vec3::ZERO, |transform| transform.translation());
+            spawn_damage_number(&mut commands, origin, final_damage, is_player);
+        }
+
         // Check for death
         if health.is_dead() {
             death_events.write(DeathEvent {
@@ -174,12 +277,12 @@ pub fn trigger_damage_flash(
     };
 
     for event in damage_events.read() {
-        if event.target == player_entity {
-            if let Ok(mut flash) = flash_query.single_mut() {
-                // Scale intensity by damage amount (capped)
-                let intensity_boost = (event.amount / 25.0).min(1.0);
-                flash.intensity = (flash.intensity + intensity_boost).min(1.0);
-            }
+        if event.target == player_entity
+            && let Ok(mut flash) = flash_query.single_mut()
+        {
+            // Scale intensity by damage amount (capped)
+            let intensity_boost = (event.amount / 25.0).min(1.0);
+            flash.intensity = (flash.intensity + intensity_boost).min(1.0);
         }
     }
 }
@@ -204,3 +307,45 @@ pub fn update_damage_flash(
     // Update overlay alpha
     bg_color.0 = Color::srgba(1.0, 0.0, 0.0, flash.intensity * 0.5);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blast(faction: Faction) -> Blast {
+        Blast {
+            center: Vec3::ZERO,
+            radius: 5.0,
+            damage: 100.0,
+            faction,
+        }
+    }
+
+    #[test]
+    fn test_blast_falls_off_with_distance() {
+        let blast = blast(Faction::Player);
+        let near = blast.damage_at(Vec3::X, Some(Faction::Enemy), false).unwrap();
+        let far = blast.damage_at(Vec3::X * 4.0, Some(Faction::Enemy), false).unwrap();
+
+        assert!((near - 80.0).abs() < 0.001);
+        assert!(far < near);
+        assert_eq!(blast.damage_at(Vec3::X * 6.0, Some(Faction::Enemy), false), None);
+    }
+
+    #[test]
+    fn test_blast_faction_rules() {
+        // Enemies only hurt each other with friendly fire on
+        let enemy_blast = blast(Faction::Enemy);
+        assert_eq!(enemy_blast.damage_at(Vec3::X, Some(Faction::Enemy), false), None);
+        assert!(enemy_blast.damage_at(Vec3::X, Some(Faction::Enemy), true).is_some());
+        assert!(enemy_blast.damage_at(Vec3::X, Some(Faction::Player), false).is_some());
+
+        // Own rockets always hurt the player, but only a little
+        let player_blast = blast(Faction::Player);
+        let self_damage = player_blast.damage_at(Vec3::X, Some(Faction::Player), false).unwrap();
+        assert!((self_damage - 80.0 * Faction::SELF_DAMAGE_SCALE).abs() < 0.001);
+
+        // Neutral targets take everything
+        assert!(enemy_blast.damage_at(Vec3::X, None, false).is_some());
+    }
+}
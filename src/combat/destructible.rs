@@ -0,0 +1,245 @@
+use bevy::prelude::*;
+
+use super::damage::{DeathEvent, Health};
+use super::pickups::{spawn_pickup, Pickup};
+use super::weapons::Shootable;
+use crate::level::{BoxCollider, LevelGeometry, WallCollider};
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+use crate::rng::GameRng;
+
+/// Breakable level prop - takes hitscan, projectile and splash damage like an enemy,
+/// and bursts into debris and a pickup when destroyed
+#[derive(Component)]
+pub struct Destructible;
+
+impl Destructible {
+    pub const CRATE_HEALTH: f32 = 40.0;
+    pub const CRATE_HALF_EXTENTS: Vec3 = Vec3::splat(0.5);
+    /// Debris chunks thrown out when a crate breaks
+    pub const DEBRIS_COUNT: usize = 6;
+
+    /// What a broken crate leaves behind - always something, evenly health or ammo
+    pub fn roll_drop(rng: &mut GameRng) -> Pickup {
+        if rng.f32() < 0.5 {
+            Pickup::health_pack()
+        } else {
+            Pickup::ammo_pack()
+        }
+    }
+}
+
+/// Chunk of a broken crate - flies out, falls, and disappears
+#[derive(Component)]
+pub struct Debris {
+    pub velocity: Vec3,
+    pub lifetime: f32,
+}
+
+impl Debris {
+    pub const LIFETIME: f32 = 0.8;
+    pub const GRAVITY: f32 = 15.0;
+}
+
+/// Spawn a crate resting on the surface at `position`
+pub fn spawn_crate(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+) -> Entity {
+    let half = Destructible::CRATE_HALF_EXTENTS;
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(half * 2.0))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.55, 0.38, 0.2),
+                perceptual_roughness: 0.9,
+                ..default()
+            })),
+            Transform::from_translation(position + Vec3::Y * half.y),
+            LevelGeometry,
+            // Blocks movement until it's broken
+            BoxCollider { half_extents: half },
+            WallCollider,
+            Destructible,
+            Health::new(Destructible::CRATE_HEALTH),
+            Shootable,
+            AsciiPatternId::blocks(),
+        ))
+        .id()
+}
+
+/// Break destroyed crates: despawn them, throw out debris, and leave a pickup
+pub fn handle_destructible_death(
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    destructible_query: Query<&Transform, With<Destructible>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
+) {
+    for event in death_events.read() {
+        let Ok(transform) = destructible_query.get(event.entity) else {
+            continue;
+        };
+        let position = transform.translation;
+
+        let debris_mesh = meshes.add(Cuboid::new(0.2, 0.2, 0.2));
+        let debris_material = materials.add(StandardMaterial {
+            base_color: Color::srgb(0.55, 0.38, 0.2),
+            ..default()
+        });
+        for i in 0..Destructible::DEBRIS_COUNT {
+            // Evenly spread around, with a random upward kick
+            let angle = i as f32 / Destructible::DEBRIS_COUNT as f32 * std::f32::consts::TAU + rng.range(-0.3, 0.3);
+            let velocity = Vec3::new(angle.cos() * 4.0, rng.range(3.0, 6.0), angle.sin() * 4.0);
+            commands.spawn((
+                Mesh3d(debris_mesh.clone()),
+                MeshMaterial3d(debris_material.clone()),
+                Transform::from_translation(position),
+                Debris {
+                    velocity,
+                    lifetime: Debris::LIFETIME,
+                },
+                AsciiPatternId::blocks().with_category(AsciiCategory::Effect),
+            ));
+        }
+
+        let pickup = Destructible::roll_drop(&mut rng);
+        spawn_pickup(&mut commands, &mut meshes, &mut materials, position, pickup);
+
+        commands.entity(event.entity).despawn();
+    }
+}
+
+/// Fly debris under gravity, spinning, until it expires
+pub fn update_debris(
+    mut commands: Commands,
+    mut debris_query: Query<(Entity, &mut Transform, &mut Debris)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut debris) in &mut debris_query {
+        debris.velocity.y -= Debris::GRAVITY * dt;
+        transform.translation += debris.velocity * dt;
+        transform.translation.y = transform.translation.y.max(0.1);
+        transform.rotate_x(dt * 8.0);
+
+        debris.lifetime -= dt;
+        if debris.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{
+        process_damage_events, rebuild_spatial_grid, update_explosions, CombatConfig, DamageEvent, Explosion, Faction,
+        SpatialGrid,
+    };
+
+    fn crate_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.add_plugins(TransformPlugin)
+            .with_frame_time(Duration::from_millis(50))
+            .add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(3))
+            .add_systems(Update, (process_damage_events, handle_destructible_death, update_debris).chain());
+
+        let world = app.world_mut();
+        let mut commands = world.commands();
+        let mut meshes = Assets::<Mesh>::default();
+        let mut materials = Assets::<StandardMaterial>::default();
+        let crate_entity = spawn_crate(&mut commands, &mut meshes, &mut materials, Vec3::new(3.0, 0.0, 0.0));
+        world.flush();
+        (app, crate_entity)
+    }
+
+    fn damage(app: &mut App, target: Entity, amount: f32) {
+        app.world_mut().send_event(DamageEvent { target, amount, source: None });
+        app.update();
+    }
+
+    fn count<T: Component>(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query_filtered::<(), With<T>>().iter(world).count()
+    }
+
+    #[test]
+    fn test_crate_survives_partial_damage() {
+        let (mut app, crate_entity) = crate_test_app();
+        damage(&mut app, crate_entity, Destructible::CRATE_HEALTH / 2.0);
+
+        assert!(app.world().get_entity(crate_entity).is_ok());
+        assert_eq!(count::<Pickup>(&mut app), 0);
+    }
+
+    #[test]
+    fn test_destroyed_crate_despawns_and_drops_pickup() {
+        let (mut app, crate_entity) = crate_test_app();
+        damage(&mut app, crate_entity, Destructible::CRATE_HEALTH);
+
+        assert!(app.world().get_entity(crate_entity).is_err());
+        assert_eq!(count::<Pickup>(&mut app), 1);
+        assert_eq!(count::<Debris>(&mut app), Destructible::DEBRIS_COUNT);
+
+        // The pickup lands where the crate was
+        let world = app.world_mut();
+        let pickup_transform = world.query_filtered::<&Transform, With<Pickup>>().single(world).unwrap();
+        assert_eq!(pickup_transform.translation.x, 3.0);
+    }
+
+    #[test]
+    fn test_crate_takes_splash_damage() {
+        let (mut app, crate_entity) = crate_test_app();
+        app.init_resource::<CombatConfig>()
+            .init_resource::<SpatialGrid>()
+            .add_systems(PreUpdate, rebuild_spatial_grid)
+            .add_systems(Update, update_explosions.before(process_damage_events));
+        // Player rocket going off right beside the crate
+        app.world_mut().spawn((
+            Transform::from_xyz(2.0, 0.5, 0.0),
+            Explosion {
+                radius: 0.0,
+                max_radius: 4.0,
+                damage: 100.0,
+                lifetime: 0.5,
+                has_damaged: false,
+                knockback_strength: 0.0,
+                faction: Faction::Player,
+                owner: None,
+            },
+        ));
+
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world().get_entity(crate_entity).is_err());
+        assert_eq!(count::<Pickup>(&mut app), 1);
+    }
+
+    #[test]
+    fn test_debris_expires() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .add_systems(Update, update_debris);
+        app.world_mut().spawn((
+            Transform::default(),
+            Debris {
+                velocity: Vec3::Y * 5.0,
+                lifetime: Debris::LIFETIME,
+            },
+        ));
+
+        for _ in 0..12 {
+            app.update();
+        }
+        assert_eq!(count::<Debris>(&mut app), 0);
+    }
+}
@@ -0,0 +1,409 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::weapons::MuzzleFlash;
+use crate::player::PlayerCamera;
+use crate::rendering::RenderQuality;
+
+/// Tunables for transient combat effects (decals, lights, etc)
+#[derive(Resource)]
+pub struct EffectsConfig {
+    /// Maximum number of wall decals alive at once (oldest are removed first)
+    pub max_decals: usize,
+    /// Whether shots into the ground floor leave decals (off by default to avoid clutter)
+    pub decals_on_floor: bool,
+    /// Maximum simultaneous effect lights (muzzle/explosion/trail) - oldest are culled first.
+    /// Too many dynamic lights overflow Bevy's per-cluster limits and make lights flicker.
+    pub max_effect_lights: usize,
+    /// Effect light cap used in low-spec render quality
+    pub max_effect_lights_low_spec: usize,
+}
+
+impl Default for EffectsConfig {
+    fn default() -> Self {
+        Self {
+            max_decals: 64,
+            decals_on_floor: false,
+            max_effect_lights: 16,
+            max_effect_lights_low_spec: 6,
+        }
+    }
+}
+
+impl EffectsConfig {
+    /// Effect light cap for the given render quality
+    pub fn effect_light_cap(&self, quality: RenderQuality) -> usize {
+        match quality {
+            RenderQuality::High => self.max_effect_lights,
+            RenderQuality::Low => self.max_effect_lights_low_spec,
+        }
+    }
+}
+
+/// Live effect lights in spawn order (oldest first)
+#[derive(Resource, Default)]
+pub struct EffectLights {
+    pub lights: VecDeque<Entity>,
+}
+
+impl EffectLights {
+    pub fn track(&mut self, entity: Entity) {
+        self.lights.push_back(entity);
+    }
+
+    pub fn untrack(&mut self, entity: Entity) {
+        self.lights.retain(|&light| light != entity);
+    }
+
+    /// Remove and return the oldest lights over the cap
+    pub fn cull(&mut self, cap: usize) -> Vec<Entity> {
+        let excess = self.lights.len().saturating_sub(cap);
+        self.lights.drain(..excess).collect()
+    }
+}
+
+/// Bullet hole / scorch mark left on level geometry
+#[derive(Component)]
+pub struct Decal {
+    pub lifetime: f32,
+    pub max_lifetime: f32,
+}
+
+/// Look of a decal - each weapon leaves its own mark
+#[derive(Clone, Copy)]
+pub struct DecalStyle {
+    pub size: f32,
+    pub color: Color,
+    pub lifetime: f32,
+}
+
+impl DecalStyle {
+    /// Small dark bullet hole
+    pub fn bullet_hole() -> Self {
+        Self {
+            size: 0.15,
+            color: Color::srgb(0.05, 0.05, 0.05),
+            lifetime: 6.0,
+        }
+    }
+
+    /// Large sooty scorch mark
+    pub fn scorch() -> Self {
+        Self {
+            size: 1.6,
+            color: Color::srgb(0.12, 0.08, 0.05),
+            lifetime: 10.0,
+        }
+    }
+}
+
+/// Spawn a decal quad lying flat on a surface
+pub fn spawn_decal(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    point: Vec3,
+    normal: Vec3,
+    style: DecalStyle,
+) {
+    // Each decal gets its own material so it can fade independently
+    let decal_material = materials.add(StandardMaterial {
+        base_color: style.color,
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    // Plane mesh faces +Y, rotate it onto the surface normal.
+    // Offset slightly off the surface to avoid z-fighting.
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(style.size, style.size))),
+        MeshMaterial3d(decal_material),
+        Transform::from_translation(point + normal * 0.01)
+            .with_rotation(Quat::from_rotation_arc(Vec3::Y, normal)),
+        Decal {
+            lifetime: style.lifetime,
+            max_lifetime: style.lifetime,
+        },
+    ));
+}
+
+/// Fade decals out over their lifetime and enforce the decal cap
+pub fn update_decals(
+    mut commands: Commands,
+    mut decal_query: Query<(Entity, &mut Decal, &MeshMaterial3d<StandardMaterial>)>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    config: Res<EffectsConfig>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let mut alive: Vec<(Entity, f32)> = Vec::new();
+
+    for (entity, mut decal, material_handle) in &mut decal_query {
+        decal.lifetime -= dt;
+        if decal.lifetime <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Hold full opacity for the first half, then fade out
+        let fade = (decal.lifetime / (decal.max_lifetime * 0.5)).min(1.0);
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.base_color.set_alpha(fade * 0.9);
+        }
+
+        alive.push((entity, decal.lifetime));
+    }
+
+    // Over the cap - remove the decals closest to expiring first
+    if alive.len() > config.max_decals {
+        alive.sort_by(|a, b| a.1.total_cmp(&b.1));
+        let excess = alive.len() - config.max_decals;
+        for (entity, _) in alive.into_iter().take(excess) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Floating damage readout - rises and fades above whatever was hit
+#[derive(Component)]
+pub struct DamageNumber {
+    /// World position of the hit
+    pub origin: Vec3,
+    /// Seconds since the hit
+    pub age: f32,
+    /// Hits on the player show under the crosshair instead of in the world
+    pub on_player: bool,
+}
+
+impl DamageNumber {
+    pub const LIFETIME: f32 = 0.8;
+    /// World units per second the number drifts upward
+    pub const RISE_SPEED: f32 = 1.5;
+    /// Hits at least this big get the highlighted look
+    pub const BIG_HIT: f32 = 40.0;
+
+    /// Text color by hit size and target
+    pub fn color(amount: f32, on_player: bool) -> Color {
+        if on_player {
+            Color::srgb(1.0, 0.2, 0.2)
+        } else if amount >= Self::BIG_HIT {
+            Color::srgb(1.0, 0.6, 0.1)
+        } else {
+            Color::srgb(1.0, 1.0, 0.9)
+        }
+    }
+
+    /// Current world position - starts just above the hit and drifts up
+    pub fn position(&self) -> Vec3 {
+        self.origin + Vec3::Y * (1.0 + self.age * Self::RISE_SPEED)
+    }
+
+    /// Opacity - solid for the first half, then fades out
+    pub fn alpha(&self) -> f32 {
+        let remaining = 1.0 - self.age / Self::LIFETIME;
+        (remaining * 2.0).clamp(0.0, 1.0)
+    }
+}
+
+/// Spawn a damage number for a hit at `origin`
+pub fn spawn_damage_number(commands: &mut Commands, origin: Vec3, amount: f32, on_player: bool) {
+    let font_size = if amount >= DamageNumber::BIG_HIT { 28.0 } else { 20.0 };
+
+    commands.spawn((
+        Text::new(format!("{:.0}", amount)),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(DamageNumber::color(amount, on_player)),
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        // Hidden until it has been placed on screen
+        Visibility::Hidden,
+        DamageNumber {
+            origin,
+            age: 0.0,
+            on_player,
+        },
+    ));
+}
+
+/// Move damage numbers to their projected screen position, fade them, and remove expired ones
+pub fn update_damage_numbers(
+    mut commands: Commands,
+    mut number_query: Query<(Entity, &mut DamageNumber, &mut Node, &mut TextColor, &mut Visibility)>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<PlayerCamera>>,
+    time: Res<Time>,
+) {
+    let camera = camera_query.iter().find(|(camera, _)| camera.is_active);
+
+    for (entity, mut number, mut node, mut color, mut visibility) in &mut number_query {
+        number.age += time.delta_secs();
+        if number.age >= DamageNumber::LIFETIME {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        color.0.set_alpha(number.alpha());
+
+        let Some((camera, camera_transform)) = camera else {
+            continue;
+        };
+
+        let screen_pos = if number.on_player {
+            // Just below the crosshair, drifting down as it fades
+            camera
+                .logical_viewport_size()
+                .map(|size| size * Vec2::new(0.5, 0.6) + Vec2::Y * number.age * 40.0)
+        } else {
+            camera.world_to_viewport(camera_transform, number.position()).ok()
+        };
+
+        match screen_pos {
+            Some(pos) => {
+                node.left = Val::Px(pos.x);
+                node.top = Val::Px(pos.y);
+                *visibility = Visibility::Visible;
+            }
+            // Behind the camera
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+/// Track effect lights as they spawn/despawn and cull the oldest over the cap
+pub fn cap_effect_lights(
+    mut commands: Commands,
+    mut lights: ResMut<EffectLights>,
+    spawned: Query<Entity, (Added<MuzzleFlash>, With<PointLight>)>,
+    mut removed: RemovedComponents<MuzzleFlash>,
+    config: Res<EffectsConfig>,
+    quality: Res<RenderQuality>,
+) {
+    for entity in removed.read() {
+        lights.untrack(entity);
+    }
+    for entity in &spawned {
+        lights.track(entity);
+    }
+
+    let cap = config.effect_light_cap(*quality);
+    let culled = lights.cull(cap);
+    if !culled.is_empty() {
+        debug!("Effect light cap ({}) reached - culled {} oldest lights", cap, culled.len());
+    }
+    for entity in culled {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    // ==================== Damage Number Tests ====================
+
+    #[test]
+    fn test_damage_number_rises_and_fades() {
+        let mut number = DamageNumber {
+            origin: Vec3::ZERO,
+            age: 0.0,
+            on_player: false,
+        };
+        let start = number.position();
+        assert_eq!(number.alpha(), 1.0);
+
+        number.age = DamageNumber::LIFETIME * 0.75;
+        assert!(number.position().y > start.y);
+        assert!(number.alpha() < 1.0 && number.alpha() > 0.0);
+    }
+
+    #[test]
+    fn test_big_hits_colored_differently() {
+        let small = DamageNumber::color(10.0, false);
+        let big = DamageNumber::color(DamageNumber::BIG_HIT, false);
+        let player = DamageNumber::color(10.0, true);
+        assert_ne!(small, big);
+        assert_ne!(small, player);
+    }
+
+    #[test]
+    fn test_damage_event_spawns_expiring_number() {
+        use crate::combat::{process_damage_events, DamageEvent, DeathEvent, Health};
+
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .add_systems(Update, (process_damage_events, update_damage_numbers).chain());
+
+        let target = app
+            .world_mut()
+            .spawn((Health::new(100.0), GlobalTransform::from_xyz(0.0, 1.0, -5.0)))
+            .id();
+        app.world_mut().send_event(DamageEvent {
+            target,
+            amount: 12.0,
+            source: None,
+        });
+        app.update();
+
+        let numbers: Vec<_> = app
+            .world_mut()
+            .query::<(&Text, &DamageNumber)>()
+            .iter(app.world())
+            .map(|(text, number)| (text.0.clone(), number.on_player))
+            .collect();
+        assert_eq!(numbers, vec![("12".to_string(), false)]);
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world_mut().query::<&DamageNumber>().iter(app.world()).count(), 0);
+    }
+
+    // ==================== Effect Light Cap Tests ====================
+
+    #[test]
+    fn test_effect_light_cap_follows_quality() {
+        let config = EffectsConfig::default();
+        assert_eq!(config.effect_light_cap(RenderQuality::High), config.max_effect_lights);
+        assert_eq!(config.effect_light_cap(RenderQuality::Low), config.max_effect_lights_low_spec);
+        assert!(config.max_effect_lights_low_spec < config.max_effect_lights);
+    }
+
+    #[test]
+    fn test_cull_removes_oldest_first() {
+        let mut lights = EffectLights::default();
+        let entities: Vec<_> = (0..5).map(Entity::from_raw).collect();
+        for &entity in &entities {
+            lights.track(entity);
+        }
+
+        assert_eq!(lights.cull(3), vec![entities[0], entities[1]]);
+        assert_eq!(lights.lights, VecDeque::from(entities[2..].to_vec()));
+
+        // Under the cap nothing is culled
+        assert!(lights.cull(3).is_empty());
+        assert!(lights.cull(10).is_empty());
+    }
+
+    #[test]
+    fn test_untrack_despawned_light() {
+        let mut lights = EffectLights::default();
+        let (a, b, c) = (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3));
+        lights.track(a);
+        lights.track(b);
+        lights.track(c);
+
+        // A light that expired on its own no longer counts toward the cap
+        lights.untrack(b);
+        assert_eq!(lights.cull(1), vec![a]);
+        assert_eq!(lights.lights, VecDeque::from(vec![c]));
+    }
+}
@@ -6,9 +6,21 @@ use bevy::prelude::*;
 use crate::GameState;
 
 pub mod damage;
+pub mod destructible;
+pub mod effects;
+pub mod pickups;
+pub mod ruleset;
+pub mod spatial;
+pub mod status;
 pub mod weapons;
 
 pub use damage::*;
+pub use destructible::*;
+pub use effects::*;
+pub use pickups::*;
+pub use ruleset::*;
+pub use spatial::*;
+pub use status::*;
 pub use weapons::*;
 
 pub struct CombatPlugin;
@@ -17,20 +29,46 @@ impl Plugin for CombatPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<DamageEvent>()
             .add_event::<DeathEvent>()
+            .init_resource::<EffectsConfig>()
+            .init_resource::<CombatConfig>()
+            .init_resource::<EffectLights>()
+            .init_resource::<RuleSet>()
+            .init_resource::<AmmoRegen>()
+            .init_resource::<PickupConfig>()
+            .init_resource::<SpatialGrid>()
             .add_systems(Startup, spawn_damage_flash_overlay)
+            // Before Update, so every hit check this frame (enemy blasts included) sees the same grid
+            .add_systems(PreUpdate, rebuild_spatial_grid.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                apply_ruleset,
+            )
+            .add_systems(Update, cycle_ruleset.run_if(in_state(GameState::Menu)))
             .add_systems(
                 Update,
                 (
                     update_weapon_cooldowns,
+                    regenerate_ammo,
                     handle_weapon_switch,
+                    handle_reload,
                     handle_shooting,
                     update_player_projectiles,
+                    update_grenades,
                     update_explosions,
+                    apply_explosion_knockback,
+                    apply_sword_lunge,
                     update_sword_swings,
+                    update_status_effects,
                     process_damage_events,
+                    (handle_destructible_death, update_debris).chain(),
+                    (attract_pickups, collect_pickups).chain(),
+                    spin_pickups,
                     trigger_damage_flash,
                     update_damage_flash,
-                    update_muzzle_flash,
+                    (update_muzzle_flash, cap_effect_lights, update_decals, update_damage_numbers).chain(),
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
@@ -0,0 +1,386 @@
+use bevy::prelude::*;
+
+use super::damage::{Armor, Health};
+use super::weapons::WeaponInventory;
+use crate::level::{line_of_sight_clear, BoxCollider, GroundFloor, Slope};
+use crate::player::Player;
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+use crate::rng::GameRng;
+
+/// Collectible item - walk over it to use it
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+#[allow(clippy::enum_variant_names)] // `Health` and `Armor` are already component names
+pub enum Pickup {
+    /// Restores this much health
+    HealthPack { amount: f32 },
+    /// Adds this many magazines of the current weapon to its reserve
+    AmmoPack { magazines: u32 },
+    /// Adds this much armor (up to `Armor::PICKUP_MAX`)
+    ArmorPack { amount: f32 },
+}
+
+impl Pickup {
+    /// Distance at which the player collects a pickup
+    pub const COLLECT_RADIUS: f32 = 1.5;
+    /// Chance an enemy drops something when it dies
+    pub const DROP_CHANCE: f32 = 0.5;
+
+    pub fn health_pack() -> Self {
+        Pickup::HealthPack { amount: 25.0 }
+    }
+
+    pub fn ammo_pack() -> Self {
+        Pickup::AmmoPack { magazines: 1 }
+    }
+
+    pub fn armor_pack() -> Self {
+        Pickup::ArmorPack { amount: 50.0 }
+    }
+
+    /// Roll an enemy drop - None most of the time, otherwise mostly health and ammo
+    /// with the occasional armor
+    pub fn roll_drop(rng: &mut GameRng) -> Option<Self> {
+        if rng.f32() >= Self::DROP_CHANCE {
+            return None;
+        }
+        let roll = rng.f32();
+        if roll < 0.4 {
+            Some(Self::health_pack())
+        } else if roll < 0.8 {
+            Some(Self::ammo_pack())
+        } else {
+            Some(Self::armor_pack())
+        }
+    }
+
+    /// Apply to the player - returns false (and leaves the pickup) if it would do nothing
+    pub fn apply(&self, health: &mut Health, armor: &mut Armor, inventory: &mut WeaponInventory) -> bool {
+        match *self {
+            Pickup::HealthPack { amount } => {
+                if health.current >= health.max {
+                    return false;
+                }
+                health.heal(amount);
+                true
+            }
+            Pickup::AmmoPack { magazines } => {
+                // Weapons with no reserve (the sword) don't use ammo
+                let weapon = inventory.current_mut();
+                if weapon.reload_time <= 0.0 {
+                    return false;
+                }
+                weapon.reserve_ammo += weapon.max_ammo * magazines;
+                true
+            }
+            Pickup::ArmorPack { amount } => armor.top_up(amount),
+        }
+    }
+}
+
+/// Pickup magnet tuning
+#[derive(Resource)]
+pub struct PickupConfig {
+    /// Pickups this close to the player fly toward them, if nothing is in the way (0 disables)
+    pub magnet_radius: f32,
+    /// Pull speed at the edge of the radius - triples by the time a pickup reaches the player
+    pub magnet_speed: f32,
+}
+
+impl Default for PickupConfig {
+    fn default() -> Self {
+        Self {
+            magnet_radius: 6.0,
+            magnet_speed: 4.0,
+        }
+    }
+}
+
+/// Spawn a pickup resting on the ground at `position`
+pub fn spawn_pickup(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+    pickup: Pickup,
+) {
+    let (color, emissive) = match pickup {
+        Pickup::HealthPack { .. } => (Color::srgb(0.2, 0.9, 0.3), LinearRgba::rgb(0.2, 1.5, 0.3)),
+        Pickup::AmmoPack { .. } => (Color::srgb(0.9, 0.8, 0.2), LinearRgba::rgb(1.5, 1.2, 0.2)),
+        Pickup::ArmorPack { .. } => (Color::srgb(0.3, 0.6, 1.0), LinearRgba::rgb(0.3, 0.8, 1.5)),
+    };
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(0.5, 0.5, 0.5))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: color,
+            emissive,
+            ..default()
+        })),
+        Transform::from_translation(Vec3::new(position.x, 0.5, position.z)),
+        pickup,
+        // Pickups keep their colors in monochrome mode
+        AsciiPatternId::blocks().with_category(AsciiCategory::Effect).with_force_color(),
+    ));
+}
+
+/// Use pickups the player walks over
+pub fn collect_pickups(
+    mut commands: Commands,
+    pickup_query: Query<(Entity, &Transform, &Pickup)>,
+    mut player_query: Query<(&Transform, &mut Health, &mut Armor, &mut WeaponInventory), With<Player>>,
+) {
+    let Ok((player_transform, mut health, mut armor, mut inventory)) = player_query.single_mut() else {
+        return;
+    };
+
+    if health.is_dead() {
+        return;
+    }
+
+    for (entity, transform, pickup) in &pickup_query {
+        let distance = transform.translation.distance(player_transform.translation);
+        if distance < Pickup::COLLECT_RADIUS && pickup.apply(&mut health, &mut armor, &mut inventory) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Pull pickups the player can see within `magnet_radius` toward them, faster as they close in
+#[allow(clippy::type_complexity)]
+pub fn attract_pickups(
+    config: Res<PickupConfig>,
+    mut pickup_query: Query<&mut Transform, (With<Pickup>, Without<Player>)>,
+    player_query: Query<(&Transform, &Health), With<Player>>,
+    // Same sight blockers as enemy line of sight - the floor and ramps don't hide pickups
+    collider_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Slope>, Without<Pickup>)>,
+    time: Res<Time>,
+) {
+    if config.magnet_radius <= 0.0 {
+        return;
+    }
+    let Ok((player_transform, health)) = player_query.single() else {
+        return;
+    };
+    if health.is_dead() {
+        return;
+    }
+
+    let player_pos = player_transform.translation;
+    for mut transform in &mut pickup_query {
+        let offset = player_pos - transform.translation;
+        let distance = offset.length();
+        if distance >= config.magnet_radius
+            || !line_of_sight_clear(collider_query.iter(), transform.translation, player_pos)
+        {
+            continue;
+        }
+
+        let closeness = 1.0 - distance / config.magnet_radius;
+        let speed = config.magnet_speed * (1.0 + 2.0 * closeness);
+        transform.translation += offset.normalize_or_zero() * (speed * time.delta_secs()).min(distance);
+    }
+}
+
+/// Slowly spin pickups so they stand out
+pub fn spin_pickups(mut pickup_query: Query<&mut Transform, With<Pickup>>, time: Res<Time>) {
+    for mut transform in &mut pickup_query {
+        transform.rotate_y(time.delta_secs() * 2.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{process_damage_events, DamageEvent, DeathEvent, WeaponStats};
+
+    fn pickup_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.add_systems(Update, collect_pickups);
+
+        let mut health = Health::new(100.0);
+        health.take_damage(60.0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 0.9, 0.0),
+                health,
+                Armor::default(),
+                WeaponInventory::default(),
+            ))
+            .id();
+        (app, player)
+    }
+
+    /// Player at the origin with the magnet running, stepping 100ms per update
+    fn magnet_test_app(magnet_radius: f32) -> App {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .insert_resource(PickupConfig {
+                magnet_radius,
+                ..default()
+            })
+            .add_systems(Update, attract_pickups);
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(0.0, 0.9, 0.0), Health::new(100.0)));
+        app
+    }
+
+    fn pickup_distance(app: &App, pickup: Entity) -> f32 {
+        let position = app.world().get::<Transform>(pickup).unwrap().translation;
+        position.xz().length()
+    }
+
+    #[test]
+    fn test_magnet_pulls_visible_pickups_faster_as_they_close() {
+        let mut app = magnet_test_app(6.0);
+        let near = app
+            .world_mut()
+            .spawn((Transform::from_xyz(5.0, 0.5, 0.0), Pickup::health_pack()))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((Transform::from_xyz(0.0, 0.5, 10.0), Pickup::health_pack()))
+            .id();
+        // First update has no delta
+        app.update();
+
+        let mut distances = vec![pickup_distance(&app, near)];
+        for _ in 0..3 {
+            app.update();
+            distances.push(pickup_distance(&app, near));
+        }
+        let steps: Vec<f32> = distances.windows(2).map(|pair| pair[0] - pair[1]).collect();
+        assert!(steps.iter().all(|step| *step > 0.0), "steps {steps:?}");
+        assert!(steps.windows(2).all(|pair| pair[1] > pair[0]), "not speeding up: {steps:?}");
+
+        // Out of range
+        assert_eq!(pickup_distance(&app, far), 10.0);
+    }
+
+    #[test]
+    fn test_magnet_does_not_pull_through_walls() {
+        let mut app = magnet_test_app(6.0);
+        let pickup = app
+            .world_mut()
+            .spawn((Transform::from_xyz(4.0, 0.5, 0.0), Pickup::health_pack()))
+            .id();
+        app.world_mut().spawn((
+            Transform::from_xyz(2.0, 2.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(0.5, 2.0, 5.0) },
+        ));
+        app.update();
+        app.update();
+
+        assert_eq!(pickup_distance(&app, pickup), 4.0);
+    }
+
+    #[test]
+    fn test_zero_magnet_radius_disables_the_magnet() {
+        let mut app = magnet_test_app(0.0);
+        let pickup = app
+            .world_mut()
+            .spawn((Transform::from_xyz(1.0, 0.5, 0.0), Pickup::health_pack()))
+            .id();
+        app.update();
+        app.update();
+
+        assert_eq!(pickup_distance(&app, pickup), 1.0);
+    }
+
+    fn pickup_count(app: &mut App) -> usize {
+        app.world_mut().query::<&Pickup>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn test_health_pack_heals_player() {
+        let (mut app, player) = pickup_test_app();
+        app.world_mut().spawn((Transform::from_xyz(0.0, 0.5, 0.0), Pickup::health_pack()));
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 65.0);
+        assert_eq!(pickup_count(&mut app), 0);
+    }
+
+    #[test]
+    fn test_distant_pickup_is_left_alone() {
+        let (mut app, player) = pickup_test_app();
+        app.world_mut().spawn((Transform::from_xyz(10.0, 0.5, 0.0), Pickup::health_pack()));
+        app.update();
+
+        assert_eq!(app.world().get::<Health>(player).unwrap().current, 40.0);
+        assert_eq!(pickup_count(&mut app), 1);
+    }
+
+    #[test]
+    fn test_ammo_pack_adds_a_magazine_to_reserve() {
+        let (mut app, player) = pickup_test_app();
+        app.world_mut().spawn((Transform::from_xyz(0.5, 0.5, 0.0), Pickup::ammo_pack()));
+        app.update();
+
+        let machinegun = WeaponStats::machinegun();
+        let inventory = app.world().get::<WeaponInventory>(player).unwrap();
+        assert_eq!(inventory.current().reserve_ammo, machinegun.reserve_ammo + machinegun.max_ammo);
+        assert_eq!(pickup_count(&mut app), 0);
+    }
+
+    #[test]
+    fn test_armor_pack_absorbs_damage() {
+        let (mut app, player) = pickup_test_app();
+        app.add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .add_systems(Update, process_damage_events.after(collect_pickups));
+        app.world_mut().spawn((Transform::from_xyz(0.0, 0.5, 0.0), Pickup::armor_pack()));
+        app.update();
+        assert_eq!(app.world().get::<Armor>(player).unwrap().current, 50.0);
+
+        app.world_mut().send_event(DamageEvent {
+            target: player,
+            amount: 30.0,
+            source: None,
+        });
+        app.update();
+
+        // Armor soaks 66% of the hit, health takes the rest
+        let armor = app.world().get::<Armor>(player).unwrap();
+        assert!((armor.current - (50.0 - 30.0 * 0.66)).abs() < 0.001);
+        let health = app.world().get::<Health>(player).unwrap();
+        assert!((health.current - (40.0 - 30.0 * 0.34)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_armor_tops_up_to_the_cap() {
+        let mut armor = Armor::default();
+        assert!(armor.top_up(80.0));
+        assert!(armor.top_up(80.0));
+        assert_eq!(armor.current, Armor::PICKUP_MAX);
+        assert!(!armor.top_up(80.0));
+    }
+
+    #[test]
+    fn test_useless_pickups_stay_on_the_ground() {
+        let mut health = Health::new(100.0);
+        let mut armor = Armor::new(Armor::PICKUP_MAX);
+        let mut inventory = WeaponInventory::default();
+
+        // Full health
+        assert!(!Pickup::health_pack().apply(&mut health, &mut armor, &mut inventory));
+        // Full armor
+        assert!(!Pickup::armor_pack().apply(&mut health, &mut armor, &mut inventory));
+        // Sword doesn't use ammo
+        inventory.switch_to(2);
+        assert!(!Pickup::ammo_pack().apply(&mut health, &mut armor, &mut inventory));
+    }
+
+    #[test]
+    fn test_drops_are_mixed() {
+        let mut rng = GameRng::new(11);
+        let drops: Vec<_> = (0..200).map(|_| Pickup::roll_drop(&mut rng)).collect();
+
+        assert!(drops.iter().any(|drop| drop.is_none()));
+        assert!(drops.contains(&Some(Pickup::health_pack())));
+        assert!(drops.contains(&Some(Pickup::ammo_pack())));
+        assert!(drops.contains(&Some(Pickup::armor_pack())));
+    }
+}
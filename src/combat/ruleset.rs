@@ -0,0 +1,227 @@
+use bevy::prelude::*;
+
+use super::damage::{Armor, Health};
+use super::weapons::{WeaponInventory, WeaponStats, WeaponType};
+use crate::player::Player;
+
+/// Game modifiers - scale weapons and player health at the start of a run
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RuleSet {
+    /// Standard damage and health
+    #[default]
+    Normal,
+    /// Every hit kills - for weapons and for the player
+    Instagib,
+    /// Rocket launcher only, with a deep ammo supply
+    RocketArena,
+}
+
+/// Starting weapons (with their ammo) for a run
+#[derive(Clone)]
+pub struct Loadout {
+    pub weapons: Vec<WeaponStats>,
+}
+
+impl Loadout {
+    pub fn new(weapons: Vec<WeaponStats>) -> Self {
+        Self { weapons }
+    }
+
+    /// Inventory holding this loadout, with the first weapon selected
+    pub fn into_inventory(self) -> WeaponInventory {
+        WeaponInventory {
+            weapons: self.weapons,
+            current_index: 0,
+        }
+    }
+}
+
+impl RuleSet {
+    /// Cycle to the next rule set
+    pub fn next(self) -> Self {
+        match self {
+            RuleSet::Normal => RuleSet::Instagib,
+            RuleSet::Instagib => RuleSet::RocketArena,
+            RuleSet::RocketArena => RuleSet::Normal,
+        }
+    }
+
+    /// Get display name for this rule set
+    pub fn name(&self) -> &'static str {
+        match self {
+            RuleSet::Normal => "NORMAL",
+            RuleSet::Instagib => "INSTAGIB",
+            RuleSet::RocketArena => "ROCKET ARENA",
+        }
+    }
+
+    /// Player health at the start of a run
+    pub fn player_max_health(&self) -> f32 {
+        match self {
+            RuleSet::Normal | RuleSet::RocketArena => 100.0,
+            RuleSet::Instagib => 1.0,
+        }
+    }
+
+    /// Multiplier applied to every weapon's base damage
+    pub fn damage_multiplier(&self) -> f32 {
+        match self {
+            RuleSet::Normal | RuleSet::RocketArena => 1.0,
+            RuleSet::Instagib => 100.0,
+        }
+    }
+
+    /// Scale a weapon's stats for this rule set
+    pub fn apply_to_weapon(&self, weapon: &mut WeaponStats) {
+        weapon.damage *= self.damage_multiplier();
+
+        // Instagib machinegun becomes a slow, long-range railgun
+        if *self == RuleSet::Instagib && weapon.weapon_type == WeaponType::Machinegun {
+            weapon.fire_rate = 1.5;
+            weapon.range = 300.0;
+        }
+    }
+
+    /// Starting weapons and ammo for this rule set
+    pub fn loadout(&self) -> Loadout {
+        let mut weapons = match self {
+            RuleSet::Normal => vec![
+                WeaponStats::machinegun(),
+                WeaponStats::rocket_launcher(),
+                WeaponStats::sword(),
+                WeaponStats::shotgun(),
+                WeaponStats::grenade_launcher(),
+            ],
+            // Only the railgun - every shot has to count
+            RuleSet::Instagib => vec![WeaponStats::machinegun()],
+            RuleSet::RocketArena => {
+                let mut rockets = WeaponStats::rocket_launcher();
+                rockets.reserve_ammo = 100;
+                vec![rockets]
+            }
+        };
+
+        for weapon in &mut weapons {
+            self.apply_to_weapon(weapon);
+        }
+        Loadout::new(weapons)
+    }
+
+    /// Build a fresh inventory from this rule set's loadout
+    pub fn build_inventory(&self) -> WeaponInventory {
+        self.loadout().into_inventory()
+    }
+}
+
+/// Reset the player's health, armor and weapons for the active rule set when a run starts
+pub fn apply_ruleset(
+    ruleset: Res<RuleSet>,
+    mut player_query: Query<(&mut Health, Option<&mut Armor>, &mut WeaponInventory), With<Player>>,
+) {
+    let Ok((mut health, armor, mut inventory)) = player_query.single_mut() else {
+        return;
+    };
+
+    *health = Health::new(ruleset.player_max_health());
+    if let Some(mut armor) = armor {
+        *armor = Armor::default();
+    }
+    *inventory = ruleset.build_inventory();
+    info!("Rule set: {}", ruleset.name());
+}
+
+/// Cycle rule sets with Tab from the menu
+pub fn cycle_ruleset(keyboard: Res<ButtonInput<KeyCode>>, mut ruleset: ResMut<RuleSet>) {
+    if keyboard.just_pressed(KeyCode::Tab) {
+        *ruleset = ruleset.next();
+        info!("Rule set: {}", ruleset.name());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_ruleset_keeps_defaults() {
+        let inventory = RuleSet::Normal.build_inventory();
+        let defaults = WeaponInventory::default();
+
+        for (weapon, default) in inventory.weapons.iter().zip(defaults.weapons.iter()) {
+            assert_eq!(weapon.damage, default.damage);
+            assert_eq!(weapon.fire_rate, default.fire_rate);
+        }
+        assert_eq!(RuleSet::Normal.player_max_health(), 100.0);
+    }
+
+    #[test]
+    fn test_instagib_every_weapon_one_shots() {
+        let inventory = RuleSet::Instagib.build_inventory();
+
+        // Toughest regular enemy has 50 HP
+        for weapon in &inventory.weapons {
+            assert!(weapon.damage >= 50.0);
+        }
+    }
+
+    #[test]
+    fn test_normal_loadout_has_all_weapons() {
+        let inventory = RuleSet::Normal.build_inventory();
+        let types: Vec<_> = inventory.weapons.iter().map(|w| w.weapon_type).collect();
+
+        assert_eq!(
+            types,
+            vec![
+                WeaponType::Machinegun,
+                WeaponType::RocketLauncher,
+                WeaponType::Sword,
+                WeaponType::Shotgun,
+                WeaponType::GrenadeLauncher
+            ]
+        );
+        assert_eq!(inventory.current_index, 0);
+    }
+
+    #[test]
+    fn test_instagib_loadout_is_railgun_only() {
+        let inventory = RuleSet::Instagib.build_inventory();
+
+        assert_eq!(inventory.weapons.len(), 1);
+        assert_eq!(inventory.current().weapon_type, WeaponType::Machinegun);
+    }
+
+    #[test]
+    fn test_rocket_arena_loadout() {
+        let inventory = RuleSet::RocketArena.build_inventory();
+
+        assert_eq!(inventory.weapons.len(), 1);
+        let rockets = inventory.current();
+        assert_eq!(rockets.weapon_type, WeaponType::RocketLauncher);
+        assert_eq!(rockets.reserve_ammo, 100);
+        assert!(rockets.reserve_ammo > WeaponStats::rocket_launcher().reserve_ammo);
+        assert_eq!(rockets.damage, WeaponStats::rocket_launcher().damage);
+    }
+
+    #[test]
+    fn test_ruleset_cycle() {
+        assert_eq!(RuleSet::Normal.next(), RuleSet::Instagib);
+        assert_eq!(RuleSet::Instagib.next(), RuleSet::RocketArena);
+        assert_eq!(RuleSet::RocketArena.next(), RuleSet::Normal);
+    }
+
+    #[test]
+    fn test_instagib_machinegun_is_railgun() {
+        let inventory = RuleSet::Instagib.build_inventory();
+        let machinegun = &inventory.weapons[0];
+
+        assert_eq!(machinegun.weapon_type, WeaponType::Machinegun);
+        assert!(machinegun.fire_rate < WeaponStats::machinegun().fire_rate);
+    }
+
+    #[test]
+    fn test_instagib_player_dies_in_one_hit() {
+        let mut health = Health::new(RuleSet::Instagib.player_max_health());
+        health.take_damage(1.0);
+        assert!(health.is_dead());
+    }
+}
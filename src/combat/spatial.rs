@@ -0,0 +1,210 @@
+// Spatial grid - buckets damageable entities by where they stand, so hit checks only
+// look at targets in nearby cells instead of scanning every one
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::{Headbox, Health};
+
+/// How far from its position a target can be hit - the hitscan body sphere and
+/// projectile contact distance
+pub const TARGET_HIT_RADIUS: f32 = 1.0;
+
+/// Damageable entities bucketed into square cells on the ground plane. Rebuilt every frame
+/// by `rebuild_spatial_grid`; queries return a superset of the entities they could hit,
+/// so callers still run their exact test and get the same results as a full scan.
+#[derive(Resource)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    /// A few enemies wide - small enough to skip most of the arena, big enough that
+    /// blasts only touch a handful of cells
+    pub const DEFAULT_CELL_SIZE: f32 = 8.0;
+
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, position: Vec3) -> IVec2 {
+        (position.xz() / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Add an entity to every cell its bounding sphere overlaps (seen from above)
+    pub fn insert(&mut self, entity: Entity, position: Vec3, radius: f32) {
+        let min = self.cell(position - Vec3::splat(radius));
+        let max = self.cell(position + Vec3::splat(radius));
+        for x in min.x..=max.x {
+            for y in min.y..=max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+    }
+
+    /// Entities in any of the given cells, each listed once
+    fn collect(&self, cells: impl IntoIterator<Item = IVec2>) -> Vec<Entity> {
+        let mut entities: Vec<Entity> = cells
+            .into_iter()
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+        entities.sort_unstable();
+        entities.dedup();
+        entities
+    }
+
+    /// Entities that may be within `radius` of `center`
+    pub fn query_radius(&self, center: Vec3, radius: f32) -> Vec<Entity> {
+        let min = self.cell(center - Vec3::splat(radius));
+        let max = self.cell(center + Vec3::splat(radius));
+        self.collect((min.x..=max.x).flat_map(|x| (min.y..=max.y).map(move |y| IVec2::new(x, y))))
+    }
+
+    /// Entities whose hit sphere a ray may pass through before `max_distance`
+    pub fn query_ray(&self, origin: Vec3, direction: Vec3, max_distance: f32) -> Vec<Entity> {
+        // Samples a cell apart leave every point of the ray within half a cell of one,
+        // so the cell it lies in is always a neighbour of a sampled cell
+        let steps = (max_distance.max(0.0) / self.cell_size).ceil() as i32;
+        let mut cells = Vec::new();
+        for step in 0..=steps {
+            let distance = (step as f32 * self.cell_size).min(max_distance.max(0.0));
+            let cell = self.cell(origin + direction * distance);
+            for x in -1..=1 {
+                for y in -1..=1 {
+                    cells.push(cell + IVec2::new(x, y));
+                }
+            }
+        }
+        cells.sort_unstable_by_key(|cell| (cell.x, cell.y));
+        cells.dedup();
+        self.collect(cells)
+    }
+}
+
+/// Rebuild the grid from where every damageable entity is this frame. Runs before the
+/// frame's combat, so every system queries the same positions a full scan would see.
+pub fn rebuild_spatial_grid(
+    mut grid: ResMut<SpatialGrid>,
+    target_query: Query<(Entity, &GlobalTransform, Option<&Headbox>), With<Health>>,
+) {
+    grid.clear();
+    for (entity, transform, headbox) in &target_query {
+        // Heads can stick out past the body sphere
+        let reach = headbox.map_or(TARGET_HIT_RADIUS, |head| {
+            TARGET_HIT_RADIUS.max(head.offset.length() + head.radius)
+        });
+        grid.insert(entity, transform.translation(), reach);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::GameRng;
+
+    /// Random targets scattered over an arena, registered in a grid
+    fn random_scene(rng: &mut GameRng) -> (SpatialGrid, Vec<(Entity, Vec3)>) {
+        let mut grid = SpatialGrid::default();
+        let targets: Vec<(Entity, Vec3)> = (0..300)
+            .map(|index| {
+                let position = Vec3::new(rng.range(-60.0, 60.0), rng.range(0.0, 4.0), rng.range(-60.0, 60.0));
+                (Entity::from_raw(index), position)
+            })
+            .collect();
+        for &(entity, position) in &targets {
+            grid.insert(entity, position, TARGET_HIT_RADIUS);
+        }
+        (grid, targets)
+    }
+
+    fn random_direction(rng: &mut GameRng) -> Vec3 {
+        Vec3::new(rng.range(-1.0, 1.0), rng.range(-0.3, 0.3), rng.range(-1.0, 1.0)).normalize_or(Vec3::X)
+    }
+
+    /// Whether a ray passes within the hit radius of a target before `max_distance`
+    fn ray_hits(origin: Vec3, direction: Vec3, max_distance: f32, target: Vec3) -> bool {
+        let along = (target - origin).dot(direction);
+        (0.0..=max_distance).contains(&along) && target.distance(origin + direction * along) < TARGET_HIT_RADIUS
+    }
+
+    #[test]
+    fn test_grid_matches_brute_force_on_random_scene() {
+        let mut rng = GameRng::new(7);
+        let (grid, targets) = random_scene(&mut rng);
+        let position = |entity: Entity| targets[entity.index() as usize].1;
+
+        for _ in 0..200 {
+            let origin = Vec3::new(rng.range(-60.0, 60.0), rng.range(0.0, 4.0), rng.range(-60.0, 60.0));
+
+            // Blasts
+            let radius = rng.range(0.5, 12.0);
+            let brute: Vec<Entity> = targets
+                .iter()
+                .filter(|(_, target)| target.distance(origin) < radius)
+                .map(|(entity, _)| *entity)
+                .collect();
+            let gridded: Vec<Entity> = grid
+                .query_radius(origin, radius)
+                .into_iter()
+                .filter(|&entity| position(entity).distance(origin) < radius)
+                .collect();
+            assert_eq!(gridded, brute);
+
+            // Shots
+            let direction = random_direction(&mut rng);
+            let range = rng.range(1.0, 100.0);
+            let brute: Vec<Entity> = targets
+                .iter()
+                .filter(|(_, target)| ray_hits(origin, direction, range, *target))
+                .map(|(entity, _)| *entity)
+                .collect();
+            let gridded: Vec<Entity> = grid
+                .query_ray(origin, direction, range)
+                .into_iter()
+                .filter(|&entity| ray_hits(origin, direction, range, position(entity)))
+                .collect();
+            assert_eq!(gridded, brute);
+        }
+    }
+
+    #[test]
+    fn test_rebuild_covers_headboxes() {
+        let mut app = App::new();
+        app.init_resource::<SpatialGrid>()
+            .add_systems(Update, rebuild_spatial_grid);
+        let target = app
+            .world_mut()
+            .spawn((
+                Health::new(100.0),
+                GlobalTransform::from_translation(Vec3::new(7.5, 0.0, 0.0)),
+                Headbox {
+                    offset: Vec3::new(1.5, 0.0, 0.0),
+                    radius: 0.5,
+                },
+            ))
+            .id();
+        app.update();
+
+        // The head pokes into the next cell over, so a shot there still finds the target
+        let grid = app.world().resource::<SpatialGrid>();
+        assert!(grid.query_radius(Vec3::new(9.5, 0.0, 0.0), 0.1).contains(&target));
+        assert!(grid.query_radius(Vec3::new(-20.0, 0.0, 0.0), 1.0).is_empty());
+    }
+}
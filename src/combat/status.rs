@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+
+use super::damage::{DamageEvent, Health};
+
+/// Kind of damage-over-time effect
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StatusKind {
+    Burning,
+}
+
+/// Damage over time - deals `dps` in ticks of `TICK_INTERVAL` until it runs out.
+/// An entity has at most one; applying another refreshes it (see `stack`).
+#[derive(Component, Clone, Copy, Debug)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub dps: f32,
+    /// Seconds left
+    pub remaining: f32,
+    /// Seconds until the next damage tick
+    pub next_tick: f32,
+    /// Who applied it - credited with the tick damage
+    pub source: Option<Entity>,
+}
+
+impl StatusEffect {
+    /// Seconds between damage ticks
+    pub const TICK_INTERVAL: f32 = 0.5;
+
+    pub fn new(kind: StatusKind, dps: f32, duration: f32) -> Self {
+        Self {
+            kind,
+            dps,
+            remaining: duration,
+            next_tick: Self::TICK_INTERVAL,
+            source: None,
+        }
+    }
+
+    pub fn with_source(self, source: Option<Entity>) -> Self {
+        Self { source, ..self }
+    }
+
+    pub fn burning(dps: f32, duration: f32) -> Self {
+        Self::new(StatusKind::Burning, dps, duration)
+    }
+
+    /// Re-applying refreshes the duration - the stronger DPS wins, it never adds up
+    pub fn stack(&mut self, other: StatusEffect) {
+        self.kind = other.kind;
+        self.dps = self.dps.max(other.dps);
+        self.remaining = self.remaining.max(other.remaining);
+        self.source = other.source.or(self.source);
+    }
+
+    /// Advance by `dt` - returns the damage of any ticks that came due
+    pub fn tick(&mut self, dt: f32) -> Option<f32> {
+        // Don't tick past the end of the effect
+        let step = dt.min(self.remaining.max(0.0));
+        self.remaining -= dt;
+        self.next_tick -= step;
+
+        let mut ticks = 0;
+        // Small tolerance so summed frame times land on the tick
+        while self.next_tick <= 1e-4 {
+            self.next_tick += Self::TICK_INTERVAL;
+            ticks += 1;
+        }
+        (ticks > 0).then_some(self.dps * Self::TICK_INTERVAL * ticks as f32)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 1e-4
+    }
+}
+
+/// Add a status effect to `target`, stacking with the one it already has
+pub fn apply_status_effect(
+    commands: &mut Commands,
+    existing: Option<Mut<StatusEffect>>,
+    target: Entity,
+    effect: StatusEffect,
+) {
+    match existing {
+        Some(mut current) => current.stack(effect),
+        None => {
+            commands.entity(target).insert(effect);
+        }
+    }
+}
+
+/// Explosion that sets everything it damages on fire (except its own side)
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Incendiary {
+    pub dps: f32,
+    pub duration: f32,
+}
+
+impl Incendiary {
+    /// Grenade launcher rounds
+    pub const GRENADE: Incendiary = Incendiary { dps: 8.0, duration: 3.0 };
+
+    pub fn status(&self) -> StatusEffect {
+        StatusEffect::burning(self.dps, self.duration)
+    }
+}
+
+/// Tick status effects, sending their damage as `DamageEvent`s and removing expired ones
+pub fn update_status_effects(
+    mut commands: Commands,
+    mut status_query: Query<(Entity, &mut StatusEffect, &Health)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut status, health) in &mut status_query {
+        if health.is_dead() {
+            commands.entity(entity).remove::<StatusEffect>();
+            continue;
+        }
+
+        if let Some(amount) = status.tick(dt) {
+            damage_events.write(DamageEvent {
+                target: entity,
+                amount,
+                source: status.source,
+            });
+        }
+
+        if status.is_expired() {
+            commands.entity(entity).remove::<StatusEffect>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{process_damage_events, DeathEvent};
+
+    #[test]
+    fn test_burn_ticks_damage_until_expired() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .add_systems(Update, (update_status_effects, process_damage_events).chain());
+        // First frame has no delta
+        app.update();
+
+        let enemy = app
+            .world_mut()
+            .spawn((Health::new(100.0), StatusEffect::burning(10.0, 2.0)))
+            .id();
+        let health = |app: &App| app.world().get::<Health>(enemy).unwrap().current;
+
+        // One tick every half second
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!((health(&app) - 95.0).abs() < 0.001, "health {}", health(&app));
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!((health(&app) - 90.0).abs() < 0.001, "health {}", health(&app));
+
+        // Four ticks in total, then the burn is gone
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!((health(&app) - 80.0).abs() < 0.001, "health {}", health(&app));
+        assert!(app.world().get::<StatusEffect>(enemy).is_none());
+    }
+
+    #[test]
+    fn test_stacking_refreshes_without_adding_dps() {
+        let mut burn = StatusEffect::burning(10.0, 3.0);
+        burn.tick(2.0);
+        burn.stack(StatusEffect::burning(10.0, 3.0));
+        assert_eq!(burn.dps, 10.0);
+        assert_eq!(burn.remaining, 3.0);
+
+        // A weaker burn doesn't shorten or weaken it
+        burn.stack(StatusEffect::burning(4.0, 1.0));
+        assert_eq!(burn.dps, 10.0);
+        assert_eq!(burn.remaining, 3.0);
+    }
+}
@@ -1,17 +1,28 @@
+use std::collections::HashMap;
+
+use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
 
-use super::damage::{DamageEvent, Health};
-use crate::player::{Player, PlayerCamera};
-use crate::level::BoxCollider;
-use crate::GameState;
+use super::damage::{Blast, CombatConfig, DamageEvent, Faction, Headbox, Health};
+use super::effects::{spawn_decal, DecalStyle, EffectsConfig};
+use super::spatial::{SpatialGrid, TARGET_HIT_RADIUS};
+use super::status::{apply_status_effect, Incendiary, StatusEffect};
+use crate::audio::{SoundEffect, SoundEmitter};
+use crate::player::movement::Velocity;
+use crate::player::{Action, ActionInput, Ads, DeathCam, Player, PlayerCamera};
+use crate::level::{BoxCollider, GroundFloor, RayHit, Slope};
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+use crate::rng::GameRng;
 
 /// Weapon types available to the player
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
 pub enum WeaponType {
     #[default]
     Machinegun,   // 1 - Hitscan rapid fire
     RocketLauncher, // 2 - Projectile with explosion
     Sword,        // 3 - Melee swing
+    Shotgun,      // 4 - Hitscan pellet spread
+    GrenadeLauncher, // 5 - Bouncing grenades on a fuse
 }
 
 impl WeaponType {
@@ -20,20 +31,75 @@ impl WeaponType {
             WeaponType::Machinegun => "MACHINEGUN",
             WeaponType::RocketLauncher => "ROCKET",
             WeaponType::Sword => "SWORD",
+            WeaponType::Shotgun => "SHOTGUN",
+            WeaponType::GrenadeLauncher => "GRENADE",
+        }
+    }
+
+    /// Mark this weapon leaves on walls it hits (None = no decal)
+    pub fn decal_style(&self) -> Option<DecalStyle> {
+        match self {
+            WeaponType::Machinegun | WeaponType::Shotgun => Some(DecalStyle::bullet_hole()),
+            WeaponType::RocketLauncher | WeaponType::GrenadeLauncher => Some(DecalStyle::scorch()),
+            WeaponType::Sword => None,
+        }
+    }
+
+    /// Sound played each time this weapon fires
+    pub fn fire_sound(&self) -> SoundEffect {
+        match self {
+            WeaponType::Machinegun => SoundEffect::Machinegun,
+            WeaponType::RocketLauncher => SoundEffect::Rocket,
+            WeaponType::Sword => SoundEffect::SwordSwing,
+            WeaponType::Shotgun => SoundEffect::Shotgun,
+            WeaponType::GrenadeLauncher => SoundEffect::Grenade,
         }
     }
 }
 
-/// Individual weapon stats
+/// Individual weapon stats and state.
+///
+/// Weapon state transitions - every rule for how weapon actions interact lives in
+/// `WeaponStats`/`WeaponInventory`, so input systems only request transitions:
+///
+/// ```text
+///            fire()                        cooldown elapsed
+///   Ready ───────────▶ Cooling down ─────────────────────────▶ Ready
+///     │
+///     │ reload()                 reload_time elapsed
+///     └──────────────▶ Reloading ───────────────────▶ Ready (magazine topped up from reserve)
+///                          │
+///                          │ switch_to() another weapon / cancel_reload()
+///                          └──────────────────────────▶ Ready (ammo unchanged)
+/// ```
+///
+/// - Firing is blocked while reloading
+/// - Aiming down sights is blocked while reloading, so starting a reload drops ADS
+/// - Switching weapons cancels the outgoing weapon's reload
+/// - A reload can't start with a full magazine, an empty reserve, or while already reloading
 #[derive(Clone)]
 pub struct WeaponStats {
     pub weapon_type: WeaponType,
     pub damage: f32,
     pub fire_rate: f32,
+    /// Rounds in the magazine
     pub ammo: u32,
+    /// Magazine size
     pub max_ammo: u32,
+    /// Rounds carried outside the magazine, loaded by reloading
+    pub reserve_ammo: u32,
     pub range: f32,
     pub cooldown: f32,
+    /// Hitscan spread cone half-angle in radians (0 = perfectly accurate)
+    pub spread: f32,
+    /// Hitscan rays per shot, each dealing `damage` (1 for everything but the shotgun)
+    pub pellets: u32,
+    /// Seconds to refill the magazine
+    pub reload_time: f32,
+    /// Seconds left on an in-progress reload (None = not reloading)
+    pub reloading: Option<f32>,
+    /// Upward camera kick per shot in radians - springs back in update_view_sway
+    pub recoil: f32,
 }
 
 impl WeaponStats {
@@ -42,10 +108,16 @@ impl WeaponStats {
             weapon_type: WeaponType::Machinegun,
             damage: 15.0,
             fire_rate: 10.0,
-            ammo: 200,
-            max_ammo: 200,
+            ammo: 50,
+            max_ammo: 50,
+            reserve_ammo: 150,
             range: 150.0,
             cooldown: 0.0,
+            spread: 0.02,
+            pellets: 1,
+            reload_time: 1.5,
+            reloading: None,
+            recoil: 0.012,
         }
     }
 
@@ -54,10 +126,16 @@ impl WeaponStats {
             weapon_type: WeaponType::RocketLauncher,
             damage: 50.0,
             fire_rate: 1.0,
-            ammo: 20,
-            max_ammo: 20,
+            ammo: 5,
+            max_ammo: 5,
+            reserve_ammo: 15,
             range: 200.0,
             cooldown: 0.0,
+            spread: 0.0,
+            pellets: 1,
+            reload_time: 2.0,
+            reloading: None,
+            recoil: 0.08,
         }
     }
 
@@ -68,13 +146,92 @@ impl WeaponStats {
             fire_rate: 2.0,
             ammo: 999, // Unlimited
             max_ammo: 999,
+            reserve_ammo: 0,
             range: 3.0,
             cooldown: 0.0,
+            spread: 0.0,
+            pellets: 1,
+            reload_time: 0.0, // Never needs reloading
+            reloading: None,
+            recoil: 0.0,
+        }
+    }
+
+    pub fn shotgun() -> Self {
+        Self {
+            weapon_type: WeaponType::Shotgun,
+            damage: 10.0, // Per pellet
+            fire_rate: 1.2,
+            ammo: 8,
+            max_ammo: 8,
+            reserve_ammo: 24,
+            range: 60.0,
+            cooldown: 0.0,
+            spread: 0.08,
+            pellets: 8,
+            reload_time: 2.0,
+            reloading: None,
+            recoil: 0.05,
+        }
+    }
+
+    pub fn grenade_launcher() -> Self {
+        Self {
+            weapon_type: WeaponType::GrenadeLauncher,
+            damage: 45.0,
+            fire_rate: 1.2,
+            ammo: 6,
+            max_ammo: 6,
+            reserve_ammo: 18,
+            range: 100.0,
+            cooldown: 0.0,
+            spread: 0.0,
+            pellets: 1,
+            reload_time: 2.2,
+            reloading: None,
+            recoil: 0.06,
         }
     }
 
     pub fn can_fire(&self) -> bool {
-        self.cooldown <= 0.0 && self.ammo > 0
+        self.cooldown <= 0.0 && self.ammo > 0 && !self.is_reloading()
+    }
+
+    pub fn is_reloading(&self) -> bool {
+        self.reloading.is_some()
+    }
+
+    /// Begin moving reserve ammo into the magazine over `reload_time`.
+    /// Returns false if already reloading, the magazine is full, or the reserve is empty.
+    pub fn reload(&mut self) -> bool {
+        if self.is_reloading() || self.ammo >= self.max_ammo || self.reserve_ammo == 0 {
+            return false;
+        }
+        self.reloading = Some(self.reload_time);
+        true
+    }
+
+    /// Abandon an in-progress reload, leaving ammo as it was
+    pub fn cancel_reload(&mut self) {
+        self.reloading = None;
+    }
+
+    /// Advance an in-progress reload, loading the magazine from reserve when it finishes
+    pub fn update_reload(&mut self, dt: f32) {
+        let Some(remaining) = self.reloading else {
+            return;
+        };
+
+        let remaining = remaining - dt;
+        if remaining <= 0.0 {
+            // A short reserve only partially fills the magazine
+            let loaded = (self.max_ammo - self.ammo).min(self.reserve_ammo);
+            self.ammo += loaded;
+            self.reserve_ammo -= loaded;
+            self.reloading = None;
+        } else {
+            self.reloading = Some(remaining);
+        }
     }
 
     pub fn fire(&mut self) {
@@ -91,7 +248,10 @@ impl WeaponStats {
     }
 }
 
-/// Player's weapon inventory - holds all weapons
+/// Player's weapon inventory - holds all weapons.
+/// Lives on the `Player` entity itself (not its camera child); shots are aimed
+/// from the `PlayerCamera`'s GlobalTransform. Enemies never carry one - their
+/// attacks come from `Enemy` stats instead.
 #[derive(Component)]
 pub struct WeaponInventory {
     pub weapons: Vec<WeaponStats>,
@@ -105,6 +265,8 @@ impl Default for WeaponInventory {
                 WeaponStats::machinegun(),
                 WeaponStats::rocket_launcher(),
                 WeaponStats::sword(),
+                WeaponStats::shotgun(),
+                WeaponStats::grenade_launcher(),
             ],
             current_index: 0,
         }
@@ -120,36 +282,28 @@ impl WeaponInventory {
         &mut self.weapons[self.current_index]
     }
 
+    /// Switch weapons - the outgoing weapon's reload is cancelled
     pub fn switch_to(&mut self, index: usize) {
-        if index < self.weapons.len() {
+        if index < self.weapons.len() && index != self.current_index {
+            self.current_mut().cancel_reload();
             self.current_index = index;
         }
     }
-}
-
-/// Legacy Weapon component - now wraps WeaponInventory for compatibility
-#[derive(Component)]
-pub struct Weapon {
-    pub damage: f32,
-    pub fire_rate: f32,
-    pub spread: f32,
-    pub ammo: u32,
-    pub max_ammo: u32,
-    pub range: f32,
-    pub cooldown: f32,
-}
 
-impl Default for Weapon {
-    fn default() -> Self {
-        Self {
-            damage: 15.0,
-            fire_rate: 10.0,
-            spread: 0.02,
-            ammo: 200,
-            max_ammo: 200,
-            range: 150.0,
-            cooldown: 0.0,
+    /// Switch to the next (step > 0) or previous (step < 0) weapon, wrapping around
+    pub fn cycle(&mut self, step: i32) {
+        let len = self.weapons.len() as i32;
+        if len == 0 {
+            return;
         }
+        let index = (self.current_index as i32 + step).rem_euclid(len);
+        self.switch_to(index as usize);
+    }
+
+    /// Whether the current weapon can aim down sights (machinegun only, not while reloading)
+    pub fn can_aim(&self) -> bool {
+        let current = self.current();
+        current.weapon_type == WeaponType::Machinegun && !current.is_reloading()
     }
 }
 
@@ -165,6 +319,74 @@ pub struct PlayerProjectile {
     pub direction: Vec3,
     pub lifetime: f32,
     pub explosion_radius: f32,
+    /// Steer toward the nearest shootable in front of it
+    pub homing: bool,
+    /// Fastest a homing projectile can turn, in radians per second
+    pub turn_rate: f32,
+    /// Who fired it - credited with the blast's hits
+    pub owner: Option<Entity>,
+}
+
+impl PlayerProjectile {
+    /// Half-angle (radians) of the cone a homing projectile looks for targets in
+    pub const HOMING_CONE: f32 = 0.5;
+    /// Targets further than this are ignored by homing
+    pub const HOMING_RANGE: f32 = 80.0;
+    pub const ROCKET_TURN_RATE: f32 = 2.0;
+
+    /// Direction to the nearest target inside the homing cone
+    pub fn homing_direction(&self, position: Vec3, targets: impl IntoIterator<Item = Vec3>) -> Option<Vec3> {
+        targets
+            .into_iter()
+            .map(|target| target - position)
+            .filter(|offset| {
+                let distance = offset.length();
+                distance > 0.0
+                    && distance < Self::HOMING_RANGE
+                    && self.direction.angle_between(*offset) < Self::HOMING_CONE
+            })
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .map(Vec3::normalize)
+    }
+}
+
+/// Turn `direction` toward `desired` by at most `max_angle` radians
+pub fn steer_toward(direction: Vec3, desired: Vec3, max_angle: f32) -> Vec3 {
+    let angle = direction.angle_between(desired);
+    if angle <= max_angle {
+        return desired;
+    }
+    match direction.cross(desired).try_normalize() {
+        Some(axis) => (Quat::from_axis_angle(axis, max_angle) * direction).normalize(),
+        None => direction,
+    }
+}
+
+/// Grenade launcher round - falls under gravity, bounces off level geometry,
+/// and explodes when its fuse runs out or it touches a shootable, setting targets on fire
+#[derive(Component)]
+pub struct Grenade {
+    pub damage: f32,
+    pub velocity: Vec3,
+    /// Seconds until it goes off on its own
+    pub fuse: f32,
+    pub explosion_radius: f32,
+    /// Who threw it - credited with the blast's hits
+    pub owner: Option<Entity>,
+}
+
+impl Grenade {
+    pub const LAUNCH_SPEED: f32 = 22.0;
+    pub const GRAVITY: f32 = 20.0;
+    pub const FUSE: f32 = 2.5;
+    pub const RADIUS: f32 = 0.15;
+    /// Fraction of speed kept after each bounce
+    pub const BOUNCE_DAMPING: f32 = 0.55;
+
+    /// Velocity after hitting a surface with `normal` - reflected and slowed
+    pub fn bounce(velocity: Vec3, normal: Vec3) -> Vec3 {
+        velocity.reflect(normal) * Self::BOUNCE_DAMPING
+    }
 }
 
 /// Explosion effect
@@ -175,29 +397,139 @@ pub struct Explosion {
     pub damage: f32,
     pub lifetime: f32,
     pub has_damaged: bool,
+    /// Speed (m/s) added to the player at the blast center, falling off to 0 at max_radius
+    pub knockback_strength: f32,
+    /// Who set it off - decides which targets it hurts
+    pub faction: Faction,
+    /// Entity credited with its hits (the player for rockets)
+    pub owner: Option<Entity>,
+}
+
+impl Explosion {
+    pub const ROCKET_KNOCKBACK: f32 = 14.0;
+    /// Knockback never pushes the player past this speed (the bhop speed cap)
+    pub const MAX_KNOCKBACK_SPEED: f32 = 30.0;
+
+    /// Velocity change for something at `position`, pushed away from the blast center
+    pub fn knockback_at(&self, center: Vec3, position: Vec3) -> Vec3 {
+        let offset = position - center;
+        let dist = offset.length();
+        if dist >= self.max_radius {
+            return Vec3::ZERO;
+        }
+
+        let falloff = 1.0 - dist / self.max_radius;
+        offset.normalize_or(Vec3::Y) * self.knockback_strength * falloff
+    }
 }
 
-/// Sword swing effect
+/// Sword swing - the blade sweeps right to left through an arc in front of where the
+/// player was looking, hitting the first target inside that arc
 #[derive(Component)]
 pub struct SwordSwing {
     pub damage: f32,
     pub lifetime: f32,
     pub has_hit: bool,
+    /// Camera position when the swing started - the arc is centered here
+    pub origin: Vec3,
+    /// Camera facing when the swing started
+    pub forward: Vec3,
+    /// Axis the blade sweeps around (camera up)
+    pub up: Vec3,
+    /// Who is swinging - credited with the hit
+    pub owner: Option<Entity>,
+}
+
+impl SwordSwing {
+    pub const DURATION: f32 = 0.15;
+    /// How far from the camera the blade reaches
+    pub const REACH: f32 = 3.0;
+    /// Half-angle of the arc, either side of the facing direction
+    pub const HALF_ARC: f32 = std::f32::consts::FRAC_PI_3;
+    /// Distance from the camera to the middle of the blade
+    pub const BLADE_OFFSET: f32 = 1.2;
+    /// Enemies in the arc within this range pull the player into a lunge
+    pub const LUNGE_RANGE: f32 = 4.5;
+    /// Speed a lunge adds toward its target
+    pub const LUNGE_SPEED: f32 = 8.0;
+    pub const LUNGE_DURATION: f32 = 0.2;
+
+    pub fn new(damage: f32, origin: Vec3, forward: Vec3, up: Vec3) -> Self {
+        Self {
+            damage,
+            lifetime: Self::DURATION,
+            has_hit: false,
+            origin,
+            forward,
+            up,
+            owner: None,
+        }
+    }
+
+    /// Whether `position` is within reach and inside the arc in front of the swing
+    pub fn in_arc(&self, position: Vec3) -> bool {
+        self.in_arc_within(position, Self::REACH)
+    }
+
+    /// Whether `position` is inside the arc and close enough to lunge at
+    pub fn in_lunge_range(&self, position: Vec3) -> bool {
+        self.in_arc_within(position, Self::LUNGE_RANGE)
+    }
+
+    fn in_arc_within(&self, position: Vec3, range: f32) -> bool {
+        let offset = position - self.origin;
+        if offset.length() > range {
+            return false;
+        }
+        offset.length() < 0.01 || self.forward.angle_between(offset) <= Self::HALF_ARC
+    }
+
+    /// Blade angle around `up` - starts at the right edge of the arc and ends at the left
+    pub fn sweep_angle(&self) -> f32 {
+        let progress = (1.0 - self.lifetime / Self::DURATION).clamp(0.0, 1.0);
+        -Self::HALF_ARC + 2.0 * Self::HALF_ARC * progress
+    }
+
+    /// Direction the blade points at this point of the swing
+    pub fn blade_direction(&self) -> Vec3 {
+        Quat::from_axis_angle(self.up, self.sweep_angle()) * self.forward
+    }
+
+    /// Blade placement at this point of the swing, tilted for a diagonal slash
+    pub fn blade_transform(&self) -> Transform {
+        let direction = self.blade_direction();
+        Transform::from_translation(self.origin + direction * Self::BLADE_OFFSET)
+            .looking_to(direction, self.up)
+            * Transform::from_rotation(Quat::from_rotation_z(0.3))
+    }
+}
+
+/// Sword lunge in progress on the player - running into a wall takes its push back
+#[derive(Component)]
+pub struct SwordLunge {
+    /// Horizontal direction of the push
+    pub direction: Vec3,
+    /// Seconds until the lunge is over and walls no longer cancel it
+    pub remaining: f32,
+}
+
+impl SwordLunge {
+    /// Velocity with whatever is left of the lunge's push removed
+    pub fn cancel(&self, velocity: Vec3) -> Vec3 {
+        let along = velocity.dot(self.direction).clamp(0.0, SwordSwing::LUNGE_SPEED);
+        velocity - self.direction * along
+    }
 }
 
 /// Muzzle flash visual effect
 #[derive(Component)]
 pub struct MuzzleFlash {
     pub lifetime: f32,
-    pub max_lifetime: f32,
 }
 
 impl MuzzleFlash {
     pub fn new() -> Self {
-        Self {
-            lifetime: 0.05,
-            max_lifetime: 0.05,
-        }
+        Self { lifetime: 0.05 }
     }
 }
 
@@ -205,7 +537,15 @@ impl MuzzleFlash {
 #[derive(Component)]
 pub struct MuzzleFlashLight;
 
-/// Update weapon cooldowns
+/// Hitscan bullet tracer - a thin streak from the muzzle to the impact, timed out by its MuzzleFlash
+#[derive(Component)]
+pub struct Tracer;
+
+/// Spark where a hitscan round struck something, timed out by its MuzzleFlash
+#[derive(Component)]
+pub struct HitSpark;
+
+/// Update weapon cooldowns and reloads
 pub fn update_weapon_cooldowns(
     mut inventory_query: Query<&mut WeaponInventory>,
     time: Res<Time>,
@@ -214,44 +554,121 @@ pub fn update_weapon_cooldowns(
     for mut inventory in &mut inventory_query {
         for weapon in &mut inventory.weapons {
             weapon.update_cooldown(dt);
+            weapon.update_reload(dt);
+        }
+    }
+}
+
+/// Magazine ammo that trickles back over time, for pickup-free play.
+/// Rates are rounds per second per weapon type - missing or 0 means no regen,
+/// so the default turns it off entirely.
+#[derive(Resource, Default)]
+pub struct AmmoRegen {
+    pub rates: HashMap<WeaponType, f32>,
+    /// Partial rounds built up toward the next one, per weapon type
+    progress: HashMap<WeaponType, f32>,
+}
+
+impl AmmoRegen {
+    pub fn rate(&self, weapon_type: WeaponType) -> f32 {
+        self.rates.get(&weapon_type).copied().unwrap_or(0.0)
+    }
+
+    /// Top up a weapon's magazine by `dt` worth of regen. Nothing comes back while the
+    /// weapon is firing (cooling down between shots) or reloading, or once it's full.
+    pub fn regenerate(&mut self, weapon: &mut WeaponStats, dt: f32) {
+        let rate = self.rate(weapon.weapon_type);
+        if rate <= 0.0 || weapon.cooldown > 0.0 || weapon.is_reloading() || weapon.ammo >= weapon.max_ammo {
+            self.progress.remove(&weapon.weapon_type);
+            return;
+        }
+
+        let progress = self.progress.entry(weapon.weapon_type).or_default();
+        *progress += rate * dt;
+        let rounds = progress.floor();
+        *progress -= rounds;
+        weapon.ammo = (weapon.ammo + rounds as u32).min(weapon.max_ammo);
+    }
+}
+
+/// Regenerate ammo for every weapon the player carries, when `AmmoRegen` is on
+pub fn regenerate_ammo(
+    mut regen: ResMut<AmmoRegen>,
+    mut inventory_query: Query<&mut WeaponInventory, With<Player>>,
+    time: Res<Time>,
+) {
+    if regen.rates.values().all(|&rate| rate <= 0.0) {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    for mut inventory in &mut inventory_query {
+        for weapon in &mut inventory.weapons {
+            regen.regenerate(weapon, dt);
         }
     }
 }
 
-/// Handle weapon switching with number keys
+/// Handle weapon switching with the weapon slot keys, or cycle with the scroll wheel
 pub fn handle_weapon_switch(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut inventory_query: Query<&mut WeaponInventory, With<Player>>,
+) {
+    let scroll: f32 = mouse_wheel.read().map(|event| event.y).sum();
+
+    let Ok(mut inventory) = inventory_query.single_mut() else {
+        return;
+    };
+
+    if let Some(slot) = Action::WEAPON_SLOTS.iter().position(|&slot| input.just_pressed(slot)) {
+        inventory.switch_to(slot);
+    } else if scroll > 0.0 {
+        // Scroll up = next weapon
+        inventory.cycle(1);
+    } else if scroll < 0.0 {
+        inventory.cycle(-1);
+    }
+}
+
+/// Reload the current weapon on the reload key, or automatically once the magazine runs dry
+pub fn handle_reload(
+    input: ActionInput,
     mut inventory_query: Query<&mut WeaponInventory, With<Player>>,
 ) {
     let Ok(mut inventory) = inventory_query.single_mut() else {
         return;
     };
 
-    if keyboard.just_pressed(KeyCode::Digit1) {
-        inventory.switch_to(0);
-    } else if keyboard.just_pressed(KeyCode::Digit2) {
-        inventory.switch_to(1);
-    } else if keyboard.just_pressed(KeyCode::Digit3) {
-        inventory.switch_to(2);
+    let weapon = inventory.current_mut();
+    if (input.just_pressed(Action::Reload) || weapon.ammo == 0) && weapon.reload() {
+        info!("Reloading {}", weapon.weapon_type.name());
     }
 }
 
 /// Handle shooting input based on current weapon
+#[allow(clippy::too_many_arguments)]
 pub fn handle_shooting(
-    mouse_button: Res<ButtonInput<MouseButton>>,
-    mut player_query: Query<(Entity, &mut WeaponInventory), With<Player>>,
-    camera_query: Query<(&GlobalTransform, &PlayerCamera)>,
-    shootable_query: Query<(Entity, &GlobalTransform), (With<Shootable>, With<Health>)>,
+    input: ActionInput,
+    mut player_query: Query<(Entity, &mut WeaponInventory, &Ads), With<Player>>,
+    mut camera_query: Query<(&GlobalTransform, &mut PlayerCamera), Without<DeathCam>>,
+    shootable_query: HitscanTargets,
+    grid: Res<SpatialGrid>,
+    combat_config: Res<CombatConfig>,
+    // Slope colliders are boxes around the ramp - they would stop shots in the air above it
+    collider_query: Query<(&Transform, &BoxCollider, Has<GroundFloor>), Without<Slope>>,
+    effects_config: Res<EffectsConfig>,
     mut damage_events: EventWriter<DamageEvent>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
 ) {
-    if !mouse_button.pressed(MouseButton::Left) {
+    if !input.pressed(Action::Fire) {
         return;
     }
 
-    let Ok((player_entity, mut inventory)) = player_query.single_mut() else {
+    let Ok((player_entity, mut inventory, ads)) = player_query.single_mut() else {
         return;
     };
 
@@ -259,28 +676,60 @@ pub fn handle_shooting(
         return;
     }
 
-    let Ok((camera_transform, _camera)) = camera_query.single() else {
+    let Ok((camera_transform, mut camera)) = camera_query.single_mut() else {
         return;
     };
 
     let weapon_type = inventory.current().weapon_type;
     let damage = inventory.current().damage;
     let range = inventory.current().range;
+    let spread = inventory.current().spread * ads.spread_multiplier();
+    let pellets = inventory.current().pellets;
 
     // Fire the weapon
     inventory.current_mut().fire();
+    camera.kick(inventory.current().recoil);
+    commands.spawn((
+        SoundEmitter(weapon_type.fire_sound()),
+        Transform::from_translation(muzzle_position(camera_transform)),
+    ));
 
     match weapon_type {
-        WeaponType::Machinegun => {
-            // Hitscan
-            fire_hitscan(
-                player_entity,
-                camera_transform,
-                &shootable_query,
-                &mut damage_events,
-                damage,
-                range,
-            );
+        WeaponType::Machinegun | WeaponType::Shotgun => {
+            // Hitscan - one ray per pellet, each scattered within the spread cone
+            let origin = camera_transform.translation();
+            for _ in 0..pellets {
+                let direction = spread_direction(camera_transform.forward().as_vec3(), spread, &mut rng);
+                let (hit_enemy, distance) = fire_hitscan(
+                    player_entity,
+                    origin,
+                    direction,
+                    &shootable_query,
+                    &grid,
+                    collider_query.iter(),
+                    &mut damage_events,
+                    damage,
+                    range,
+                    combat_config.headshot_multiplier,
+                );
+
+                // Missed shots leave a mark on whatever wall they struck
+                if !hit_enemy
+                    && let (Some(hit), Some(style)) = (
+                        raycast_level(collider_query.iter(), origin, direction, range, effects_config.decals_on_floor),
+                        weapon_type.decal_style(),
+                    )
+                {
+                    spawn_decal(&mut commands, &mut meshes, &mut materials, hit.point, hit.normal, style);
+                }
+
+                let impact = origin + direction * distance;
+                spawn_tracer(&mut commands, &mut meshes, &mut materials, muzzle_position(camera_transform), impact);
+                if distance < range {
+                    spawn_hit_spark(&mut commands, &mut meshes, &mut materials, impact);
+                }
+            }
+
             spawn_muzzle_flash(&mut commands, &mut meshes, &mut materials, camera_transform);
         }
         WeaponType::RocketLauncher => {
@@ -291,6 +740,18 @@ pub fn handle_shooting(
                 &mut materials,
                 camera_transform,
                 damage,
+                combat_config.homing_rockets,
+                player_entity,
+            );
+        }
+        WeaponType::GrenadeLauncher => {
+            spawn_grenade(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                camera_transform,
+                damage,
+                player_entity,
             );
         }
         WeaponType::Sword => {
@@ -301,52 +762,149 @@ pub fn handle_shooting(
                 &mut materials,
                 camera_transform,
                 damage,
+                player_entity,
             );
         }
     }
 }
 
-/// Fire hitscan weapon
-fn fire_hitscan(
+/// Deflect an aim direction randomly within a cone of the given half-angle.
+/// Draws from the shared `GameRng`, so a seeded rng gives reproducible shots.
+pub fn spread_direction(direction: Vec3, spread: f32, rng: &mut GameRng) -> Vec3 {
+    if spread <= 0.0 {
+        return direction;
+    }
+
+    let angle = rng.f32() * std::f32::consts::TAU;
+    // sqrt for an even distribution over the cone's cross-section
+    let radius = rng.f32().sqrt() * spread.tan();
+
+    let (a, b) = direction.any_orthonormal_pair();
+    (direction + (a * angle.cos() + b * angle.sin()) * radius).normalize()
+}
+
+/// Hitscan targets - a headbox, if present, takes bonus damage
+pub type HitscanTargets<'w, 's> =
+    Query<'w, 's, (Entity, &'static GlobalTransform, Option<&'static Headbox>), (With<Shootable>, With<Health>)>;
+
+/// Distance along a ray to where it enters a sphere, if its closest approach to `center`
+/// (within `max_distance`) passes within `radius`
+fn ray_sphere_distance(origin: Vec3, direction: Vec3, center: Vec3, radius: f32, max_distance: f32) -> Option<f32> {
+    let distance_along_ray = (center - origin).dot(direction);
+    if distance_along_ray < 0.0 || distance_along_ray > max_distance {
+        return None;
+    }
+
+    let miss = center.distance(origin + direction * distance_along_ray);
+    (miss < radius).then(|| (distance_along_ray - (radius * radius - miss * miss).sqrt()).max(0.0))
+}
+
+/// Fire hitscan weapon - returns whether a shootable was hit, and the distance to whatever
+/// stopped the round (`range` if nothing did).
+/// Level geometry (walls, pillars, floor) blocks the shot. Headbox hits are checked
+/// before the body and deal `damage * headshot_multiplier`.
+#[allow(clippy::too_many_arguments)]
+fn fire_hitscan<'a>(
     player_entity: Entity,
-    camera_transform: &GlobalTransform,
-    shootable_query: &Query<(Entity, &GlobalTransform), (With<Shootable>, With<Health>)>,
+    ray_origin: Vec3,
+    ray_direction: Vec3,
+    shootable_query: &HitscanTargets,
+    grid: &SpatialGrid,
+    colliders: impl IntoIterator<Item = (&'a Transform, &'a BoxCollider, bool)>,
     damage_events: &mut EventWriter<DamageEvent>,
     damage: f32,
     range: f32,
-) {
-    let ray_origin = camera_transform.translation();
-    let ray_direction = camera_transform.forward().as_vec3();
+    headshot_multiplier: f32,
+) -> (bool, f32) {
+    // Nothing past the first wall can be hit
+    let max_distance = raycast_level(colliders, ray_origin, ray_direction, range, true)
+        .map_or(range, |wall| wall.distance);
 
-    let mut closest_hit: Option<(Entity, f32)> = None;
+    // (target, distance along ray, headshot)
+    let mut closest_hit: Option<(Entity, f32, bool)> = None;
 
-    for (entity, transform) in shootable_query {
-        let to_target = transform.translation() - ray_origin;
-        let distance_along_ray = to_target.dot(ray_direction);
-
-        if distance_along_ray < 0.0 || distance_along_ray > range {
+    for target in grid.query_ray(ray_origin, ray_direction, max_distance) {
+        let Ok((entity, transform, headbox)) = shootable_query.get(target) else {
             continue;
-        }
-
-        let closest_point = ray_origin + ray_direction * distance_along_ray;
-        let distance_to_center = (transform.translation() - closest_point).length();
-
-        let hit_radius = 1.0;
-
-        if distance_to_center < hit_radius {
-            if closest_hit.is_none() || distance_along_ray < closest_hit.unwrap().1 {
-                closest_hit = Some((entity, distance_along_ray));
-            }
+        };
+        let center = transform.translation();
+        let head_hit = headbox.and_then(|head| {
+            ray_sphere_distance(ray_origin, ray_direction, center + head.offset, head.radius, max_distance)
+        });
+        let hit = match head_hit {
+            Some(distance) => Some((distance, true)),
+            None => ray_sphere_distance(ray_origin, ray_direction, center, TARGET_HIT_RADIUS, max_distance)
+                .map(|distance| (distance, false)),
+        };
+
+        if let Some((distance, headshot)) = hit
+            && closest_hit.is_none_or(|(_, closest, _)| distance < closest)
+        {
+            closest_hit = Some((entity, distance, headshot));
         }
     }
 
-    if let Some((hit_entity, _distance)) = closest_hit {
+    if let Some((hit_entity, distance, headshot)) = closest_hit {
+        let amount = if headshot { damage * headshot_multiplier } else { damage };
         damage_events.write(DamageEvent {
             target: hit_entity,
-            amount: damage,
+            amount,
             source: Some(player_entity),
         });
+        return (true, distance);
+    }
+
+    (false, max_distance)
+}
+
+/// Find the closest level surface along a ray
+/// The ground floor's collider is a thick slab centered on the visible plane,
+/// so floor hits are snapped down onto the plane itself.
+fn raycast_level<'a>(
+    colliders: impl IntoIterator<Item = (&'a Transform, &'a BoxCollider, bool)>,
+    origin: Vec3,
+    direction: Vec3,
+    max_distance: f32,
+    include_floor: bool,
+) -> Option<RayHit> {
+    let mut closest: Option<RayHit> = None;
+
+    for (transform, collider, is_floor) in colliders {
+        if is_floor && !include_floor {
+            continue;
+        }
+
+        let center = transform.translation;
+        let Some(mut hit) = collider.ray_intersection(center, origin, direction, max_distance) else {
+            continue;
+        };
+
+        if is_floor {
+            let Some(plane_distance) = ray_plane_distance(origin, direction, center.y) else {
+                continue;
+            };
+            hit = RayHit {
+                distance: plane_distance,
+                point: origin + direction * plane_distance,
+                normal: Vec3::Y,
+            };
+        }
+
+        if closest.is_none_or(|c| hit.distance < c.distance) {
+            closest = Some(hit);
+        }
     }
+
+    closest
+}
+
+/// Distance along a ray to the horizontal plane at `plane_y`, if it is in front of the ray
+fn ray_plane_distance(origin: Vec3, direction: Vec3, plane_y: f32) -> Option<f32> {
+    if direction.y.abs() < 1e-6 {
+        return None;
+    }
+    let t = (plane_y - origin.y) / direction.y;
+    (t >= 0.0).then_some(t)
 }
 
 /// Spawn a rocket projectile
@@ -356,6 +914,8 @@ fn spawn_rocket(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     camera_transform: &GlobalTransform,
     damage: f32,
+    homing: bool,
+    owner: Entity,
 ) {
     let direction = camera_transform.forward().as_vec3();
     let spawn_pos = camera_transform.translation() + direction * 1.0;
@@ -379,6 +939,9 @@ fn spawn_rocket(
             direction,
             lifetime: 5.0,
             explosion_radius: 5.0,
+            homing,
+            turn_rate: PlayerProjectile::ROCKET_TURN_RATE,
+            owner: Some(owner),
         },
     ));
 
@@ -392,7 +955,41 @@ fn spawn_rocket(
             ..default()
         },
         Transform::from_translation(spawn_pos),
-        MuzzleFlash { lifetime: 0.1, max_lifetime: 0.1 },
+        MuzzleFlash { lifetime: 0.1 },
+    ));
+}
+
+/// Lob a grenade - launched slightly upward from the aim direction so it arcs
+fn spawn_grenade(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    camera_transform: &GlobalTransform,
+    damage: f32,
+    owner: Entity,
+) {
+    let direction = camera_transform.forward().as_vec3();
+    let spawn_pos = camera_transform.translation() + direction * 1.0;
+    let launch = (direction + Vec3::Y * 0.15).normalize();
+
+    let grenade_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.3, 0.5, 0.2),
+        emissive: LinearRgba::rgb(0.5, 1.5, 0.3),
+        unlit: true,
+        ..default()
+    });
+
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(Grenade::RADIUS))),
+        MeshMaterial3d(grenade_material),
+        Transform::from_translation(spawn_pos),
+        Grenade {
+            damage,
+            velocity: launch * Grenade::LAUNCH_SPEED,
+            fuse: Grenade::FUSE,
+            explosion_radius: 5.0,
+            owner: Some(owner),
+        },
     ));
 }
 
@@ -403,9 +1000,17 @@ fn spawn_sword_swing(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     camera_transform: &GlobalTransform,
     damage: f32,
+    owner: Entity,
 ) {
-    let direction = camera_transform.forward().as_vec3();
-    let spawn_pos = camera_transform.translation() + direction * 1.5 + camera_transform.right().as_vec3() * 0.3;
+    let swing = SwordSwing {
+        owner: Some(owner),
+        ..SwordSwing::new(
+            damage,
+            camera_transform.translation(),
+            camera_transform.forward().as_vec3(),
+            camera_transform.up().as_vec3(),
+        )
+    };
 
     let sword_material = materials.add(StandardMaterial {
         base_color: Color::srgb(0.8, 0.8, 1.0),
@@ -414,27 +1019,24 @@ fn spawn_sword_swing(
         ..default()
     });
 
-    // Sword slash arc
+    // Blade - long axis points away from the player, swept around by update_sword_swings
     commands.spawn((
-        Mesh3d(meshes.add(Cuboid::new(0.1, 0.8, 1.5))),
+        Mesh3d(meshes.add(Cuboid::new(0.1, 0.3, 1.6))),
         MeshMaterial3d(sword_material),
-        Transform::from_translation(spawn_pos)
-            .looking_to(direction, Vec3::Y)
-            .with_rotation(Quat::from_rotation_z(0.3)),
-        SwordSwing {
-            damage,
-            lifetime: 0.15,
-            has_hit: false,
-        },
+        swing.blade_transform(),
+        swing,
     ));
 }
 
 /// Update player projectiles
+#[allow(clippy::too_many_arguments)]
 pub fn update_player_projectiles(
     mut commands: Commands,
     mut projectile_query: Query<(Entity, &mut Transform, &mut PlayerProjectile)>,
-    shootable_query: Query<(Entity, &GlobalTransform), (With<Shootable>, With<Health>)>,
-    collider_query: Query<(&Transform, &BoxCollider), Without<PlayerProjectile>>,
+    shootable_query: Query<(&GlobalTransform, &Health), With<Shootable>>,
+    grid: Res<SpatialGrid>,
+    collider_query: Query<(&Transform, &BoxCollider, Has<GroundFloor>), Without<PlayerProjectile>>,
+    effects_config: Res<EffectsConfig>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     time: Res<Time>,
@@ -442,7 +1044,21 @@ pub fn update_player_projectiles(
     let dt = time.delta_secs();
 
     for (entity, mut transform, mut projectile) in &mut projectile_query {
+        // Homing projectiles turn toward the nearest living target ahead of them
+        if projectile.homing {
+            let targets = shootable_query
+                .iter()
+                .filter(|(_, health)| !health.is_dead())
+                .map(|(target, _)| target.translation());
+            if let Some(desired) = projectile.homing_direction(transform.translation, targets) {
+                projectile.direction = steer_toward(projectile.direction, desired, projectile.turn_rate * dt);
+                let direction = projectile.direction;
+                transform.look_to(direction, Vec3::Y);
+            }
+        }
+
         // Move projectile
+        let prev_pos = transform.translation;
         transform.translation += projectile.direction * projectile.speed * dt;
 
         // Update lifetime
@@ -453,30 +1069,42 @@ pub fn update_player_projectiles(
         }
 
         let proj_pos = transform.translation;
-        let mut should_explode = false;
+        let mut hit_wall = false;
 
         // Check collision with enemies
-        for (enemy_entity, enemy_transform) in &shootable_query {
-            let dist = (enemy_transform.translation() - proj_pos).length();
-            if dist < 1.0 {
-                should_explode = true;
-                break;
-            }
-        }
+        let mut should_explode = grid
+            .query_radius(proj_pos, TARGET_HIT_RADIUS)
+            .into_iter()
+            .filter_map(|target| shootable_query.get(target).ok())
+            .any(|(enemy_transform, _)| enemy_transform.translation().distance(proj_pos) < TARGET_HIT_RADIUS);
 
         // Check collision with walls/floors (all BoxColliders)
-        for (collider_transform, collider) in &collider_query {
-            let collider_pos = collider_transform.translation;
-            let half = collider.half_extents;
-
-            let diff = proj_pos - collider_pos;
-            if diff.x.abs() < half.x && diff.y.abs() < half.y && diff.z.abs() < half.z {
-                should_explode = true;
-                break;
+        if !should_explode {
+            for (collider_transform, collider, _) in &collider_query {
+                let collider_pos = collider_transform.translation;
+                let half = collider.half_extents;
+
+                let diff = proj_pos - collider_pos;
+                if diff.x.abs() < half.x && diff.y.abs() < half.y && diff.z.abs() < half.z {
+                    should_explode = true;
+                    hit_wall = true;
+                    break;
+                }
             }
         }
 
         if should_explode {
+            // Scorch the surface the rocket entered this frame
+            if hit_wall {
+                let travel = projectile.speed * dt + 0.1;
+                if let (Some(hit), Some(style)) = (
+                    raycast_level(collider_query.iter(), prev_pos, projectile.direction, travel, effects_config.decals_on_floor),
+                    WeaponType::RocketLauncher.decal_style(),
+                ) {
+                    spawn_decal(&mut commands, &mut meshes, &mut materials, hit.point, hit.normal, style);
+                }
+            }
+
             // Spawn explosion
             spawn_explosion(
                 &mut commands,
@@ -485,31 +1113,115 @@ pub fn update_player_projectiles(
                 proj_pos,
                 projectile.damage,
                 projectile.explosion_radius,
+                None,
+                projectile.owner,
             );
             commands.entity(entity).despawn();
         }
     }
 }
 
-/// Spawn explosion effect
-fn spawn_explosion(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    position: Vec3,
-    damage: f32,
-    radius: f32,
-) {
-    let explosion_material = materials.add(StandardMaterial {
-        base_color: Color::srgba(1.0, 0.6, 0.2, 0.8),
-        emissive: LinearRgba::rgb(5.0, 2.0, 0.5),
-        unlit: true,
-        alpha_mode: AlphaMode::Blend,
-        ..default()
-    });
+/// Surface normal of a box a grenade moved into this frame, if it did.
+/// Boxes are grown by the grenade's radius; the face crossed is the one the grenade was furthest outside of.
+/// The ground floor's collider is a thick slab, so it is treated as the plane at its center instead.
+fn grenade_contact_normal(prev: Vec3, next: Vec3, center: Vec3, collider: &BoxCollider, is_floor: bool) -> Option<Vec3> {
+    let half = collider.half_extents + Vec3::splat(Grenade::RADIUS);
+    let local_next = next - center;
+    let local_prev = prev - center;
+
+    if is_floor {
+        let within = local_next.x.abs() < half.x && local_next.z.abs() < half.z;
+        return (within && local_next.y < Grenade::RADIUS && local_prev.y >= local_next.y).then_some(Vec3::Y);
+    }
 
-    // Explosion sphere
-    commands.spawn((
+    if local_next.abs().cmpge(half).any() {
+        return None;
+    }
+
+    // How far outside each face the grenade was before this step
+    let outside = local_prev.abs() - half;
+    let axis = if outside.x >= outside.y && outside.x >= outside.z {
+        Vec3::X
+    } else if outside.y >= outside.z {
+        Vec3::Y
+    } else {
+        Vec3::Z
+    };
+    let side = if local_prev.dot(axis) < 0.0 { -1.0 } else { 1.0 };
+    Some(axis * side)
+}
+
+/// Fly grenades under gravity, bounce them off level geometry, and set them off
+/// on their fuse or on contact with a shootable
+pub fn update_grenades(
+    mut commands: Commands,
+    mut grenade_query: Query<(Entity, &mut Transform, &mut Grenade)>,
+    shootable_query: Query<&GlobalTransform, (With<Shootable>, With<Health>)>,
+    collider_query: Query<(&Transform, &BoxCollider, Has<GroundFloor>), Without<Grenade>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut transform, mut grenade) in &mut grenade_query {
+        grenade.velocity.y -= Grenade::GRAVITY * dt;
+
+        let prev = transform.translation;
+        let mut next = prev + grenade.velocity * dt;
+        for (collider_transform, collider, is_floor) in &collider_query {
+            if let Some(normal) = grenade_contact_normal(prev, next, collider_transform.translation, collider, is_floor) {
+                grenade.velocity = Grenade::bounce(grenade.velocity, normal);
+                // Stay on the near side of the surface this frame
+                next = prev;
+                break;
+            }
+        }
+        transform.translation = next;
+
+        grenade.fuse -= dt;
+        let touching_shootable = shootable_query
+            .iter()
+            .any(|target| target.translation().distance(next) < 1.0);
+
+        if grenade.fuse <= 0.0 || touching_shootable {
+            spawn_explosion(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                next,
+                grenade.damage,
+                grenade.explosion_radius,
+                Some(Incendiary::GRENADE),
+                grenade.owner,
+            );
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawn explosion effect - an incendiary one also leaves its targets burning
+#[allow(clippy::too_many_arguments)]
+fn spawn_explosion(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+    damage: f32,
+    radius: f32,
+    incendiary: Option<Incendiary>,
+    owner: Option<Entity>,
+) {
+    let explosion_material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.6, 0.2, 0.8),
+        emissive: LinearRgba::rgb(5.0, 2.0, 0.5),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    // Explosion sphere
+    let mut explosion = commands.spawn((
         Mesh3d(meshes.add(Sphere::new(0.5))),
         MeshMaterial3d(explosion_material),
         Transform::from_translation(position),
@@ -519,8 +1231,14 @@ fn spawn_explosion(
             damage,
             lifetime: 0.3,
             has_damaged: false,
+            knockback_strength: Explosion::ROCKET_KNOCKBACK,
+            faction: Faction::Player,
+            owner,
         },
     ));
+    if let Some(incendiary) = incendiary {
+        explosion.insert(incendiary);
+    }
 
     // Explosion light
     commands.spawn((
@@ -532,21 +1250,27 @@ fn spawn_explosion(
             ..default()
         },
         Transform::from_translation(position),
-        MuzzleFlash { lifetime: 0.2, max_lifetime: 0.2 },
+        MuzzleFlash { lifetime: 0.2 },
     ));
+
+    commands.spawn((SoundEmitter(SoundEffect::Explosion), Transform::from_translation(position)));
 }
 
-/// Update explosions - expand and deal damage
+/// Update explosions - expand and deal damage to everyone in range (see `Blast` for faction rules)
+#[allow(clippy::too_many_arguments)]
 pub fn update_explosions(
     mut commands: Commands,
-    mut explosion_query: Query<(Entity, &mut Transform, &mut Explosion)>,
-    shootable_query: Query<(Entity, &GlobalTransform), (With<Shootable>, With<Health>)>,
+    mut explosion_query: Query<(Entity, &mut Transform, &mut Explosion, Option<&Incendiary>)>,
+    target_query: Query<(Entity, &GlobalTransform, &Health, Option<&Faction>)>,
+    grid: Res<SpatialGrid>,
+    mut status_query: Query<&mut StatusEffect>,
+    combat_config: Res<CombatConfig>,
     mut damage_events: EventWriter<DamageEvent>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, mut transform, mut explosion) in &mut explosion_query {
+    for (entity, mut transform, mut explosion, incendiary) in &mut explosion_query {
         // Expand explosion
         let expand_rate = explosion.max_radius / 0.15; // Reach max in 0.15s
         explosion.radius = (explosion.radius + expand_rate * dt).min(explosion.max_radius);
@@ -556,17 +1280,34 @@ pub fn update_explosions(
         if !explosion.has_damaged && explosion.radius > explosion.max_radius * 0.5 {
             explosion.has_damaged = true;
 
-            let explosion_pos = transform.translation;
-            for (enemy_entity, enemy_transform) in &shootable_query {
-                let dist = (enemy_transform.translation() - explosion_pos).length();
-                if dist < explosion.max_radius {
-                    // Damage falls off with distance
-                    let damage_mult = 1.0 - (dist / explosion.max_radius);
+            let blast = Blast {
+                center: transform.translation,
+                radius: explosion.max_radius,
+                damage: explosion.damage,
+                faction: explosion.faction,
+            };
+            for target in grid.query_radius(blast.center, blast.radius) {
+                let Ok((target, target_transform, health, faction)) = target_query.get(target) else {
+                    continue;
+                };
+                if health.is_dead() {
+                    continue;
+                }
+                let position = target_transform.translation();
+                if let Some(amount) = blast.damage_at(position, faction.copied(), combat_config.friendly_fire) {
                     damage_events.write(DamageEvent {
-                        target: enemy_entity,
-                        amount: explosion.damage * damage_mult,
-                        source: None,
+                        target,
+                        amount,
+                        source: explosion.owner,
                     });
+
+                    // Fire doesn't spread to the side that threw it
+                    if let Some(incendiary) = incendiary
+                        && faction.copied() != Some(explosion.faction)
+                    {
+                        let burn = incendiary.status().with_source(explosion.owner);
+                        apply_status_effect(&mut commands, status_query.get_mut(target).ok(), target, burn);
+                    }
                 }
             }
         }
@@ -579,27 +1320,94 @@ pub fn update_explosions(
     }
 }
 
+/// Push the player away from fresh explosions (rocket jumping).
+/// Self-damage is dealt by `update_explosions` like any other blast damage.
+pub fn apply_explosion_knockback(
+    explosion_query: Query<(&Transform, &Explosion), Added<Explosion>>,
+    mut player_query: Query<(&Transform, &mut Velocity), With<Player>>,
+) {
+    let Ok((player_transform, mut velocity)) = player_query.single_mut() else {
+        return;
+    };
+
+    for (explosion_transform, explosion) in &explosion_query {
+        let center = explosion_transform.translation;
+        let impulse = explosion.knockback_at(center, player_transform.translation);
+        if impulse == Vec3::ZERO {
+            continue;
+        }
+
+        // Cap the boost, but never slow down a player already moving faster
+        let cap = velocity.0.length().max(Explosion::MAX_KNOCKBACK_SPEED);
+        velocity.0 = (velocity.0 + impulse).clamp_length_max(cap);
+    }
+}
+
+/// Lunge the player toward the nearest enemy in front of a fresh swing, so the blade
+/// still connects against targets backing away. Walls cancel it in `player_collision`.
+#[allow(clippy::type_complexity)]
+pub fn apply_sword_lunge(
+    mut commands: Commands,
+    swing_query: Query<&SwordSwing, Added<SwordSwing>>,
+    mut player_query: Query<(Entity, &mut Velocity, Option<&mut SwordLunge>), With<Player>>,
+    target_query: Query<(&GlobalTransform, &Health), (With<Shootable>, Without<Player>)>,
+    time: Res<Time>,
+) {
+    let Ok((player, mut velocity, lunge)) = player_query.single_mut() else {
+        return;
+    };
+
+    if let Some(mut lunge) = lunge {
+        lunge.remaining -= time.delta_secs();
+        if lunge.remaining <= 0.0 {
+            commands.entity(player).remove::<SwordLunge>();
+        }
+    }
+
+    for swing in &swing_query {
+        let nearest = target_query
+            .iter()
+            .filter(|(_, health)| !health.is_dead())
+            .map(|(transform, _)| transform.translation())
+            .filter(|&position| swing.in_lunge_range(position))
+            .min_by(|a, b| a.distance_squared(swing.origin).total_cmp(&b.distance_squared(swing.origin)));
+        let Some(target) = nearest else {
+            continue;
+        };
+
+        let direction = (target - swing.origin).with_y(0.0).normalize_or_zero();
+        if direction == Vec3::ZERO {
+            continue;
+        }
+
+        velocity.0 += direction * SwordSwing::LUNGE_SPEED;
+        commands.entity(player).insert(SwordLunge {
+            direction,
+            remaining: SwordSwing::LUNGE_DURATION,
+        });
+    }
+}
+
 /// Update sword swings
+#[allow(clippy::type_complexity)]
 pub fn update_sword_swings(
     mut commands: Commands,
-    mut swing_query: Query<(Entity, &GlobalTransform, &mut SwordSwing)>,
+    mut swing_query: Query<(Entity, &mut Transform, &mut SwordSwing)>,
     shootable_query: Query<(Entity, &GlobalTransform), (With<Shootable>, With<Health>)>,
     mut damage_events: EventWriter<DamageEvent>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
-    for (entity, transform, mut swing) in &mut swing_query {
-        // Check for hits
+    for (entity, mut transform, mut swing) in &mut swing_query {
+        // Check for hits - only in front of the player, never behind
         if !swing.has_hit {
-            let swing_pos = transform.translation();
             for (enemy_entity, enemy_transform) in &shootable_query {
-                let dist = (enemy_transform.translation() - swing_pos).length();
-                if dist < 2.5 {
+                if swing.in_arc(enemy_transform.translation()) {
                     damage_events.write(DamageEvent {
                         target: enemy_entity,
                         amount: swing.damage,
-                        source: None,
+                        source: swing.owner,
                     });
                     swing.has_hit = true;
                     break;
@@ -610,10 +1418,19 @@ pub fn update_sword_swings(
         swing.lifetime -= dt;
         if swing.lifetime <= 0.0 {
             commands.entity(entity).despawn();
+        } else {
+            *transform = swing.blade_transform();
         }
     }
 }
 
+/// Where the muzzle sits relative to the camera - flashes and tracers start here
+fn muzzle_position(camera_transform: &GlobalTransform) -> Vec3 {
+    camera_transform.translation() + camera_transform.forward() * 0.5
+        + camera_transform.down() * 0.1
+        + camera_transform.right() * 0.15
+}
+
 /// Spawn muzzle flash visual
 fn spawn_muzzle_flash(
     commands: &mut Commands,
@@ -621,9 +1438,7 @@ fn spawn_muzzle_flash(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     camera_transform: &GlobalTransform,
 ) {
-    let flash_pos = camera_transform.translation() + camera_transform.forward() * 0.5
-        + camera_transform.down() * 0.1
-        + camera_transform.right() * 0.15;
+    let flash_pos = muzzle_position(camera_transform);
 
     commands.spawn((
         Mesh3d(meshes.add(Cuboid::new(0.1, 0.1, 0.02))),
@@ -652,7 +1467,63 @@ fn spawn_muzzle_flash(
     ));
 }
 
-/// Update and despawn muzzle flash effects
+/// Seconds a tracer streak stays on screen
+const TRACER_LIFETIME: f32 = 0.05;
+/// Seconds a hit spark stays on screen
+const HIT_SPARK_LIFETIME: f32 = 0.08;
+
+/// Spawn a thin streak from the muzzle to where the round stopped
+fn spawn_tracer(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    start: Vec3,
+    end: Vec3,
+) {
+    let length = start.distance(end);
+    if length < 0.01 {
+        return;
+    }
+
+    commands.spawn((
+        Mesh3d(meshes.add(Cuboid::new(0.02, 0.02, length))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.9, 0.6),
+            emissive: LinearRgba::rgb(6.0, 5.0, 2.0),
+            unlit: true,
+            ..default()
+        })),
+        // Cuboid's long axis is Z, so look along the shot from its midpoint
+        Transform::from_translation(start.midpoint(end)).looking_at(end, Vec3::Y),
+        MuzzleFlash { lifetime: TRACER_LIFETIME },
+        Tracer,
+        AsciiPatternId::slashes().with_category(AsciiCategory::Effect),
+    ));
+}
+
+/// Spawn a small spark at a hitscan impact
+fn spawn_hit_spark(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    position: Vec3,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(Sphere::new(0.08))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.7, 0.3),
+            emissive: LinearRgba::rgb(8.0, 4.0, 1.0),
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_translation(position),
+        MuzzleFlash { lifetime: HIT_SPARK_LIFETIME },
+        HitSpark,
+        AsciiPatternId::standard().with_category(AsciiCategory::Effect),
+    ));
+}
+
+/// Update and despawn muzzle flash effects (also tracers and hit sparks)
 pub fn update_muzzle_flash(
     mut commands: Commands,
     mut flash_query: Query<(Entity, &mut MuzzleFlash)>,
@@ -673,3 +1544,987 @@ pub struct AmmoHud;
 /// HUD element showing current weapon name
 #[derive(Component)]
 pub struct WeaponHud;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::spatial::rebuild_spatial_grid;
+
+    const RELOAD_SECS: f32 = 1.5;
+
+    /// Machinegun with some rounds spent and a reload just started
+    fn reloading_machinegun() -> WeaponStats {
+        WeaponStats {
+            ammo: 20,
+            reloading: Some(RELOAD_SECS),
+            ..WeaponStats::machinegun()
+        }
+    }
+
+    // ==================== Weapon State Tests ====================
+
+    #[test]
+    fn test_fire_starts_cooldown() {
+        let mut weapon = WeaponStats::machinegun();
+        weapon.fire();
+
+        assert_eq!(weapon.ammo, 49);
+        assert!(!weapon.can_fire());
+
+        weapon.update_cooldown(1.0 / weapon.fire_rate);
+        assert!(weapon.can_fire());
+    }
+
+    #[test]
+    fn test_reload_refills_magazine() {
+        let mut weapon = reloading_machinegun();
+
+        weapon.update_reload(RELOAD_SECS / 2.0);
+        assert!(weapon.is_reloading());
+        assert_eq!(weapon.ammo, 20);
+
+        weapon.update_reload(RELOAD_SECS / 2.0);
+        assert!(!weapon.is_reloading());
+        assert_eq!(weapon.ammo, weapon.max_ammo);
+        assert_eq!(weapon.reserve_ammo, 150 - 30);
+    }
+
+    #[test]
+    fn test_partial_reload_from_small_reserve() {
+        let mut weapon = WeaponStats {
+            ammo: 10,
+            reserve_ammo: 15,
+            ..WeaponStats::machinegun()
+        };
+
+        // 40 missing but only 15 in reserve
+        assert!(weapon.reload());
+        weapon.update_reload(weapon.reload_time);
+        assert_eq!(weapon.ammo, 25);
+        assert_eq!(weapon.reserve_ammo, 0);
+
+        // Nothing left to reload with
+        assert!(!weapon.reload());
+    }
+
+    #[test]
+    fn test_reload_rejected_with_empty_reserve() {
+        let mut weapon = WeaponStats {
+            ammo: 0,
+            reserve_ammo: 0,
+            ..WeaponStats::rocket_launcher()
+        };
+        assert!(!weapon.reload());
+        assert!(!weapon.can_fire());
+    }
+
+    #[test]
+    fn test_sword_never_reloads() {
+        let mut sword = WeaponStats::sword();
+        sword.fire();
+        assert_eq!(sword.ammo, sword.max_ammo);
+        assert!(!sword.reload());
+    }
+
+    #[test]
+    fn test_reload_blocks_firing() {
+        let mut weapon = reloading_machinegun();
+
+        assert!(!weapon.can_fire());
+        weapon.fire();
+        assert_eq!(weapon.ammo, 20);
+    }
+
+    #[test]
+    fn test_reload_rejected_when_full_or_reloading() {
+        let mut full = WeaponStats::machinegun();
+        assert!(!full.reload());
+        assert!(!full.is_reloading());
+
+        let mut weapon = WeaponStats {
+            ammo: 20,
+            ..WeaponStats::machinegun()
+        };
+        assert!(weapon.reload());
+        weapon.update_reload(0.5);
+        // A second request doesn't restart the timer
+        assert!(!weapon.reload());
+        assert_eq!(weapon.reloading, Some(weapon.reload_time - 0.5));
+    }
+
+    #[test]
+    fn test_cancel_reload_keeps_ammo() {
+        let mut weapon = reloading_machinegun();
+        weapon.cancel_reload();
+
+        assert!(!weapon.is_reloading());
+        assert_eq!(weapon.ammo, 20);
+        assert!(weapon.can_fire());
+    }
+
+    #[test]
+    fn test_switch_mid_reload_leaves_old_weapon_unreloaded() {
+        let mut inventory = WeaponInventory::default();
+        inventory.weapons[0] = reloading_machinegun();
+
+        inventory.switch_to(1);
+        assert_eq!(inventory.current().weapon_type, WeaponType::RocketLauncher);
+
+        // Time passes on the rocket launcher - the machinegun must not finish its reload
+        for weapon in &mut inventory.weapons {
+            weapon.update_reload(10.0);
+        }
+        inventory.switch_to(0);
+        assert!(!inventory.current().is_reloading());
+        assert_eq!(inventory.current().ammo, 20);
+    }
+
+    #[test]
+    fn test_switch_to_same_weapon_keeps_reload() {
+        let mut inventory = WeaponInventory::default();
+        inventory.weapons[0] = reloading_machinegun();
+
+        inventory.switch_to(0);
+        assert!(inventory.current().is_reloading());
+
+        // Out of range slots are ignored too
+        inventory.switch_to(9);
+        assert!(inventory.current().is_reloading());
+    }
+
+    #[test]
+    fn test_reload_cancels_ads() {
+        let mut inventory = WeaponInventory::default();
+        assert!(inventory.can_aim());
+
+        inventory.weapons[0] = reloading_machinegun();
+        assert!(!inventory.can_aim());
+
+        inventory.current_mut().update_reload(RELOAD_SECS);
+        assert!(inventory.can_aim());
+    }
+
+    #[test]
+    fn test_only_machinegun_can_aim() {
+        let mut inventory = WeaponInventory::default();
+        inventory.switch_to(1);
+        assert!(!inventory.can_aim());
+        inventory.switch_to(2);
+        assert!(!inventory.can_aim());
+    }
+
+    // ==================== Reload Input Tests ====================
+
+    fn reload_test_app(ammo: u32) -> (App, Entity) {
+        let mut app = test_app();
+        app.with_input()
+            .add_systems(Update, handle_reload);
+
+        let mut inventory = WeaponInventory::default();
+        inventory.current_mut().ammo = ammo;
+        let player = app.world_mut().spawn((Player, inventory)).id();
+        (app, player)
+    }
+
+    #[test]
+    fn test_r_key_starts_reload() {
+        let (mut app, player) = reload_test_app(20);
+        app.update();
+        assert!(!app.world().get::<WeaponInventory>(player).unwrap().current().is_reloading());
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyR);
+        app.update();
+        assert!(app.world().get::<WeaponInventory>(player).unwrap().current().is_reloading());
+    }
+
+    #[test]
+    fn test_empty_magazine_reloads_automatically() {
+        let (mut app, player) = reload_test_app(0);
+        app.update();
+        assert!(app.world().get::<WeaponInventory>(player).unwrap().current().is_reloading());
+    }
+
+    #[test]
+    fn test_ammo_regenerates_toward_max_after_firing() {
+        let mut regen = AmmoRegen::default();
+        regen.rates.insert(WeaponType::Machinegun, 10.0);
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .insert_resource(regen)
+            .add_systems(Update, (update_weapon_cooldowns, regenerate_ammo).chain());
+
+        let mut inventory = WeaponInventory::default();
+        let max_ammo = inventory.current().max_ammo;
+        for _ in 0..5 {
+            inventory.current_mut().cooldown = 0.0;
+            inventory.current_mut().fire();
+        }
+        let player = app.world_mut().spawn((Player, inventory)).id();
+        let ammo = |app: &App| app.world().get::<WeaponInventory>(player).unwrap().current().ammo;
+        assert_eq!(ammo(&app), max_ammo - 5);
+
+        // First frame has no delta, the next one lets the last shot's cooldown run out
+        app.update();
+        app.update();
+        let after_cooldown = ammo(&app);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(ammo(&app) > after_cooldown);
+
+        // Never past a full magazine
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(ammo(&app), max_ammo);
+    }
+
+    #[test]
+    fn test_ammo_regen_off_by_default() {
+        let mut regen = AmmoRegen::default();
+        let mut weapon = WeaponStats::machinegun();
+        weapon.ammo = 10;
+        regen.regenerate(&mut weapon, 10.0);
+        assert_eq!(weapon.ammo, 10);
+    }
+
+    // ==================== Shooting Tests ====================
+
+    /// Headless app with the trigger held, a player aiming down -Z and a target 10 units ahead
+    fn shooting_test_app() -> (App, Entity, Entity) {
+        let mut app = test_app();
+        app.with_input()
+            .add_event::<DamageEvent>()
+            .init_resource::<EffectsConfig>()
+            .with_mesh_assets()
+            .init_resource::<CombatConfig>()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<SpatialGrid>()
+            .add_systems(PreUpdate, rebuild_spatial_grid)
+            .add_systems(Update, handle_shooting);
+
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Left);
+        app.insert_resource(mouse);
+
+        // Player owns the inventory, the camera child provides the aim
+        let player = app
+            .world_mut()
+            .spawn((Player, WeaponInventory::default(), Ads::default()))
+            .id();
+        app.world_mut().spawn((
+            PlayerCamera::default(),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, 0.0).looking_to(Vec3::NEG_Z, Vec3::Y)),
+        ));
+        let target = app
+            .world_mut()
+            .spawn((
+                Shootable,
+                Health::new(100.0),
+                GlobalTransform::from(Transform::from_xyz(0.0, 1.7, -10.0)),
+            ))
+            .id();
+
+        (app, player, target)
+    }
+
+    /// Targets of every damage event sent so far
+    fn damaged_targets(app: &App) -> Vec<Entity> {
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        cursor.read(events).map(|event| event.target).collect()
+    }
+
+    #[test]
+    fn test_machinegun_shot_emits_damage_event() {
+        let (mut app, player, target) = shooting_test_app();
+        app.update();
+
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        let hits: Vec<_> = cursor.read(events).collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].target, target);
+        assert_eq!(hits[0].amount, WeaponStats::machinegun().damage);
+        assert_eq!(hits[0].source, Some(player));
+
+        // The shot was spent from the player's inventory
+        let inventory = app.world().get::<WeaponInventory>(player).unwrap();
+        assert_eq!(inventory.current().ammo, WeaponStats::machinegun().max_ammo - 1);
+    }
+
+    #[test]
+    fn test_every_weapon_spawns_its_fire_sound() {
+        let (mut app, player, _) = shooting_test_app();
+        let weapon_count = WeaponInventory::default().weapons.len();
+
+        for index in 0..weapon_count {
+            app.world_mut().get_mut::<WeaponInventory>(player).unwrap().switch_to(index);
+            app.update();
+
+            let weapon_type = app.world().get::<WeaponInventory>(player).unwrap().current().weapon_type;
+            let world = app.world_mut();
+            let sounds: Vec<SoundEffect> = world.query::<&SoundEmitter>().iter(world).map(|sound| sound.0).collect();
+            // One sound per shot, even for the shotgun's pellets
+            assert_eq!(sounds.len(), index + 1);
+            assert!(sounds.contains(&weapon_type.fire_sound()), "no sound for {}", weapon_type.name());
+        }
+    }
+
+    #[test]
+    fn test_hitscan_spawns_tracer_and_spark_that_expire() {
+        let (mut app, _, _) = shooting_test_app();
+        app.with_frame_time(Duration::from_millis(20))
+        .add_systems(Update, update_muzzle_flash.after(handle_shooting));
+        app.update();
+
+        let world = app.world_mut();
+        let tracers: Vec<Transform> = world.query_filtered::<&Transform, With<Tracer>>().iter(world).copied().collect();
+        assert_eq!(tracers.len(), 1);
+        // Streak ends at the target 10 units ahead, which also gets a spark
+        assert!(tracers[0].translation.z < -4.0 && tracers[0].translation.z > -6.0);
+        let sparks: Vec<Transform> = world.query_filtered::<&Transform, With<HitSpark>>().iter(world).copied().collect();
+        assert_eq!(sparks.len(), 1);
+        assert!((sparks[0].translation.z + 9.0).abs() < 0.5);
+
+        // Stop firing and let them time out
+        app.insert_resource(ButtonInput::<MouseButton>::default());
+        for _ in 0..10 {
+            app.update();
+        }
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<Entity, With<Tracer>>().iter(world).count(), 0);
+        assert_eq!(world.query_filtered::<Entity, With<HitSpark>>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn test_headshot_multiplies_damage() {
+        let (mut app, _, target) = shooting_test_app();
+        // Drop the target so the ray at eye height passes through its head
+        app.world_mut().entity_mut(target).insert((
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.0, -10.0)),
+            Headbox::default(),
+        ));
+        app.update();
+
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        let hits: Vec<_> = cursor.read(events).collect();
+        let multiplier = CombatConfig::default().headshot_multiplier;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].amount, WeaponStats::machinegun().damage * multiplier);
+    }
+
+    #[test]
+    fn test_body_shot_with_headbox_deals_base_damage() {
+        let (mut app, _, target) = shooting_test_app();
+        app.world_mut().entity_mut(target).insert(Headbox::default());
+        app.update();
+
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        let hits: Vec<_> = cursor.read(events).collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].amount, WeaponStats::machinegun().damage);
+    }
+
+    #[test]
+    fn test_point_blank_shotgun_lands_every_pellet() {
+        let (mut app, player, target) = shooting_test_app();
+        app.world_mut().get_mut::<WeaponInventory>(player).unwrap().switch_to(3);
+        app.world_mut()
+            .entity_mut(target)
+            .insert(GlobalTransform::from(Transform::from_xyz(0.0, 1.7, -2.0)));
+        app.update();
+
+        let shotgun = WeaponStats::shotgun();
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        let hits: Vec<_> = cursor.read(events).collect();
+        assert_eq!(hits.len(), shotgun.pellets as usize);
+        assert!(hits.iter().all(|hit| hit.target == target));
+
+        let total: f32 = hits.iter().map(|hit| hit.amount).sum();
+        assert_eq!(total, shotgun.damage * shotgun.pellets as f32);
+
+        // One shell per shot, however many pellets
+        let inventory = app.world().get::<WeaponInventory>(player).unwrap();
+        assert_eq!(inventory.current().ammo, shotgun.max_ammo - 1);
+    }
+
+    #[test]
+    fn test_seeded_spread_is_reproducible() {
+        let spread = WeaponStats::shotgun().spread;
+        let mut a = GameRng::new(9);
+        let mut b = GameRng::new(9);
+        for _ in 0..8 {
+            assert_eq!(
+                spread_direction(Vec3::NEG_Z, spread, &mut a),
+                spread_direction(Vec3::NEG_Z, spread, &mut b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_wall_blocks_hitscan() {
+        let (mut app, _, _) = shooting_test_app();
+
+        // Pillar halfway between the player and the target
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, -5.0),
+            BoxCollider {
+                half_extents: Vec3::new(1.0, 2.0, 1.0),
+            },
+        ));
+        app.update();
+
+        assert!(damaged_targets(&app).is_empty());
+    }
+
+    #[test]
+    fn test_wall_behind_target_does_not_block() {
+        let (mut app, _, target) = shooting_test_app();
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, -15.0),
+            BoxCollider {
+                half_extents: Vec3::new(1.0, 2.0, 1.0),
+            },
+        ));
+        app.update();
+
+        assert_eq!(damaged_targets(&app), vec![target]);
+    }
+
+    /// Damage events seen so far (events only live for two frames)
+    #[derive(Resource, Default)]
+    struct DamageLog(Vec<(Entity, f32)>);
+
+    fn log_damage(mut events: EventReader<DamageEvent>, mut log: ResMut<DamageLog>) {
+        log.0.extend(events.read().map(|event| (event.target, event.amount)));
+    }
+
+    /// Headless app stepping the weapon effect systems at a fixed 50ms per update
+    fn effects_test_app() -> App {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(50))
+            .add_event::<DamageEvent>()
+            .init_resource::<DamageLog>()
+            .init_resource::<EffectsConfig>()
+            .init_resource::<CombatConfig>()
+            .with_mesh_assets()
+            .init_resource::<SpatialGrid>()
+            .add_systems(PreUpdate, rebuild_spatial_grid)
+            .add_systems(
+                Update,
+                (
+                    update_player_projectiles,
+                    update_explosions,
+                    apply_explosion_knockback,
+                    update_sword_swings,
+                    log_damage,
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn spawn_target(app: &mut App, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                Shootable,
+                Faction::Enemy,
+                Health::new(100.0),
+                Transform::from_translation(position),
+                GlobalTransform::from_translation(position),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_incendiary_blast_ignites_enemies_only() {
+        let mut app = effects_test_app();
+        let enemy = spawn_target(&mut app, Vec3::new(2.0, 1.0, 0.0));
+        let player = app
+            .world_mut()
+            .spawn((Faction::Player, Health::new(100.0), GlobalTransform::from_xyz(-2.0, 1.0, 0.0)))
+            .id();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            Explosion {
+                radius: 0.0,
+                max_radius: 5.0,
+                damage: 40.0,
+                lifetime: 0.3,
+                has_damaged: false,
+                knockback_strength: 0.0,
+                faction: Faction::Player,
+                owner: Some(player),
+            },
+            Incendiary::GRENADE,
+        ));
+
+        for _ in 0..3 {
+            app.update();
+        }
+        let burn = app.world().get::<StatusEffect>(enemy).expect("enemy should be burning");
+        assert_eq!(burn.kind, crate::combat::StatusKind::Burning);
+        // The thrower is credited with the burn's ticks too
+        assert_eq!(burn.source, Some(player));
+        assert!(app.world().get::<StatusEffect>(player).is_none());
+    }
+
+    #[test]
+    fn test_rocket_blast_hits_enemies_on_both_sides() {
+        let mut app = effects_test_app();
+        let left = spawn_target(&mut app, Vec3::new(-2.0, 1.0, 0.0));
+        let right = spawn_target(&mut app, Vec3::new(2.0, 1.0, 0.0));
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            Explosion {
+                radius: 0.5,
+                max_radius: 5.0,
+                damage: 50.0,
+                lifetime: 0.3,
+                has_damaged: false,
+                knockback_strength: Explosion::ROCKET_KNOCKBACK,
+                faction: Faction::Player,
+                owner: None,
+            },
+        ));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let mut targets: Vec<_> = app.world().resource::<DamageLog>().0.iter().map(|(target, _)| *target).collect();
+        targets.sort();
+        let mut expected = vec![left, right];
+        expected.sort();
+        assert_eq!(targets, expected);
+    }
+
+    #[test]
+    fn test_rocket_explodes_on_shootable() {
+        let mut app = effects_test_app();
+        let target = spawn_target(&mut app, Vec3::new(0.0, 1.0, -3.0));
+
+        // Rocket about to reach the target
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, -2.5),
+            PlayerProjectile {
+                damage: 50.0,
+                speed: 10.0,
+                direction: Vec3::NEG_Z,
+                lifetime: 5.0,
+                explosion_radius: 5.0,
+                homing: false,
+                turn_rate: 0.0,
+                owner: None,
+            },
+        ));
+
+        app.update();
+        let explosions = app.world_mut().query::<&Explosion>().iter(app.world()).count();
+        let rockets = app.world_mut().query::<&PlayerProjectile>().iter(app.world()).count();
+        assert_eq!(explosions, 1);
+        assert_eq!(rockets, 0);
+        let sounds: Vec<SoundEffect> = app.world_mut().query::<&SoundEmitter>().iter(app.world()).map(|sound| sound.0).collect();
+        assert_eq!(sounds, [SoundEffect::Explosion]);
+
+        // Damage lands once the blast has expanded
+        for _ in 0..4 {
+            app.update();
+        }
+        let log = &app.world().resource::<DamageLog>().0;
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].0, target);
+        assert!(log[0].1 > 0.0 && log[0].1 <= 50.0);
+    }
+
+    fn spawn_rocket_at_origin(app: &mut App, homing: bool) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::default(),
+                PlayerProjectile {
+                    damage: 50.0,
+                    speed: 10.0,
+                    direction: Vec3::NEG_Z,
+                    lifetime: 5.0,
+                    explosion_radius: 5.0,
+                    homing,
+                    turn_rate: PlayerProjectile::ROCKET_TURN_RATE,
+                    owner: None,
+                },
+            ))
+            .id()
+    }
+
+    fn rocket_direction(app: &App, rocket: Entity) -> Vec3 {
+        app.world().get::<PlayerProjectile>(rocket).unwrap().direction
+    }
+
+    #[test]
+    fn test_homing_rocket_turns_toward_enemy() {
+        let mut app = effects_test_app();
+        // Off to the right, inside the homing cone
+        let enemy_pos = Vec3::new(8.0, 0.0, -20.0);
+        spawn_target(&mut app, enemy_pos);
+        let rocket = spawn_rocket_at_origin(&mut app, true);
+
+        app.update();
+        let mut previous_angle = rocket_direction(&app, rocket).angle_between(enemy_pos);
+        for _ in 0..3 {
+            app.update();
+            let direction = rocket_direction(&app, rocket);
+            let position = app.world().get::<Transform>(rocket).unwrap().translation;
+            let angle = direction.angle_between(enemy_pos - position);
+            assert!(direction.x > 0.0);
+            assert!(angle < previous_angle);
+            previous_angle = angle;
+        }
+        // Turns no faster than its turn rate (0.05s steps)
+        let turned = rocket_direction(&app, rocket).angle_between(Vec3::NEG_Z);
+        assert!(turned <= PlayerProjectile::ROCKET_TURN_RATE * 0.05 * 3.0 + 0.001);
+    }
+
+    #[test]
+    fn test_homing_ignores_targets_outside_cone() {
+        let mut app = effects_test_app();
+        spawn_target(&mut app, Vec3::new(20.0, 0.0, -5.0));
+        let rocket = spawn_rocket_at_origin(&mut app, true);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(rocket_direction(&app, rocket), Vec3::NEG_Z);
+    }
+
+    #[test]
+    fn test_non_homing_rocket_flies_straight() {
+        let mut app = effects_test_app();
+        spawn_target(&mut app, Vec3::new(4.0, 0.0, -20.0));
+        let rocket = spawn_rocket_at_origin(&mut app, false);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(rocket_direction(&app, rocket), Vec3::NEG_Z);
+    }
+
+    #[test]
+    fn test_steer_toward_caps_turn() {
+        let steered = steer_toward(Vec3::NEG_Z, Vec3::X, 0.1);
+        assert!((steered.angle_between(Vec3::NEG_Z) - 0.1).abs() < 1e-4);
+        assert_eq!(steer_toward(Vec3::NEG_Z, Vec3::NEG_Z, 0.1), Vec3::NEG_Z);
+    }
+
+    #[test]
+    fn test_grenade_bounces_then_explodes_on_fuse() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(16))
+            .with_mesh_assets()
+            .add_systems(Update, update_grenades);
+
+        // Floor plane at y = 0 (its collider is a slab centered on the plane)
+        app.world_mut().spawn((
+            Transform::default(),
+            BoxCollider { half_extents: Vec3::new(50.0, 0.5, 50.0) },
+            GroundFloor,
+        ));
+        let grenade = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 2.0, 0.0),
+                Grenade {
+                    damage: 45.0,
+                    velocity: Vec3::ZERO,
+                    fuse: Grenade::FUSE,
+                    explosion_radius: 5.0,
+                    owner: None,
+                },
+            ))
+            .id();
+
+        let mut falling = false;
+        let mut bounced = false;
+        while let Some(state) = app.world().get::<Grenade>(grenade) {
+            let vertical = state.velocity.y;
+            falling |= vertical < -1.0;
+            // Moving up after falling - the velocity was reflected off the floor
+            bounced |= falling && vertical > 1.0;
+            let height = app.world().get::<Transform>(grenade).unwrap().translation.y;
+            assert!(height >= 0.0, "grenade fell through the floor");
+            app.update();
+        }
+
+        assert!(bounced);
+        let explosions = app.world_mut().query::<&Explosion>().iter(app.world()).count();
+        assert_eq!(explosions, 1);
+        assert!(app.world().resource::<Time>().elapsed_secs() >= Grenade::FUSE);
+    }
+
+    #[test]
+    fn test_grenade_bounce_reflects_and_damps() {
+        let bounced = Grenade::bounce(Vec3::new(3.0, -10.0, 0.0), Vec3::Y);
+        assert!(bounced.y > 0.0);
+        assert!(bounced.x > 0.0);
+        assert!(bounced.length() < Vec3::new(3.0, -10.0, 0.0).length());
+    }
+
+    #[test]
+    fn test_grenade_bounces_off_wall_side() {
+        let wall = BoxCollider { half_extents: Vec3::new(0.5, 3.0, 5.0) };
+        let normal = grenade_contact_normal(Vec3::new(-1.0, 1.0, 0.0), Vec3::new(-0.55, 1.0, 0.0), Vec3::ZERO, &wall, false);
+        assert_eq!(normal, Some(Vec3::NEG_X));
+
+        // Still outside - no contact
+        let clear = grenade_contact_normal(Vec3::new(-2.0, 1.0, 0.0), Vec3::new(-1.5, 1.0, 0.0), Vec3::ZERO, &wall, false);
+        assert_eq!(clear, None);
+    }
+
+    #[test]
+    fn test_rocket_at_feet_launches_player() {
+        let mut app = effects_test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -0.5, 0.0),
+            BoxCollider {
+                half_extents: Vec3::new(20.0, 0.5, 20.0),
+            },
+            GroundFloor,
+        ));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Faction::Player,
+                Health::new(100.0),
+                Transform::from_xyz(0.0, 0.9, 0.0),
+                GlobalTransform::from_xyz(0.0, 0.9, 0.0),
+                Velocity::default(),
+            ))
+            .id();
+
+        // Rocket fired straight down into the floor under the player
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 0.3, 0.0),
+            PlayerProjectile {
+                damage: 100.0,
+                speed: 10.0,
+                direction: Vec3::NEG_Y,
+                lifetime: 5.0,
+                explosion_radius: 5.0,
+                homing: false,
+                turn_rate: 0.0,
+                owner: None,
+            },
+        ));
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!(velocity.y > 5.0, "rocket jump should launch upward, got {velocity}");
+        assert!(velocity.length() <= Explosion::MAX_KNOCKBACK_SPEED);
+
+        // Self-damage is a fraction of the blast
+        let log = &app.world().resource::<DamageLog>().0;
+        let self_damage: Vec<_> = log.iter().filter(|(target, _)| *target == player).collect();
+        assert_eq!(self_damage.len(), 1);
+        assert!(self_damage[0].1 > 0.0 && self_damage[0].1 <= 100.0 * Faction::SELF_DAMAGE_SCALE);
+    }
+
+    #[test]
+    fn test_knockback_falloff_and_direction() {
+        let explosion = Explosion {
+            radius: 0.5,
+            max_radius: 5.0,
+            damage: 100.0,
+            lifetime: 0.3,
+            has_damaged: false,
+            knockback_strength: 10.0,
+            faction: Faction::Player,
+            owner: None,
+        };
+
+        // Pushed away from the center, weaker further out, nothing outside the radius
+        let near = explosion.knockback_at(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0));
+        let far = explosion.knockback_at(Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0));
+        assert!(near.x > far.x && far.x > 0.0);
+        assert_eq!(explosion.knockback_at(Vec3::ZERO, Vec3::new(6.0, 0.0, 0.0)), Vec3::ZERO);
+
+        // Dead center pushes straight up rather than producing NaN
+        assert_eq!(explosion.knockback_at(Vec3::ZERO, Vec3::ZERO), Vec3::new(0.0, 10.0, 0.0));
+    }
+
+    #[test]
+    fn test_sword_swing_hits_once() {
+        let mut app = effects_test_app();
+        let target = spawn_target(&mut app, Vec3::new(0.0, 1.0, -2.0));
+        spawn_swing_facing_neg_z(&mut app);
+
+        for _ in 0..5 {
+            app.update();
+        }
+
+        // One hit per swing, and the swing is gone after its lifetime
+        assert_eq!(app.world().resource::<DamageLog>().0, vec![(target, 40.0)]);
+        let swings = app.world_mut().query::<&SwordSwing>().iter(app.world()).count();
+        assert_eq!(swings, 0);
+    }
+
+    /// Swing from a standing player's eye height, facing -Z
+    fn spawn_swing_facing_neg_z(app: &mut App) -> Entity {
+        let swing = SwordSwing::new(40.0, Vec3::new(0.0, 1.7, 0.0), Vec3::NEG_Z, Vec3::Y);
+        app.world_mut().spawn((swing.blade_transform(), swing)).id()
+    }
+
+    #[test]
+    fn test_sword_lunges_toward_nearby_enemy() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_sword_lunge);
+        let player = app.world_mut().spawn((Player, Velocity::default())).id();
+        // Just out of the blade's reach, but close enough to lunge at
+        spawn_target(&mut app, Vec3::new(0.0, 1.0, -4.0));
+        spawn_swing_facing_neg_z(&mut app);
+
+        app.update();
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!(velocity.z < -1.0, "velocity {velocity}");
+        assert!(velocity.x.abs() < 1e-4 && velocity.y.abs() < 1e-4);
+        assert!(app.world().get::<SwordLunge>(player).is_some());
+    }
+
+    #[test]
+    fn test_sword_only_hits_in_front() {
+        let mut app = effects_test_app();
+        // Close behind the player - inside the old hit radius around the blade
+        spawn_target(&mut app, Vec3::new(0.0, 1.0, 1.0));
+        let front = spawn_target(&mut app, Vec3::new(0.5, 1.0, -1.5));
+        let swing = spawn_swing_facing_neg_z(&mut app);
+
+        // Starts on the right...
+        app.update();
+        let blade = app.world().get::<Transform>(swing).unwrap().translation;
+        assert!(blade.x > 0.5 && blade.z < 0.0, "blade at {blade}");
+
+        // ...and sweeps across to the left
+        app.update();
+        app.update();
+        let blade = app.world().get::<Transform>(swing).unwrap().translation;
+        assert!(blade.x < 0.0 && blade.z < 0.0, "blade at {blade}");
+
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<DamageLog>().0, vec![(front, 40.0)]);
+    }
+
+    // ==================== Weapon Switch Tests ====================
+
+    fn switch_test_app(start_index: usize) -> (App, Entity) {
+        let mut app = test_app();
+        app.with_input()
+            .add_event::<MouseWheel>()
+            .add_systems(Update, handle_weapon_switch);
+
+        let mut inventory = WeaponInventory::default();
+        inventory.switch_to(start_index);
+        let player = app.world_mut().spawn((Player, inventory)).id();
+        (app, player)
+    }
+
+    fn scroll(app: &mut App, y: f32) {
+        app.world_mut().send_event(MouseWheel {
+            unit: bevy::input::mouse::MouseScrollUnit::Line,
+            x: 0.0,
+            y,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+    }
+
+    fn current_index(app: &App, player: Entity) -> usize {
+        app.world().get::<WeaponInventory>(player).unwrap().current_index
+    }
+
+    #[test]
+    fn test_digit_key_switches_weapon() {
+        let (mut app, player) = switch_test_app(0);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Digit2);
+        app.update();
+
+        assert_eq!(current_index(&app, player), 1);
+    }
+
+    #[test]
+    fn test_digit4_selects_shotgun() {
+        let (mut app, player) = switch_test_app(0);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Digit4);
+        app.update();
+
+        let inventory = app.world().get::<WeaponInventory>(player).unwrap();
+        assert_eq!(inventory.current().weapon_type, WeaponType::Shotgun);
+    }
+
+    #[test]
+    fn test_digit5_selects_grenade_launcher() {
+        let (mut app, player) = switch_test_app(0);
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Digit5);
+        app.update();
+
+        let inventory = app.world().get::<WeaponInventory>(player).unwrap();
+        assert_eq!(inventory.current().weapon_type, WeaponType::GrenadeLauncher);
+    }
+
+    #[test]
+    fn test_scroll_up_wraps_to_first_weapon() {
+        let last = WeaponInventory::default().weapons.len() - 1;
+        let (mut app, player) = switch_test_app(last);
+        scroll(&mut app, 1.0);
+
+        assert_eq!(current_index(&app, player), 0);
+    }
+
+    #[test]
+    fn test_scroll_down_wraps_to_last_weapon() {
+        let last = WeaponInventory::default().weapons.len() - 1;
+        let (mut app, player) = switch_test_app(0);
+        scroll(&mut app, -1.0);
+        assert_eq!(current_index(&app, player), last);
+
+        scroll(&mut app, -1.0);
+        assert_eq!(current_index(&app, player), last - 1);
+    }
+
+    #[test]
+    fn test_cycle_cancels_reload() {
+        let mut inventory = WeaponInventory::default();
+        inventory.weapons[0] = reloading_machinegun();
+
+        inventory.cycle(1);
+        assert_eq!(inventory.current_index, 1);
+        assert!(!inventory.weapons[0].is_reloading());
+    }
+
+    // ==================== Spread Tests ====================
+
+    #[test]
+    fn test_zero_spread_is_exact() {
+        let direction = Vec3::new(0.3, 0.1, -1.0).normalize();
+        assert_eq!(spread_direction(direction, 0.0, &mut GameRng::new(1)), direction);
+    }
+
+    #[test]
+    fn test_spread_stays_within_cone() {
+        let direction = Vec3::NEG_Z;
+        let spread = 0.02;
+        let mut rng = GameRng::new(3);
+
+        for _ in 0..200 {
+            let shot = spread_direction(direction, spread, &mut rng);
+            assert!((shot.length() - 1.0).abs() < 0.0001);
+            assert!(shot.angle_between(direction) <= spread + 0.0001);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// Developer tooling - only active with ASCII_SHOOTER_DEV=1
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::combat::{DamageEvent, Faction, Health, Shootable};
+use crate::rendering::AsciiPatternId;
+use crate::GameState;
+
+/// Environment variable that enables dev tools
+const DEV_ENV_VAR: &str = "ASCII_SHOOTER_DEV";
+
+pub struct DevPlugin;
+
+impl Plugin for DevPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DevMode>()
+            .init_resource::<DpsMeter>()
+            .add_systems(Startup, spawn_dps_hud.run_if(dev_enabled))
+            .add_systems(
+                Update,
+                (
+                    spawn_target_dummies,
+                    record_dummy_damage,
+                    regenerate_dummies,
+                    update_dps_hud,
+                )
+                    .chain()
+                    .run_if(dev_enabled)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Whether dev tools are enabled (set ASCII_SHOOTER_DEV=1)
+#[derive(Resource)]
+pub struct DevMode(pub bool);
+
+impl Default for DevMode {
+    fn default() -> Self {
+        let enabled = std::env::var(DEV_ENV_VAR)
+            .is_ok_and(|value| !value.is_empty() && value != "0");
+        if enabled {
+            info!("Dev tools enabled - F8 spawns target dummies");
+        }
+        Self(enabled)
+    }
+}
+
+fn dev_enabled(dev_mode: Res<DevMode>) -> bool {
+    dev_mode.0
+}
+
+/// Stationary damage sponge for measuring weapon output
+#[derive(Component)]
+pub struct TargetDummy {
+    /// Seconds since the dummy was last hit
+    pub since_hit: f32,
+}
+
+impl TargetDummy {
+    pub const HEALTH: f32 = 100_000.0;
+    /// Quiet time before the dummy heals back to full
+    pub const REGEN_DELAY: f32 = 3.0;
+}
+
+/// Fixed dummy positions - a line at increasing range from spawn to show falloff/spread
+const DUMMY_POSITIONS: [Vec3; 3] = [
+    Vec3::new(-12.0, 1.25, 5.0),
+    Vec3::new(-12.0, 1.25, -15.0),
+    Vec3::new(-12.0, 1.25, -40.0),
+];
+
+/// Damage landing on dummies over a sliding time window
+#[derive(Resource)]
+pub struct DpsMeter {
+    pub window: f32,
+    /// (time, damage) samples, oldest first
+    pub samples: VecDeque<(f32, f32)>,
+}
+
+impl Default for DpsMeter {
+    fn default() -> Self {
+        Self {
+            window: 3.0,
+            samples: VecDeque::new(),
+        }
+    }
+}
+
+impl DpsMeter {
+    pub fn record(&mut self, time: f32, damage: f32) {
+        self.samples.push_back((time, damage));
+    }
+
+    /// Drop samples that have slid out of the window
+    pub fn prune(&mut self, now: f32) {
+        while self.samples.front().is_some_and(|(time, _)| now - time > self.window) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Damage per second over the window ending at `now`
+    pub fn dps(&self, now: f32) -> f32 {
+        let total: f32 = self
+            .samples
+            .iter()
+            .filter(|(time, _)| now - time <= self.window)
+            .map(|(_, damage)| damage)
+            .sum();
+        total / self.window
+    }
+}
+
+/// DPS readout HUD (top-right)
+#[derive(Component)]
+pub struct DpsHud;
+
+fn spawn_dps_hud(mut commands: Commands) {
+    commands.spawn((
+        Text::new("DPS: 0"),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.8, 0.2)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(10.0),
+            ..default()
+        },
+        DpsHud,
+    ));
+}
+
+/// Spawn target dummies with F8 (replacing any existing ones)
+fn spawn_target_dummies(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    existing: Query<Entity, With<TargetDummy>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    if !keyboard.just_pressed(KeyCode::F8) {
+        return;
+    }
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    let mesh = meshes.add(Capsule3d::new(0.5, 1.5));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.9, 0.8, 0.3),
+        emissive: LinearRgba::rgb(0.3, 0.25, 0.05),
+        ..default()
+    });
+
+    for position in DUMMY_POSITIONS {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(position),
+            TargetDummy { since_hit: 0.0 },
+            Health::new(TargetDummy::HEALTH),
+            Shootable,
+            Faction::Enemy,
+            AsciiPatternId::blocks(),
+        ));
+    }
+
+    info!("Spawned {} target dummies", DUMMY_POSITIONS.len());
+}
+
+/// Feed damage landing on dummies into the DPS meter
+fn record_dummy_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    mut dummy_query: Query<&mut TargetDummy>,
+    mut meter: ResMut<DpsMeter>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in damage_events.read() {
+        if let Ok(mut dummy) = dummy_query.get_mut(event.target) {
+            dummy.since_hit = 0.0;
+            meter.record(now, event.amount);
+        }
+    }
+
+    meter.prune(now);
+}
+
+/// Heal dummies back to full once they stop being hit
+fn regenerate_dummies(mut dummy_query: Query<(&mut TargetDummy, &mut Health)>, time: Res<Time>) {
+    for (mut dummy, mut health) in &mut dummy_query {
+        dummy.since_hit += time.delta_secs();
+        if dummy.since_hit >= TargetDummy::REGEN_DELAY {
+            health.current = health.max;
+        }
+    }
+}
+
+fn update_dps_hud(
+    meter: Res<DpsMeter>,
+    mut hud_query: Query<&mut Text, With<DpsHud>>,
+    time: Res<Time>,
+) {
+    let Ok(mut text) = hud_query.single_mut() else {
+        return;
+    };
+
+    **text = format!("DPS: {:.0}", meter.dps(time.elapsed_secs()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dps_over_window() {
+        let mut meter = DpsMeter::default();
+
+        // 10 hits of 15 damage over one second (machinegun fire rate)
+        for i in 0..10 {
+            meter.record(i as f32 * 0.1, 15.0);
+        }
+
+        // 150 damage over a 3 second window
+        assert!((meter.dps(1.0) - 50.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_dps_window_slides() {
+        let mut meter = DpsMeter::default();
+        meter.record(0.0, 100.0);
+        meter.record(2.0, 30.0);
+
+        // First hit slides out of the 3 second window
+        assert!((meter.dps(2.5) - 130.0 / 3.0).abs() < 0.001);
+        assert!((meter.dps(4.0) - 10.0).abs() < 0.001);
+
+        meter.prune(4.0);
+        assert_eq!(meter.samples.len(), 1);
+
+        meter.prune(10.0);
+        assert!(meter.samples.is_empty());
+        assert_eq!(meter.dps(10.0), 0.0);
+    }
+}
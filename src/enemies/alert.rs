@@ -0,0 +1,203 @@
+// Alerts - gunfire and hurt allies wake up enemies that haven't spotted the player yet
+
+use bevy::prelude::*;
+
+use super::{Enemy, EnemyConfig, EnemyState, LineOfSight};
+use crate::audio::{SoundEffect, SoundEmitter};
+use crate::combat::{DamageEvent, Health};
+use crate::level::{line_of_sight_clear, BoxCollider, GroundFloor, Slope};
+
+/// An enemy woken by a noise or a hurt ally. It chases until the timer runs out,
+/// then goes back to idling unless it has spotted the player by then.
+#[derive(Component)]
+pub struct Alerted {
+    pub timer: f32,
+}
+
+/// Whether a sound gives away the player - enemy blasts are their own side's noise
+fn is_player_noise(effect: SoundEffect) -> bool {
+    !matches!(effect, SoundEffect::EnemyExplosion)
+}
+
+/// Wake idle and wandering enemies near fresh player noises (heard through walls) and
+/// near enemies that just took damage (only if they can see them), and calm them down
+/// again once their alert wears off
+#[allow(clippy::type_complexity)]
+pub fn propagate_alerts(
+    mut commands: Commands,
+    config: Res<EnemyConfig>,
+    sound_query: Query<(&SoundEmitter, &Transform), Added<SoundEmitter>>,
+    mut damage_events: EventReader<DamageEvent>,
+    mut enemy_query: Query<
+        (Entity, &Transform, &mut EnemyState, &Health, &LineOfSight, Option<&mut Alerted>),
+        With<Enemy>,
+    >,
+    // Same sight blockers as update_line_of_sight
+    collider_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Slope>, Without<Enemy>)>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    // Wear off old alerts first, so a fresh one this frame isn't cut short
+    for (entity, _, mut state, _, los, alerted) in &mut enemy_query {
+        let Some(mut alerted) = alerted else {
+            continue;
+        };
+        alerted.timer -= dt;
+        if alerted.timer <= 0.0 {
+            commands.entity(entity).remove::<Alerted>();
+            if matches!(*state, EnemyState::Chase) && !los.visible {
+                *state = EnemyState::Idle;
+            }
+        }
+    }
+
+    let noises: Vec<Vec3> = sound_query
+        .iter()
+        .filter(|(sound, _)| is_player_noise(sound.0))
+        .map(|(_, transform)| transform.translation)
+        .collect();
+    let hurt: Vec<Vec3> = damage_events
+        .read()
+        .filter(|event| event.amount > 0.0)
+        .filter_map(|event| enemy_query.get(event.target).ok())
+        .map(|(_, transform, ..)| transform.translation)
+        .collect();
+    if noises.is_empty() && hurt.is_empty() {
+        return;
+    }
+
+    let colliders: Vec<_> = collider_query.iter().collect();
+
+    for (entity, transform, mut state, health, _, _) in &mut enemy_query {
+        if health.is_dead() || !matches!(*state, EnemyState::Idle | EnemyState::Wander) {
+            continue;
+        }
+
+        let position = transform.translation;
+        let heard = noises
+            .iter()
+            .find(|noise| noise.distance(position) < config.hearing_radius);
+        let seen = || {
+            hurt.iter().find(|ally| {
+                ally.distance(position) < config.alert_radius
+                    && line_of_sight_clear(colliders.iter().copied(), position, **ally)
+            })
+        };
+
+        if heard.or_else(seen).is_some() {
+            *state = EnemyState::Chase;
+            commands.entity(entity).insert(Alerted { timer: config.alert_duration });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{handle_shooting, CombatConfig, EffectsConfig, SpatialGrid, WeaponInventory};
+    use crate::player::{Ads, Player, PlayerCamera};
+    use crate::rng::GameRng;
+
+    /// Player holding the trigger at the origin, plus an alert-only app stepping 50ms per update
+    fn alert_test_app() -> App {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_millis(50))
+            .add_event::<DamageEvent>()
+            .init_resource::<EnemyConfig>()
+            .init_resource::<EffectsConfig>()
+            .init_resource::<CombatConfig>()
+            .init_resource::<SpatialGrid>()
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .add_systems(Update, (handle_shooting, propagate_alerts).chain());
+
+        app.world_mut()
+            .spawn((Player, WeaponInventory::default(), Ads::default()));
+        app.world_mut().spawn((
+            PlayerCamera::default(),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, 0.0).looking_to(Vec3::NEG_Z, Vec3::Y)),
+        ));
+        app
+    }
+
+    fn spawn_enemy(app: &mut App, position: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_translation(position),
+                Enemy::melee(),
+                EnemyState::Idle,
+                Health::new(50.0),
+                LineOfSight::default(),
+            ))
+            .id()
+    }
+
+    fn state(app: &App, enemy: Entity) -> EnemyState {
+        app.world().get::<EnemyState>(enemy).unwrap().clone()
+    }
+
+    #[test]
+    fn test_gunfire_wakes_idle_enemy_behind_wall() {
+        let mut app = alert_test_app();
+        let enemy = spawn_enemy(&mut app, Vec3::new(12.0, 1.0, 0.0));
+        let far_enemy = spawn_enemy(&mut app, Vec3::new(200.0, 1.0, 0.0));
+        // Sound carries through walls
+        app.world_mut().spawn((
+            Transform::from_xyz(6.0, 2.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(0.5, 2.0, 5.0) },
+        ));
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        app.update();
+
+        assert!(matches!(state(&app, enemy), EnemyState::Chase));
+        assert!(app.world().get::<Alerted>(enemy).is_some());
+        assert!(matches!(state(&app, far_enemy), EnemyState::Idle));
+    }
+
+    #[test]
+    fn test_alert_wears_off_without_sighting() {
+        let mut app = alert_test_app();
+        let enemy = spawn_enemy(&mut app, Vec3::new(12.0, 1.0, 0.0));
+        app.world_mut().resource_mut::<EnemyConfig>().alert_duration = 0.2;
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(MouseButton::Left);
+        assert!(matches!(state(&app, enemy), EnemyState::Chase));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(matches!(state(&app, enemy), EnemyState::Idle));
+        assert!(app.world().get::<Alerted>(enemy).is_none());
+    }
+
+    #[test]
+    fn test_hurt_ally_only_alerts_enemies_that_see_it() {
+        let mut app = alert_test_app();
+        let victim = spawn_enemy(&mut app, Vec3::new(0.0, 1.0, -60.0));
+        let witness = spawn_enemy(&mut app, Vec3::new(8.0, 1.0, -60.0));
+        let hidden = spawn_enemy(&mut app, Vec3::new(-8.0, 1.0, -60.0));
+        app.world_mut().spawn((
+            Transform::from_xyz(-4.0, 2.0, -60.0),
+            BoxCollider { half_extents: Vec3::new(0.5, 2.0, 5.0) },
+        ));
+
+        app.world_mut().send_event(DamageEvent {
+            target: victim,
+            amount: 10.0,
+            source: None,
+        });
+        app.update();
+
+        assert!(matches!(state(&app, victim), EnemyState::Chase));
+        assert!(matches!(state(&app, witness), EnemyState::Chase));
+        assert!(matches!(state(&app, hidden), EnemyState::Idle));
+    }
+}
@@ -0,0 +1,360 @@
+// Boss enemy - a heavy that changes attack pattern as its health drops
+// Stomp (melee brute) -> Barrage (projectile fans, below 66%) -> Charge (fast melee rush, below 33%)
+
+use bevy::prelude::*;
+
+use super::{varied_cooldown, Difficulty, Enemy, EnemyConfig, EnemyProjectile, EnemyProjectileCount, EnemyState};
+use crate::combat::{DamageEvent, Health};
+use crate::player::Player;
+use crate::rng::GameRng;
+
+/// Projectiles in each Barrage volley
+pub const BARRAGE_SHOTS: usize = 5;
+/// Total angle a Barrage volley fans across, in radians
+const BARRAGE_FAN: f32 = 0.6;
+
+/// Boss attack phases, in the order they're reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum BossPhase {
+    /// Slow melee brute
+    #[default]
+    Stomp,
+    /// Keeps some distance and fires fans of projectiles
+    Barrage,
+    /// Fast, hard-hitting melee rush
+    Charge,
+}
+
+impl BossPhase {
+    /// Phase for a health fraction - Barrage below 66%, Charge below 33%
+    pub fn for_health(fraction: f32) -> Self {
+        if fraction < 0.33 {
+            BossPhase::Charge
+        } else if fraction < 0.66 {
+            BossPhase::Barrage
+        } else {
+            BossPhase::Stomp
+        }
+    }
+
+    /// Set the boss's movement/attack stats for this phase
+    pub fn apply(self, enemy: &mut Enemy) {
+        let (speed, attack_damage, attack_range, attack_interval, preferred_range) = match self {
+            BossPhase::Stomp => (3.0, 25.0, 4.0, 1.5, 2.5),
+            // Damage is per projectile
+            BossPhase::Barrage => (2.5, 10.0, 35.0, 2.0, 15.0),
+            BossPhase::Charge => (9.0, 30.0, 4.0, 0.8, 2.0),
+        };
+        enemy.speed = speed;
+        enemy.attack_damage = attack_damage;
+        enemy.attack_range = attack_range;
+        enemy.attack_interval = attack_interval;
+        enemy.preferred_range = preferred_range;
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BossPhase::Stomp => "STOMP",
+            BossPhase::Barrage => "BARRAGE",
+            BossPhase::Charge => "CHARGE",
+        }
+    }
+}
+
+/// Marks an EnemyType::Boss enemy and tracks its phase
+#[derive(Component, Default)]
+pub struct Boss {
+    pub phase: BossPhase,
+}
+
+impl Enemy {
+    pub fn boss() -> Self {
+        let mut enemy = Self {
+            attack_cooldown: 0.0,
+            sight_range: 80.0,
+            ..Self::melee()
+        };
+        BossPhase::Stomp.apply(&mut enemy);
+        enemy
+    }
+}
+
+/// Advance boss phases as health crosses the thresholds (never back, even if healed)
+pub fn update_boss_phase(mut boss_query: Query<(&mut Boss, &mut Enemy, &Health)>) {
+    for (mut boss, mut enemy, health) in &mut boss_query {
+        let phase = BossPhase::for_health(health.fraction());
+        if phase > boss.phase {
+            boss.phase = phase;
+            phase.apply(&mut enemy);
+            info!("Boss phase: {}", phase.name());
+        }
+    }
+}
+
+/// Boss attacks - melee hits in Stomp/Charge, projectile fans in Barrage
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn boss_attack(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    player_query: Query<(Entity, &Transform), With<Player>>,
+    mut boss_query: Query<(Entity, &Transform, &mut Enemy, &Boss, &EnemyState, &Health), Without<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    config: Res<EnemyConfig>,
+    difficulty: Res<Difficulty>,
+    mut projectile_count: ResMut<EnemyProjectileCount>,
+    mut rng: ResMut<GameRng>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, player_transform)) = player_query.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+    let dt = time.delta_secs();
+
+    for (boss_entity, transform, mut enemy, boss, state, health) in &mut boss_query {
+        if health.is_dead() {
+            continue;
+        }
+
+        enemy.attack_cooldown = (enemy.attack_cooldown - dt).max(0.0);
+        let distance = (player_pos - transform.translation).length();
+        if !matches!(state, EnemyState::Attack) || enemy.attack_cooldown > 0.0 || distance >= enemy.attack_range {
+            continue;
+        }
+
+        match boss.phase {
+            BossPhase::Stomp | BossPhase::Charge => {
+                damage_events.write(DamageEvent {
+                    target: player_entity,
+                    amount: enemy.attack_damage,
+                    source: Some(boss_entity),
+                });
+            }
+            BossPhase::Barrage => {
+                let aim = (player_pos - transform.translation).normalize_or(Vec3::NEG_Z);
+                let spawn_pos = transform.translation + aim * 1.2 + Vec3::Y * 0.5;
+                let material = materials.add(StandardMaterial {
+                    base_color: Color::srgb(1.0, 0.3, 0.1),
+                    emissive: LinearRgba::rgb(3.0, 0.8, 0.2),
+                    unlit: true,
+                    ..default()
+                });
+
+                for shot in 0..BARRAGE_SHOTS {
+                    // Shares the projectile cap with ranged enemies
+                    if !projectile_count.try_spawn(difficulty.scale_projectile_cap(config.max_projectiles)) {
+                        break;
+                    }
+                    let t = shot as f32 / (BARRAGE_SHOTS - 1) as f32 - 0.5;
+                    let direction = Quat::from_rotation_y(t * BARRAGE_FAN) * aim;
+                    commands.spawn((
+                        Mesh3d(meshes.add(Sphere::new(0.2))),
+                        MeshMaterial3d(material.clone()),
+                        Transform::from_translation(spawn_pos),
+                        EnemyProjectile {
+                            damage: enemy.attack_damage,
+                            speed: 18.0,
+                            direction,
+                            lifetime: 5.0,
+                            explosion_radius: 2.5,
+                            owner: Some(boss_entity),
+                        },
+                    ));
+                }
+            }
+        }
+
+        enemy.attack_cooldown = varied_cooldown(enemy.attack_interval, config.attack_cooldown_variance, rng.f32());
+    }
+}
+
+/// Boss health bar container (top-center) - hidden while no boss is alive
+#[derive(Component)]
+pub struct BossHealthBar;
+
+/// Fill of the boss health bar, sized to the boss's health fraction
+#[derive(Component)]
+pub struct BossHealthFill;
+
+/// "BOSS - <PHASE>" label above the bar
+#[derive(Component)]
+pub struct BossHealthLabel;
+
+pub fn spawn_boss_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(25.0),
+                width: Val::Percent(50.0),
+                top: Val::Px(36.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            BossHealthBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("BOSS"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.3, 0.1)),
+                BossHealthLabel,
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.2, 0.05, 0.05, 0.8)),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.9, 0.2, 0.1)),
+                    BossHealthFill,
+                ));
+        });
+}
+
+/// Show the bar while a boss is alive, with its health and phase
+pub fn update_boss_hud(
+    boss_query: Query<(&Boss, &Health)>,
+    mut bar_query: Query<&mut Visibility, With<BossHealthBar>>,
+    mut fill_query: Query<&mut Node, With<BossHealthFill>>,
+    mut label_query: Query<&mut Text, With<BossHealthLabel>>,
+) {
+    let boss = boss_query.iter().find(|(_, health)| !health.is_dead());
+
+    for mut visibility in &mut bar_query {
+        let target = if boss.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != target {
+            *visibility = target;
+        }
+    }
+
+    let Some((boss, health)) = boss else {
+        return;
+    };
+    for mut fill in &mut fill_query {
+        fill.width = Val::Percent(health.fraction().clamp(0.0, 1.0) * 100.0);
+    }
+    for mut label in &mut label_query {
+        let text = format!("BOSS - {}", boss.phase.name());
+        if **label != text {
+            **label = text;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{process_damage_events, DeathEvent};
+
+    #[test]
+    fn test_phase_thresholds() {
+        assert_eq!(BossPhase::for_health(1.0), BossPhase::Stomp);
+        assert_eq!(BossPhase::for_health(0.66), BossPhase::Stomp);
+        assert_eq!(BossPhase::for_health(0.5), BossPhase::Barrage);
+        assert_eq!(BossPhase::for_health(0.2), BossPhase::Charge);
+    }
+
+    #[test]
+    fn test_damage_advances_boss_phase() {
+        let mut app = test_app();
+        app.add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .add_systems(Update, (process_damage_events, update_boss_phase).chain());
+        let boss = app
+            .world_mut()
+            .spawn((Enemy::boss(), Boss::default(), Health::new(600.0)))
+            .id();
+
+        // 600 -> 350 HP (58%) crosses the Barrage threshold
+        app.world_mut().send_event(DamageEvent { target: boss, amount: 250.0, source: None });
+        app.update();
+        assert_eq!(app.world().get::<Boss>(boss).unwrap().phase, BossPhase::Barrage);
+        assert_eq!(app.world().get::<Enemy>(boss).unwrap().attack_range, 35.0);
+
+        // 350 -> 150 HP (25%) - charging
+        app.world_mut().send_event(DamageEvent { target: boss, amount: 200.0, source: None });
+        app.update();
+        assert_eq!(app.world().get::<Boss>(boss).unwrap().phase, BossPhase::Charge);
+        assert!(app.world().get::<Enemy>(boss).unwrap().speed > Enemy::boss().speed);
+
+        // Healing doesn't undo a phase
+        app.world_mut().get_mut::<Health>(boss).unwrap().heal(600.0);
+        app.update();
+        assert_eq!(app.world().get::<Boss>(boss).unwrap().phase, BossPhase::Charge);
+    }
+
+    /// Boss in its barrage phase, ready to fire at a player 10m away
+    fn barrage_test_app(config: EnemyConfig, difficulty: Difficulty) -> App {
+        let mut app = test_app();
+        app.add_event::<DamageEvent>()
+            .with_mesh_assets()
+            .insert_resource(config)
+            .insert_resource(difficulty)
+            .init_resource::<EnemyProjectileCount>()
+            .insert_resource(GameRng::new(1))
+            .add_systems(Update, boss_attack);
+
+        app.world_mut().spawn((Player, Transform::from_xyz(0.0, 1.0, -10.0)));
+        let mut enemy = Enemy::boss();
+        BossPhase::Barrage.apply(&mut enemy);
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            enemy,
+            Boss { phase: BossPhase::Barrage },
+            EnemyState::Attack,
+            Health::new(300.0),
+        ));
+        app
+    }
+
+    #[test]
+    fn test_barrage_fires_a_fan_of_projectiles() {
+        let mut app = barrage_test_app(EnemyConfig::default(), Difficulty::Normal);
+        app.update();
+
+        let world = app.world_mut();
+        let directions: Vec<Vec3> =
+            world.query::<&EnemyProjectile>().iter(world).map(|projectile| projectile.direction).collect();
+        assert_eq!(directions.len(), BARRAGE_SHOTS);
+        // Spread out sideways, all heading toward the player
+        assert!(directions.iter().all(|direction| direction.z < 0.0));
+        assert!(directions.iter().any(|direction| direction.x > 0.1));
+        assert!(directions.iter().any(|direction| direction.x < -0.1));
+        assert_eq!(app.world().resource::<EnemyProjectileCount>().live, BARRAGE_SHOTS);
+    }
+
+    #[test]
+    fn test_projectile_cap_scales_with_difficulty() {
+        let config = || EnemyConfig {
+            max_projectiles: 4,
+            ..default()
+        };
+        let live = |difficulty| {
+            let mut app = barrage_test_app(config(), difficulty);
+            app.update();
+            app.world().resource::<EnemyProjectileCount>().live
+        };
+
+        assert_eq!(live(Difficulty::Easy), 3);
+        assert_eq!(live(Difficulty::Normal), 4);
+        assert_eq!(live(Difficulty::Nightmare), BARRAGE_SHOTS);
+    }
+}
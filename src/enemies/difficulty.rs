@@ -0,0 +1,136 @@
+// Difficulty presets - scale enemy stats and wave sizes as enemies spawn
+
+use bevy::prelude::*;
+
+use super::Enemy;
+
+/// Difficulty preset, picked in the settings menu. Applies to enemies as they spawn,
+/// so a change takes effect from the next wave.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+impl Difficulty {
+    /// Cycle to the next difficulty
+    pub fn next(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Nightmare,
+            Difficulty::Nightmare => Difficulty::Easy,
+        }
+    }
+
+    /// Cycle to the previous difficulty
+    pub fn prev(self) -> Self {
+        match self {
+            Difficulty::Easy => Difficulty::Nightmare,
+            Difficulty::Normal => Difficulty::Easy,
+            Difficulty::Hard => Difficulty::Normal,
+            Difficulty::Nightmare => Difficulty::Hard,
+        }
+    }
+
+    /// Get display name for this difficulty
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+            Difficulty::Nightmare => "NIGHTMARE",
+        }
+    }
+
+    pub fn health_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+            Difficulty::Nightmare => 2.0,
+        }
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.85,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.15,
+            Difficulty::Nightmare => 1.3,
+        }
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Nightmare => 1.5,
+        }
+    }
+
+    pub fn count_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.75,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Nightmare => 1.5,
+        }
+    }
+
+    /// Live enemy projectile cap scale - tracks the enemy count, so bigger waves fill the air with more shots
+    pub fn projectile_cap_multiplier(&self) -> f32 {
+        self.count_multiplier()
+    }
+
+    /// Scale the live enemy projectile cap - never below one, so ranged enemies can still fire
+    pub fn scale_projectile_cap(&self, cap: usize) -> usize {
+        ((cap as f32 * self.projectile_cap_multiplier()).round() as usize).max(1)
+    }
+
+    /// Scale a spawn count - never rounds a non-empty group down to nothing
+    pub fn scale_count(&self, count: u32) -> u32 {
+        if count == 0 {
+            return 0;
+        }
+        ((count as f32 * self.count_multiplier()).round() as u32).max(1)
+    }
+
+    /// Scale an enemy's speed and attack damage
+    pub fn apply_to_enemy(&self, enemy: &mut Enemy) {
+        enemy.speed *= self.speed_multiplier();
+        enemy.attack_damage *= self.damage_multiplier();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_and_prev_round_trip() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard, Difficulty::Nightmare] {
+            assert_eq!(difficulty.next().prev(), difficulty);
+        }
+    }
+
+    #[test]
+    fn test_scale_count() {
+        assert_eq!(Difficulty::Normal.scale_count(6), 6);
+        assert_eq!(Difficulty::Nightmare.scale_count(6), 9);
+        assert_eq!(Difficulty::Easy.scale_count(1), 1);
+        assert_eq!(Difficulty::Easy.scale_count(0), 0);
+    }
+
+    #[test]
+    fn test_scale_projectile_cap() {
+        assert_eq!(Difficulty::Easy.scale_projectile_cap(24), 18);
+        assert_eq!(Difficulty::Normal.scale_projectile_cap(24), 24);
+        assert_eq!(Difficulty::Nightmare.scale_projectile_cap(24), 36);
+        assert_eq!(Difficulty::Easy.scale_projectile_cap(1), 1);
+    }
+}
@@ -0,0 +1,233 @@
+// Impact markers - a ring on the ground under each enemy projectile's predicted impact point,
+// sized to its blast radius, so the danger zone is visible before the projectile detonates
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::EnemyProjectile;
+use crate::level::{BoxCollider, GroundFloor};
+use crate::player::Player;
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+
+/// Distance from the player at which enemy projectiles detonate
+pub const PROJECTILE_PLAYER_HIT_RADIUS: f32 = 1.5;
+
+/// How close to a collider's faces enemy projectiles detonate
+pub const PROJECTILE_COLLIDER_PADDING: f32 = 0.2;
+
+/// Rings float this far above the surface they mark so they don't sink into it
+const MARKER_LIFT: f32 = 0.05;
+
+/// Whether an enemy projectile at `position` is close enough to a collider to detonate
+pub fn projectile_touches(position: Vec3, center: Vec3, collider: &BoxCollider) -> bool {
+    let reach = collider.half_extents + Vec3::splat(PROJECTILE_COLLIDER_PADDING);
+    let diff = (position - center).abs();
+    diff.x < reach.x && diff.y < reach.y && diff.z < reach.z
+}
+
+/// Ring showing where a projectile will explode - follows its projectile and goes away with it
+#[derive(Component)]
+pub struct ImpactMarker {
+    pub projectile: Entity,
+}
+
+/// Where a projectile flying from `origin` will detonate - the first collider it comes within
+/// `PROJECTILE_COLLIDER_PADDING` of, the player if it passes close enough first, or wherever
+/// its lifetime runs out
+pub fn predict_impact<'a>(
+    origin: Vec3,
+    projectile: &EnemyProjectile,
+    colliders: impl IntoIterator<Item = (&'a Transform, &'a BoxCollider)>,
+    player: Option<Vec3>,
+) -> Vec3 {
+    let mut range = projectile.speed * projectile.lifetime.max(0.0);
+
+    for (transform, collider) in colliders {
+        let padded = BoxCollider {
+            half_extents: collider.half_extents + Vec3::splat(PROJECTILE_COLLIDER_PADDING),
+        };
+        if let Some(hit) = padded.ray_intersection(transform.translation, origin, projectile.direction, range) {
+            range = hit.distance;
+        }
+    }
+
+    // First point along the path within hit range of the player
+    if let Some(player) = player {
+        let to_player = player - origin;
+        let along = to_player.dot(projectile.direction);
+        let miss_sq = to_player.length_squared() - along * along;
+        let hit_sq = PROJECTILE_PLAYER_HIT_RADIUS * PROJECTILE_PLAYER_HIT_RADIUS;
+        if miss_sq < hit_sq {
+            let entry = (along - (hit_sq - miss_sq).sqrt()).max(0.0);
+            range = range.min(entry);
+        }
+    }
+
+    origin + projectile.direction * range
+}
+
+/// Drop a point straight down onto the top of whatever it's above, or the floor (y = 0)
+/// if it isn't over anything. `colliders` shouldn't include the ground floor's collider,
+/// which sits below the visible floor.
+pub fn ground_below<'a>(point: Vec3, colliders: impl IntoIterator<Item = (&'a Transform, &'a BoxCollider)>) -> Vec3 {
+    let mut height = 0.0_f32;
+    for (transform, collider) in colliders {
+        let center = transform.translation;
+        let half = collider.half_extents;
+        let top = center.y + half.y;
+        let above = (point.x - center.x).abs() <= half.x && (point.z - center.z).abs() <= half.z;
+        // Blasts on a box top stop the padding distance above it
+        if above && top <= point.y + PROJECTILE_COLLIDER_PADDING {
+            height = height.max(top);
+        }
+    }
+    point.with_y(height)
+}
+
+/// Keep one ring per enemy projectile on the ground under its predicted impact point,
+/// removing rings whose projectile has exploded
+pub fn update_impact_markers(
+    mut commands: Commands,
+    projectile_query: Query<(Entity, &Transform, &EnemyProjectile)>,
+    mut marker_query: Query<(Entity, &mut Transform, &ImpactMarker), Without<EnemyProjectile>>,
+    collider_query: Query<(&Transform, &BoxCollider, Has<GroundFloor>), Without<ImpactMarker>>,
+    player_query: Query<&Transform, (With<Player>, Without<ImpactMarker>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let player = player_query.single().ok().map(|transform| transform.translation);
+
+    let colliders = || collider_query.iter().map(|(transform, collider, _)| (transform, collider));
+    let surfaces = || {
+        collider_query
+            .iter()
+            .filter(|(_, _, ground)| !ground)
+            .map(|(transform, collider, _)| (transform, collider))
+    };
+
+    let mut impacts: HashMap<Entity, (Vec3, f32)> = projectile_query
+        .iter()
+        .map(|(entity, transform, projectile)| {
+            let impact = predict_impact(transform.translation, projectile, colliders(), player);
+            let ground = ground_below(impact, surfaces()) + Vec3::Y * MARKER_LIFT;
+            (entity, (ground, projectile.explosion_radius))
+        })
+        .collect();
+
+    for (entity, mut transform, marker) in &mut marker_query {
+        match impacts.remove(&marker.projectile) {
+            Some((impact, radius)) => {
+                transform.translation = impact;
+                transform.scale = Vec3::new(radius, 1.0, radius);
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    if impacts.is_empty() {
+        return;
+    }
+
+    // Projectiles fired since last frame - a unit ring scaled out to the blast radius
+    let mesh = meshes.add(Torus::new(0.94, 1.0));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.3, 0.9, 0.6),
+        emissive: LinearRgba::rgb(2.5, 0.4, 2.0),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for (projectile, (impact, radius)) in impacts {
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_translation(impact).with_scale(Vec3::new(radius, 1.0, radius)),
+            AsciiPatternId::matrix_cycle().with_category(AsciiCategory::Effect),
+            ImpactMarker { projectile },
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn projectile(direction: Vec3) -> EnemyProjectile {
+        EnemyProjectile {
+            damage: 10.0,
+            speed: 10.0,
+            direction,
+            lifetime: 5.0,
+            explosion_radius: 3.0,
+            owner: None,
+        }
+    }
+
+    #[test]
+    fn test_impact_predicted_at_wall() {
+        let wall = (
+            Transform::from_xyz(0.0, 0.0, -10.0),
+            BoxCollider {
+                half_extents: Vec3::new(5.0, 5.0, 0.5),
+            },
+        );
+        let impact = predict_impact(Vec3::ZERO, &projectile(Vec3::NEG_Z), [(&wall.0, &wall.1)], None);
+        // Detonates the collider padding short of the face, same as the projectile itself
+        assert!((impact - Vec3::new(0.0, 0.0, -9.5 + PROJECTILE_COLLIDER_PADDING)).length() < 1e-4);
+        assert!(projectile_touches(impact + Vec3::NEG_Z * 0.01, wall.0.translation, &wall.1));
+    }
+
+    #[test]
+    fn test_impact_dropped_to_the_surface_below() {
+        let crate_box = (
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            BoxCollider {
+                half_extents: Vec3::new(1.0, 1.0, 1.0),
+            },
+        );
+        let surfaces = || [(&crate_box.0, &crate_box.1)];
+
+        // Mid-air at chest height, or high up a wall - marked on the floor
+        assert_eq!(ground_below(Vec3::new(5.0, 1.2, 0.0), surfaces()), Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(ground_below(Vec3::new(1.2, 3.0, 0.0), surfaces()), Vec3::new(1.2, 0.0, 0.0));
+        // Just above the crate top - marked on the crate
+        assert_eq!(ground_below(Vec3::new(0.5, 2.2, 0.0), surfaces()), Vec3::new(0.5, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_impact_predicted_at_player() {
+        let impact = predict_impact(Vec3::ZERO, &projectile(Vec3::X), [], Some(Vec3::new(8.0, 0.0, 0.0)));
+        assert!((impact.x - (8.0 - PROJECTILE_PLAYER_HIT_RADIUS)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_impact_falls_back_to_lifetime_range() {
+        let impact = predict_impact(Vec3::ZERO, &projectile(Vec3::X), [], Some(Vec3::new(0.0, 0.0, 20.0)));
+        assert!((impact.x - 50.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_marker_follows_projectile_and_despawns_with_it() {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .add_systems(Update, update_impact_markers);
+        let shot = app
+            .world_mut()
+            .spawn((Transform::default(), projectile(Vec3::X)))
+            .id();
+
+        app.update();
+        let mut markers = app.world_mut().query::<(&Transform, &ImpactMarker)>();
+        let (transform, marker) = markers.single(app.world()).unwrap();
+        assert_eq!(marker.projectile, shot);
+        assert!((transform.translation.x - 50.0).abs() < 1e-4);
+        assert!((transform.translation.y - MARKER_LIFT).abs() < 1e-4);
+        assert!((transform.scale.x - 3.0).abs() < 1e-4);
+
+        app.world_mut().despawn(shot);
+        app.update();
+        assert_eq!(markers.iter(app.world()).count(), 0);
+    }
+}
@@ -0,0 +1,193 @@
+// Kill feed - the last few enemies the player killed, listed under the minimap and fading out
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use super::minimap::MINIMAP_SIZE;
+use super::{Enemy, EnemyType};
+use crate::combat::DeathEvent;
+
+/// A line in the kill feed
+#[derive(Clone, Copy)]
+pub struct KillFeedEntry {
+    pub enemy_type: EnemyType,
+    /// Seconds until the line disappears
+    pub remaining: f32,
+}
+
+/// Recent kills, oldest first
+#[derive(Resource, Default)]
+pub struct KillFeed {
+    pub entries: VecDeque<KillFeedEntry>,
+}
+
+impl KillFeed {
+    pub const MAX_ENTRIES: usize = 5;
+    /// How long a line stays up
+    pub const LIFETIME: f32 = 4.0;
+    /// Lines fade out over their last second
+    pub const FADE_TIME: f32 = 1.0;
+
+    /// Add a kill, dropping the oldest line once the feed is full
+    pub fn push(&mut self, enemy_type: EnemyType) {
+        if self.entries.len() == Self::MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(KillFeedEntry {
+            enemy_type,
+            remaining: Self::LIFETIME,
+        });
+    }
+
+    /// Run down every line's timer, removing expired ones
+    pub fn tick(&mut self, dt: f32) {
+        for entry in &mut self.entries {
+            entry.remaining -= dt;
+        }
+        self.entries.retain(|entry| entry.remaining > 0.0);
+    }
+}
+
+impl KillFeedEntry {
+    pub fn text(&self) -> &'static str {
+        match self.enemy_type {
+            EnemyType::Melee => "MELEE",
+            EnemyType::Ranged => "RANGED",
+            EnemyType::Boss => "BOSS",
+        }
+    }
+
+    /// Opacity - solid until the last `FADE_TIME` seconds
+    pub fn alpha(&self) -> f32 {
+        (self.remaining / KillFeed::FADE_TIME).clamp(0.0, 1.0)
+    }
+}
+
+/// Kill feed panel - holds one text line per feed slot
+#[derive(Component)]
+pub struct KillFeedPanel;
+
+/// Text line showing the feed entry at this index
+#[derive(Component)]
+pub struct KillFeedLine(pub usize);
+
+pub fn spawn_kill_feed(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(110.0 + MINIMAP_SIZE),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::FlexEnd,
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            KillFeedPanel,
+        ))
+        .with_children(|parent| {
+            for index in 0..KillFeed::MAX_ENTRIES {
+                parent.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.4, 0.3)),
+                    KillFeedLine(index),
+                ));
+            }
+        });
+}
+
+/// Add a line for every enemy killed by the player (enemies blowing each other up don't count),
+/// then age the feed
+pub fn update_kill_feed(
+    mut death_events: EventReader<DeathEvent>,
+    enemy_query: Query<&EnemyType, With<Enemy>>,
+    mut feed: ResMut<KillFeed>,
+    time: Res<Time>,
+) {
+    feed.tick(time.delta_secs());
+
+    for event in death_events.read() {
+        if event.killer.is_some_and(|killer| enemy_query.contains(killer)) {
+            continue;
+        }
+        if let Ok(enemy_type) = enemy_query.get(event.entity) {
+            feed.push(*enemy_type);
+        }
+    }
+}
+
+pub fn update_kill_feed_hud(feed: Res<KillFeed>, mut line_query: Query<(&mut Text, &mut TextColor, &KillFeedLine)>) {
+    for (mut text, mut color, line) in &mut line_query {
+        let (label, alpha) = feed.entries.get(line.0).map_or(("", 0.0), |entry| (entry.text(), entry.alpha()));
+        if **text != label {
+            **text = label.to_string();
+        }
+        color.0.set_alpha(alpha);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn kill_feed_app() -> App {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .add_event::<DeathEvent>()
+            .init_resource::<KillFeed>()
+            .add_systems(Update, update_kill_feed);
+        app
+    }
+
+    #[test]
+    fn test_kills_appear_then_expire() {
+        let mut app = kill_feed_app();
+        let melee = app.world_mut().spawn((Enemy::melee(), EnemyType::Melee)).id();
+        let ranged = app.world_mut().spawn((Enemy::melee(), EnemyType::Ranged)).id();
+
+        app.world_mut().send_event(DeathEvent { entity: melee, killer: None });
+        app.world_mut().send_event(DeathEvent { entity: ranged, killer: None });
+        app.update();
+
+        let labels: Vec<_> = app.world().resource::<KillFeed>().entries.iter().map(KillFeedEntry::text).collect();
+        assert_eq!(labels, ["MELEE", "RANGED"]);
+
+        // Quarter-second frames (the longest step virtual time takes) - well past the lifetime
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(app.world().resource::<KillFeed>().entries.is_empty());
+    }
+
+    #[test]
+    fn test_enemy_on_enemy_kills_are_not_listed() {
+        let mut app = kill_feed_app();
+        let victim = app.world_mut().spawn((Enemy::melee(), EnemyType::Melee)).id();
+        let killer = app.world_mut().spawn((Enemy::melee(), EnemyType::Ranged)).id();
+
+        app.world_mut().send_event(DeathEvent { entity: victim, killer: Some(killer) });
+        app.update();
+        assert!(app.world().resource::<KillFeed>().entries.is_empty());
+    }
+
+    #[test]
+    fn test_feed_keeps_the_most_recent_entries() {
+        let mut feed = KillFeed::default();
+        feed.push(EnemyType::Boss);
+        for _ in 0..KillFeed::MAX_ENTRIES {
+            feed.push(EnemyType::Melee);
+        }
+        assert_eq!(feed.entries.len(), KillFeed::MAX_ENTRIES);
+        assert!(feed.entries.iter().all(|entry| entry.enemy_type == EnemyType::Melee));
+
+        // Fades over the last second only
+        feed.tick(KillFeed::LIFETIME - 0.5);
+        assert!((feed.entries[0].alpha() - 0.5).abs() < 0.001);
+    }
+}
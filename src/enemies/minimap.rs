@@ -0,0 +1,247 @@
+// Top-down arena map (top-right, under the score) - walls, platforms, live enemies and the player
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use super::{Enemy, EnemyState, EnemyType};
+use crate::level::{BoxCollider, GroundFloor, WallCollider, ARENA_SIZE};
+use crate::player::Player;
+
+/// Width and height of the minimap (pixels) - the whole arena is scaled to fit
+pub const MINIMAP_SIZE: f32 = 180.0;
+/// Diameter of enemy/player dots (pixels)
+const DOT_SIZE: f32 = 5.0;
+/// How far ahead of the player dot the heading tick sits (pixels)
+const HEADING_OFFSET: f32 = 6.0;
+
+/// Minimap background; blips are its children
+#[derive(Component)]
+pub struct Minimap;
+
+/// Minimap marker for a world entity (enemy or level box) - removed when the entity goes away
+#[derive(Component)]
+pub struct MinimapBlip {
+    pub source: Entity,
+}
+
+/// Player dot
+#[derive(Component)]
+pub struct MinimapPlayer;
+
+/// Small tick ahead of the player dot showing which way they face
+#[derive(Component)]
+pub struct MinimapHeading;
+
+/// Project a world position onto the minimap (pixels from its top-left corner).
+/// North (-Z) is up; anything outside the arena is clamped to the edge.
+pub fn world_to_minimap(position: Vec3) -> Vec2 {
+    let scale = MINIMAP_SIZE / (ARENA_SIZE * 2.0);
+    let map = Vec2::new(position.x + ARENA_SIZE, position.z + ARENA_SIZE) * scale;
+    map.clamp(Vec2::ZERO, Vec2::splat(MINIMAP_SIZE))
+}
+
+/// Minimap rectangle covering a box's footprint, clipped to the map
+pub fn box_to_minimap(center: Vec3, half_extents: Vec3) -> Rect {
+    Rect::from_corners(world_to_minimap(center - half_extents), world_to_minimap(center + half_extents))
+}
+
+fn enemy_color(enemy_type: EnemyType) -> Color {
+    match enemy_type {
+        EnemyType::Melee => Color::srgb(1.0, 0.25, 0.2),
+        EnemyType::Ranged => Color::srgb(1.0, 0.7, 0.1),
+        EnemyType::Boss => Color::srgb(1.0, 0.2, 1.0),
+    }
+}
+
+fn dot_node(center: Vec2, size: f32) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(center.x - size / 2.0),
+        top: Val::Px(center.y - size / 2.0),
+        width: Val::Px(size),
+        height: Val::Px(size),
+        ..default()
+    }
+}
+
+fn rect_node(rect: Rect) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(rect.min.x),
+        top: Val::Px(rect.min.y),
+        // Thin walls still show up as a line
+        width: Val::Px(rect.width().max(1.0)),
+        height: Val::Px(rect.height().max(1.0)),
+        ..default()
+    }
+}
+
+pub fn spawn_minimap(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(100.0),
+                width: Val::Px(MINIMAP_SIZE),
+                height: Val::Px(MINIMAP_SIZE),
+                border: UiRect::all(Val::Px(1.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            BorderColor(Color::srgb(0.3, 0.6, 0.3)),
+            Minimap,
+        ))
+        .with_children(|parent| {
+            // Drawn over every blip
+            parent.spawn((
+                dot_node(Vec2::splat(MINIMAP_SIZE / 2.0), DOT_SIZE + 1.0),
+                BackgroundColor(Color::srgb(0.3, 1.0, 0.3)),
+                ZIndex(2),
+                MinimapPlayer,
+            ));
+            parent.spawn((
+                dot_node(Vec2::splat(MINIMAP_SIZE / 2.0), 3.0),
+                BackgroundColor(Color::WHITE),
+                ZIndex(2),
+                MinimapHeading,
+            ));
+        });
+}
+
+/// Sync blips with the world: move existing ones, add new enemies/geometry, drop ones whose source is gone
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_minimap(
+    mut commands: Commands,
+    minimap_query: Query<Entity, With<Minimap>>,
+    player_query: Query<&Transform, With<Player>>,
+    enemy_query: Query<(Entity, &Transform, &EnemyType, &EnemyState), With<Enemy>>,
+    geometry_query: Query<(Entity, &Transform, &BoxCollider, Has<WallCollider>), (Without<GroundFloor>, Without<Enemy>)>,
+    mut blip_query: Query<(Entity, &MinimapBlip, &mut Node), (Without<MinimapPlayer>, Without<MinimapHeading>)>,
+    mut player_dot_query: Query<&mut Node, (With<MinimapPlayer>, Without<MinimapHeading>)>,
+    mut heading_query: Query<&mut Node, (With<MinimapHeading>, Without<MinimapPlayer>)>,
+) {
+    let Ok(minimap) = minimap_query.single() else {
+        return;
+    };
+
+    if let Ok(player_transform) = player_query.single() {
+        let center = world_to_minimap(player_transform.translation);
+        if let Ok(mut node) = player_dot_query.single_mut() {
+            *node = dot_node(center, DOT_SIZE + 1.0);
+        }
+        if let Ok(mut node) = heading_query.single_mut() {
+            let forward = player_transform.forward();
+            let heading = Vec2::new(forward.x, forward.z).normalize_or(Vec2::NEG_Y);
+            *node = dot_node(center + heading * HEADING_OFFSET, 3.0);
+        }
+    }
+
+    // Where every blip should be this frame, keyed by its source entity
+    let mut wanted: HashMap<Entity, (Node, Color)> = HashMap::new();
+    for (entity, transform, collider, is_wall) in &geometry_query {
+        let color = if is_wall { Color::srgb(0.6, 0.6, 0.6) } else { Color::srgb(0.35, 0.35, 0.5) };
+        wanted.insert(entity, (rect_node(box_to_minimap(transform.translation, collider.half_extents)), color));
+    }
+    for (entity, transform, enemy_type, state) in &enemy_query {
+        if !matches!(state, EnemyState::Dead) {
+            let node = dot_node(world_to_minimap(transform.translation), DOT_SIZE);
+            wanted.insert(entity, (node, enemy_color(*enemy_type)));
+        }
+    }
+
+    for (blip_entity, blip, mut node) in &mut blip_query {
+        match wanted.remove(&blip.source) {
+            Some((target, _)) => {
+                if *node != target {
+                    *node = target;
+                }
+            }
+            None => commands.entity(blip_entity).despawn(),
+        }
+    }
+
+    for (source, (node, color)) in wanted {
+        // Enemies sit above level geometry
+        let z = if enemy_query.contains(source) { 1 } else { 0 };
+        commands.spawn((node, BackgroundColor(color), ZIndex(z), MinimapBlip { source }, ChildOf(minimap)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn approx_eq(a: Vec2, b: Vec2) -> bool {
+        (a - b).length() < 0.001
+    }
+
+    // ==================== Projection Tests ====================
+
+    #[test]
+    fn test_world_to_minimap_known_points() {
+        let half = MINIMAP_SIZE / 2.0;
+
+        // Arena center is the map center
+        assert!(approx_eq(world_to_minimap(Vec3::ZERO), Vec2::splat(half)));
+        // North-west corner (-X, -Z) is the top-left
+        assert!(approx_eq(world_to_minimap(Vec3::new(-ARENA_SIZE, 0.0, -ARENA_SIZE)), Vec2::ZERO));
+        // South-east corner is the bottom-right
+        assert!(approx_eq(world_to_minimap(Vec3::new(ARENA_SIZE, 0.0, ARENA_SIZE)), Vec2::splat(MINIMAP_SIZE)));
+        // Halfway east, height ignored
+        let east = world_to_minimap(Vec3::new(ARENA_SIZE / 2.0, 7.0, 0.0));
+        assert!(approx_eq(east, Vec2::new(half * 1.5, half)));
+    }
+
+    #[test]
+    fn test_world_to_minimap_clamps_outside_arena() {
+        let outside = world_to_minimap(Vec3::new(ARENA_SIZE * 3.0, 0.0, -ARENA_SIZE * 2.0));
+        assert!(approx_eq(outside, Vec2::new(MINIMAP_SIZE, 0.0)));
+    }
+
+    #[test]
+    fn test_box_to_minimap_clips_to_map() {
+        // North wall straddles the arena edge - only the inside half is drawn
+        let wall = box_to_minimap(Vec3::new(0.0, 4.0, -ARENA_SIZE), Vec3::new(ARENA_SIZE, 4.0, 1.0));
+        assert!(approx_eq(wall.min, Vec2::ZERO));
+        assert!((wall.max.x - MINIMAP_SIZE).abs() < 0.001);
+        assert!(wall.height() > 0.0 && wall.height() < 1.0);
+    }
+
+    // ==================== Blip Tests ====================
+
+    #[test]
+    fn test_blips_follow_enemies_and_despawn_with_them() {
+        let mut app = test_app();
+        app.add_systems(Startup, spawn_minimap)
+            .add_systems(Update, update_minimap);
+        app.world_mut().spawn((Player, Transform::default()));
+        let enemy = app
+            .world_mut()
+            .spawn((Enemy::melee(), Transform::from_xyz(50.0, 1.0, 0.0), EnemyType::Ranged, EnemyState::Chase))
+            .id();
+        app.update();
+
+        let blip_left = |app: &mut App| {
+            let world = app.world_mut();
+            let mut query = world.query::<(&MinimapBlip, &Node)>();
+            query.iter(world).find(|(blip, _)| blip.source == enemy).map(|(_, node)| node.left)
+        };
+        let expected = world_to_minimap(Vec3::new(50.0, 0.0, 0.0)).x - DOT_SIZE / 2.0;
+        assert_eq!(blip_left(&mut app), Some(Val::Px(expected)));
+
+        // Moves with the enemy
+        app.world_mut().get_mut::<Transform>(enemy).unwrap().translation.x = -50.0;
+        app.update();
+        let expected = world_to_minimap(Vec3::new(-50.0, 0.0, 0.0)).x - DOT_SIZE / 2.0;
+        assert_eq!(blip_left(&mut app), Some(Val::Px(expected)));
+
+        // Gone once it dies
+        *app.world_mut().get_mut::<EnemyState>(enemy).unwrap() = EnemyState::Dead;
+        app.update();
+        assert_eq!(blip_left(&mut app), None);
+    }
+}
@@ -1,34 +1,102 @@
 //! Enemy AI and spawning system
 //! Phase 4: Enemy entities, patrol, death states
 
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 
-use crate::combat::{DamageEvent, Dead, DeathEvent, Health, Shootable, Weapon};
-use crate::level::{BoxCollider, GroundFloor, Slope, WallCollider, ARENA_SIZE};
-use crate::player::Player;
-use crate::rendering::AsciiPatternId;
+use crate::audio::{SoundEffect, SoundEmitter};
+use crate::combat::{
+    spawn_pickup, Blast, CombatConfig, DamageEvent, DeathEvent, Faction, Headbox, Health, Pickup, Shootable,
+    SpatialGrid,
+};
+use crate::level::{line_of_sight_clear, BoxCollider, CylinderCollider, GroundFloor, LevelDef, Slope, WallCollider, ARENA_SIZE};
+use crate::player::{Action, ActionInput, Binding, InputMap, Player};
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+use crate::rng::GameRng;
 use crate::GameState;
 
+mod alert;
+mod boss;
+mod difficulty;
+mod impact_marker;
+mod kill_feed;
+mod minimap;
+mod navigation;
+mod round_timer;
+mod telegraph;
+
+use alert::propagate_alerts;
+pub use boss::Boss;
+use boss::{boss_attack, spawn_boss_hud, update_boss_hud, update_boss_phase};
+pub use difficulty::Difficulty;
+use impact_marker::{projectile_touches, update_impact_markers, PROJECTILE_PLAYER_HIT_RADIUS};
+pub use kill_feed::KillFeed;
+use kill_feed::{spawn_kill_feed, update_kill_feed, update_kill_feed_hud};
+use minimap::{spawn_minimap, update_minimap};
+pub use navigation::NavPath;
+use navigation::{build_nav_grid, update_enemy_paths};
+pub use round_timer::RoundTimer;
+use round_timer::{
+    finish_round_timer, load_best_time, reset_round_timer, spawn_round_timer_hud, start_round_timer, stop_round_timer,
+    tick_round_timer, update_round_timer_hud,
+};
+pub use telegraph::SpawnTelegraph;
+use telegraph::{spawn_telegraphs, update_spawn_telegraphs};
+
 pub struct EnemyPlugin;
 
 impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_initial_enemies)
+        app.init_resource::<EnemyConfig>()
+            .init_resource::<Difficulty>()
+            .init_resource::<EnemyProjectileCount>()
+            .init_resource::<PlayerMotion>()
+            .init_resource::<Score>()
+            .init_resource::<KillFeed>()
+            .init_resource::<RoundTimer>()
+            .init_resource::<WaveState>()
+            .add_systems(
+                Startup,
+                (
+                    (spawn_first_wave, spawn_wave_huds).chain(),
+                    spawn_boss_hud,
+                    spawn_minimap,
+                    spawn_kill_feed,
+                    load_best_time,
+                    spawn_round_timer_hud,
+                ),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                (reset_round_timer, (clear_last_run, spawn_first_wave).chain()),
+            )
+            .add_systems(OnEnter(GameState::Playing), start_round_timer)
+            .add_systems(OnEnter(GameState::GameOver), stop_round_timer)
             .add_systems(
                 Update,
                 (
+                    (build_nav_grid, track_player_motion, tick_round_timer),
                     update_wander_targets,
-                    enemy_ai_update,
+                    update_line_of_sight,
+                    (update_boss_phase, propagate_alerts, enemy_ai_update).chain(),
+                    update_enemy_paths,
                     enemy_movement,
                     enemy_collision,
-                    enemy_melee_attack,
-                    enemy_ranged_attack,
-                    update_enemy_projectiles,
+                    (enemy_melee_attack, enemy_ranged_attack, boss_attack),
+                    (update_enemy_projectiles, update_impact_markers).chain(),
                     update_enemy_explosions,
                     trigger_hit_reactions,
                     update_hit_reactions,
-                    handle_enemy_death,
-                    update_kill_counter,
+                    update_hurt_flashes,
+                    (tick_score_combo, handle_enemy_death, update_kill_feed).chain(),
+                    update_despawn_timers,
+                    (update_spawn_telegraphs, skip_wave_rest, advance_waves, finish_round_timer).chain(),
+                    (update_kill_counter, update_score_hud, update_kill_feed_hud, update_round_timer_hud),
+                    (update_wave_hud, update_boss_hud, update_minimap),
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
@@ -36,12 +104,117 @@ impl Plugin for EnemyPlugin {
     }
 }
 
+/// Enemy tuning shared by all enemies
+#[derive(Resource)]
+pub struct EnemyConfig {
+    /// Random +/- fraction applied to each attack cooldown so groups don't attack in sync
+    pub attack_cooldown_variance: f32,
+    /// Maximum live enemy projectiles on Normal (scaled by difficulty) - ranged enemies hold fire while at the cap
+    pub max_projectiles: usize,
+    /// Seconds of rest between clearing a wave and the next one spawning
+    pub wave_rest_duration: f32,
+    /// Pickups scattered around the arena when a wave is cleared (0 disables the refresh)
+    pub wave_rest_pickups: u32,
+    /// How far ranged enemies lead a moving player - 0 aims straight at them, 1 at the exact intercept
+    pub lead_accuracy: f32,
+    /// Seconds a wave enemy's spawn point is marked before it appears
+    pub spawn_telegraph_time: f32,
+    /// Idle enemies this close to the player's gunfire or blasts hear it, even through walls
+    pub hearing_radius: f32,
+    /// Idle enemies this close to a hurt enemy they can see join in
+    pub alert_radius: f32,
+    /// Seconds an alerted enemy keeps chasing without spotting the player
+    pub alert_duration: f32,
+}
+
+impl Default for EnemyConfig {
+    fn default() -> Self {
+        Self {
+            attack_cooldown_variance: 0.3,
+            max_projectiles: 24,
+            wave_rest_duration: 3.0,
+            wave_rest_pickups: 2,
+            lead_accuracy: 0.8,
+            spawn_telegraph_time: 1.0,
+            hearing_radius: 30.0,
+            alert_radius: 15.0,
+            alert_duration: 6.0,
+        }
+    }
+}
+
+/// Number of live enemy projectiles, kept in sync as they spawn and explode
+#[derive(Resource, Default)]
+pub struct EnemyProjectileCount {
+    pub live: usize,
+}
+
+impl EnemyProjectileCount {
+    /// Claim a slot for a new projectile - returns false if at the cap
+    pub fn try_spawn(&mut self, cap: usize) -> bool {
+        if self.live >= cap {
+            return false;
+        }
+        self.live += 1;
+        true
+    }
+
+    pub fn despawned(&mut self) {
+        self.live = self.live.saturating_sub(1);
+    }
+}
+
+/// Player velocity as seen by enemies, estimated from the player's position each frame
+#[derive(Resource, Default)]
+pub struct PlayerMotion {
+    last_position: Option<Vec3>,
+    pub velocity: Vec3,
+}
+
+/// Point to fire at so a projectile at `projectile_speed` meets a target moving at `target_velocity`.
+/// Falls back to the target's current position when it can't be caught.
+pub fn lead_target(shooter: Vec3, target: Vec3, target_velocity: Vec3, projectile_speed: f32) -> Vec3 {
+    // Solve |offset + velocity * t| = speed * t for the earliest t > 0
+    let offset = target - shooter;
+    let a = target_velocity.length_squared() - projectile_speed * projectile_speed;
+    let b = 2.0 * offset.dot(target_velocity);
+    let c = offset.length_squared();
+
+    let time = if a.abs() < 1e-4 {
+        (b < 0.0).then(|| -c / b)
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            None
+        } else {
+            let root = discriminant.sqrt();
+            [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+                .into_iter()
+                .filter(|t| *t > 0.0)
+                .reduce(f32::min)
+        }
+    };
+
+    match time {
+        Some(time) => target + target_velocity * time,
+        None => target,
+    }
+}
+
+/// Cooldown after an attack - base interval scaled by a random roll in [0, 1)
+/// into the range [1 - variance, 1 + variance]
+pub fn varied_cooldown(base: f32, variance: f32, roll: f32) -> f32 {
+    let variance = variance.clamp(0.0, 1.0);
+    base * (1.0 - variance + 2.0 * variance * roll)
+}
+
 /// Enemy type determines behavior and appearance
 #[derive(Component, Clone, Copy, PartialEq, Eq, Default)]
 pub enum EnemyType {
     #[default]
     Melee,   // Rushes player and attacks up close
     Ranged,  // Keeps distance and shoots projectiles
+    Boss,    // Heavy with phased attacks - see boss.rs
 }
 
 /// Enemy component with stats
@@ -51,6 +224,8 @@ pub struct Enemy {
     pub attack_damage: f32,
     pub attack_range: f32,
     pub attack_cooldown: f32,
+    /// Base time between attacks, before variance
+    pub attack_interval: f32,
     pub sight_range: f32,
     pub preferred_range: f32, // For ranged enemies - distance to maintain
 }
@@ -62,6 +237,7 @@ impl Enemy {
             attack_damage: 10.0,
             attack_range: 3.0,
             attack_cooldown: 0.0,
+            attack_interval: 1.0,
             sight_range: 50.0,
             preferred_range: 2.0,
         }
@@ -73,6 +249,7 @@ impl Enemy {
             attack_damage: 8.0,
             attack_range: 40.0,  // Can shoot from far
             attack_cooldown: 0.0,
+            attack_interval: 1.5, // Slower fire rate than melee attack speed
             sight_range: 60.0,
             preferred_range: 20.0, // Tries to stay at this distance
         }
@@ -109,6 +286,34 @@ impl HitReaction {
     }
 }
 
+/// Hurt flash - tints the enemy body emissive white when damaged, decaying back to base
+#[derive(Component, Default)]
+pub struct HurtFlash {
+    pub timer: f32,
+    /// Body material's emissive before any flash, cached on the first hit
+    pub base_emissive: Option<LinearRgba>,
+}
+
+impl HurtFlash {
+    pub const DURATION: f32 = 0.15;
+    const FLASH_EMISSIVE: LinearRgba = LinearRgba::rgb(3.0, 3.0, 3.0);
+
+    pub fn trigger(&mut self) {
+        self.timer = Self::DURATION;
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        self.timer = (self.timer - dt).max(0.0);
+    }
+
+    /// Current emissive - full white right after a hit, back to base once the timer runs out
+    pub fn emissive(&self) -> LinearRgba {
+        let base = self.base_emissive.unwrap_or(LinearRgba::BLACK);
+        let t = (self.timer / Self::DURATION).clamp(0.0, 1.0);
+        base.mix(&Self::FLASH_EMISSIVE, t)
+    }
+}
+
 /// Wander behavior for idle enemies
 #[derive(Component)]
 pub struct WanderBehavior {
@@ -129,6 +334,13 @@ impl WanderBehavior {
     }
 }
 
+/// Whether the enemy can currently see the player - refreshed once per frame
+/// by `update_line_of_sight` so AI and attacks share one raycast per enemy
+#[derive(Component, Default)]
+pub struct LineOfSight {
+    pub visible: bool,
+}
+
 /// AI behavior states
 #[derive(Component, Default, Clone)]
 pub enum EnemyState {
@@ -149,6 +361,8 @@ pub struct EnemyProjectile {
     pub direction: Vec3,
     pub lifetime: f32,
     pub explosion_radius: f32,
+    /// Enemy that fired this projectile (credited as the killer)
+    pub owner: Option<Entity>,
 }
 
 /// Enemy explosion effect
@@ -159,47 +373,65 @@ pub struct EnemyExplosion {
     pub damage: f32,
     pub lifetime: f32,
     pub has_damaged: bool,
+    pub owner: Option<Entity>,
 }
 
-/// Spawn initial enemies around the arena
-fn spawn_initial_enemies(
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    // Melee enemy material - menacing red/dark
-    let melee_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.8, 0.2, 0.2),
-        emissive: LinearRgba::rgb(0.4, 0.05, 0.05),
-        perceptual_roughness: 0.6,
-        ..default()
-    });
+/// Wave progression - a new, bigger wave arrives after a rest once the last enemy dies
+#[derive(Resource)]
+pub struct WaveState {
+    pub current_wave: u32,
+    /// Living enemies in the current wave
+    pub enemies_remaining: u32,
+    /// Seconds of rest left before the next wave spawns (None = wave in progress)
+    pub intermission: Option<f32>,
+    /// The final wave is down - no more waves spawn
+    pub all_cleared: bool,
+}
 
-    // Ranged enemy material - purple/blue
-    let ranged_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.4, 0.2, 0.8),
-        emissive: LinearRgba::rgb(0.1, 0.05, 0.4),
-        perceptual_roughness: 0.6,
-        ..default()
-    });
+impl Default for WaveState {
+    fn default() -> Self {
+        Self {
+            current_wave: 1,
+            enemies_remaining: 0,
+            intermission: None,
+            all_cleared: false,
+        }
+    }
+}
 
-    // Eye materials
-    let melee_eye_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(1.0, 1.0, 0.0),
-        emissive: LinearRgba::rgb(2.0, 2.0, 0.0),
-        unlit: true,
-        ..default()
-    });
+impl WaveState {
+    /// Clearing this wave ends the round (and stops the round timer)
+    pub const FINAL_WAVE: u32 = 10;
 
-    let ranged_eye_material = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.5, 1.0, 1.0),
-        emissive: LinearRgba::rgb(1.0, 2.0, 2.0),
-        unlit: true,
-        ..default()
-    });
+    /// (melee, ranged) enemy counts for a wave - wave 1 is the starting 6 + 4
+    pub fn enemy_counts(wave: u32) -> (u32, u32) {
+        let extra = wave.saturating_sub(1);
+        (6 + 2 * extra, 4 + extra)
+    }
+
+    /// Enemy health scale for a wave - each wave is 25% tougher than the first
+    pub fn health_multiplier(wave: u32) -> f32 {
+        1.0 + 0.25 * wave.saturating_sub(1) as f32
+    }
+
+    /// Every fifth wave brings a boss along
+    pub fn has_boss(wave: u32) -> bool {
+        wave.is_multiple_of(5)
+    }
+}
 
+/// Spawn the first wave around the arena - at startup, and again for every new run
+fn spawn_first_wave(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
+    mut wave: ResMut<WaveState>,
+    difficulty: Res<Difficulty>,
+    level: Option<Res<LevelDef>>,
+) {
     // Melee enemy positions
-    let melee_positions = [
+    let mut melee_positions = vec![
         Vec3::new(-30.0, 1.0, -30.0),
         Vec3::new(30.0, 1.0, -30.0),
         Vec3::new(-30.0, 1.0, 30.0),
@@ -209,35 +441,61 @@ fn spawn_initial_enemies(
     ];
 
     // Ranged enemy positions
-    let ranged_positions = [
+    let mut ranged_positions = vec![
         Vec3::new(-50.0, 1.0, 0.0),
         Vec3::new(50.0, 1.0, 0.0),
         Vec3::new(-60.0, 1.0, -60.0),
         Vec3::new(60.0, 1.0, 60.0),
     ];
 
-    for pos in melee_positions {
-        spawn_enemy(
-            &mut commands,
-            &mut meshes,
-            &melee_material,
-            &melee_eye_material,
-            pos,
-            EnemyType::Melee,
-        );
+    // A level file brings its own spawn points
+    if let Some(level) = level {
+        melee_positions = level.melee_spawns.iter().copied().map(Vec3::from_array).collect();
+        ranged_positions = level.ranged_spawns.iter().copied().map(Vec3::from_array).collect();
     }
 
-    for pos in ranged_positions {
-        spawn_enemy(
-            &mut commands,
-            &mut meshes,
-            &ranged_material,
-            &ranged_eye_material,
-            pos,
-            EnemyType::Ranged,
-        );
+    // Harder difficulties fill out the group with random spawn points, easier ones thin it
+    let melee_count = difficulty.scale_count(melee_positions.len() as u32) as usize;
+    melee_positions.resize_with(melee_count, || random_spawn_position(&mut rng, None));
+    let ranged_count = difficulty.scale_count(ranged_positions.len() as u32) as usize;
+    ranged_positions.resize_with(ranged_count, || random_spawn_position(&mut rng, None));
+
+    let spawns: Vec<_> = melee_positions
+        .into_iter()
+        .map(|pos| (pos, EnemyType::Melee))
+        .chain(ranged_positions.into_iter().map(|pos| (pos, EnemyType::Ranged)))
+        .collect();
+
+    *wave = WaveState {
+        enemies_remaining: spawns.len() as u32,
+        ..default()
+    };
+    spawn_wave(&mut commands, &mut meshes, &mut materials, &mut rng, &spawns, 1.0, *difficulty);
+}
+
+/// A new run from the menu: clear out the last run's enemies, shots and telegraphs, and its score and kills
+#[allow(clippy::type_complexity)]
+fn clear_last_run(
+    mut commands: Commands,
+    leftovers: Query<Entity, Or<(With<Enemy>, With<EnemyProjectile>, With<EnemyExplosion>, With<SpawnTelegraph>)>>,
+    mut kill_counter: Query<&mut KillCounter>,
+    mut score: ResMut<Score>,
+    mut projectile_count: ResMut<EnemyProjectileCount>,
+    mut kill_feed: ResMut<KillFeed>,
+) {
+    for entity in &leftovers {
+        commands.entity(entity).despawn();
     }
+    for mut counter in &mut kill_counter {
+        counter.kills = 0;
+    }
+    *score = Score::default();
+    *projectile_count = EnemyProjectileCount::default();
+    kill_feed.entries.clear();
+}
 
+/// Kill counter, wave and score HUDs
+fn spawn_wave_huds(mut commands: Commands, wave: Res<WaveState>) {
     // Spawn kill counter HUD
     commands.spawn((
         Text::new("KILLS: 0"),
@@ -254,9 +512,210 @@ fn spawn_initial_enemies(
         },
         KillCounter { kills: 0 },
     ));
+
+    // Wave HUD just below the kill counter
+    commands.spawn((
+        Text::new(wave_text(&wave, Action::SkipRest.default_binding())),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.6, 0.2)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(40.0),
+            ..default()
+        },
+        WaveHud,
+    ));
+
+    // Score HUD below the wave counter
+    commands.spawn((
+        Text::new(score_text(&Score::default())),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.9, 0.3)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(70.0),
+            ..default()
+        },
+        ScoreHud,
+    ));
+}
+
+/// Spawn a group of enemies, with health scaled by `health_multiplier` on top of `difficulty`
+fn spawn_wave(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    rng: &mut GameRng,
+    spawns: &[(Vec3, EnemyType)],
+    health_multiplier: f32,
+    difficulty: Difficulty,
+) {
+    // Body materials are templates - each enemy gets its own copy so hurt flashes stay per-enemy
+    // Melee enemy material - menacing red/dark
+    let melee_material = StandardMaterial {
+        base_color: Color::srgb(0.8, 0.2, 0.2),
+        emissive: LinearRgba::rgb(0.4, 0.05, 0.05),
+        perceptual_roughness: 0.6,
+        ..default()
+    };
+
+    // Ranged enemy material - purple/blue
+    let ranged_material = StandardMaterial {
+        base_color: Color::srgb(0.4, 0.2, 0.8),
+        emissive: LinearRgba::rgb(0.1, 0.05, 0.4),
+        perceptual_roughness: 0.6,
+        ..default()
+    };
+
+    // Boss material - dark molten orange
+    let boss_material = StandardMaterial {
+        base_color: Color::srgb(0.5, 0.15, 0.05),
+        emissive: LinearRgba::rgb(0.8, 0.2, 0.0),
+        perceptual_roughness: 0.4,
+        ..default()
+    };
+
+    // Eye materials
+    let melee_eye_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(1.0, 1.0, 0.0),
+        emissive: LinearRgba::rgb(2.0, 2.0, 0.0),
+        unlit: true,
+        ..default()
+    });
+
+    let ranged_eye_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.5, 1.0, 1.0),
+        emissive: LinearRgba::rgb(1.0, 2.0, 2.0),
+        unlit: true,
+        ..default()
+    });
+
+    for &(pos, enemy_type) in spawns {
+        let (body_material, eye_material) = match enemy_type {
+            EnemyType::Melee => (materials.add(melee_material.clone()), &melee_eye_material),
+            EnemyType::Ranged => (materials.add(ranged_material.clone()), &ranged_eye_material),
+            EnemyType::Boss => (materials.add(boss_material.clone()), &melee_eye_material),
+        };
+        spawn_enemy(
+            commands,
+            meshes,
+            &body_material,
+            eye_material,
+            pos,
+            enemy_type,
+            health_multiplier,
+            difficulty,
+            rng,
+        );
+    }
+}
+
+/// Random spawn point inside the arena, kept away from `avoid` (the player) when possible
+fn random_spawn_position(rng: &mut GameRng, avoid: Option<Vec3>) -> Vec3 {
+    let bounds = ARENA_SIZE - 10.0;
+    let min_distance = 25.0;
+
+    let mut position = Vec3::ZERO;
+    for _ in 0..10 {
+        position = Vec3::new(rng.range(-bounds, bounds), 1.0, rng.range(-bounds, bounds));
+        if avoid.is_none_or(|avoid| position.distance(avoid) >= min_distance) {
+            break;
+        }
+    }
+    position
+}
+
+/// Track living enemies and bring in the next wave once they're all dead (until the final wave)
+#[allow(clippy::too_many_arguments)]
+fn advance_waves(
+    mut commands: Commands,
+    mut wave: ResMut<WaveState>,
+    enemy_query: Query<&Health, With<Enemy>>,
+    telegraph_query: Query<(), With<SpawnTelegraph>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
+    config: Res<EnemyConfig>,
+    difficulty: Res<Difficulty>,
+    time: Res<Time>,
+) {
+    // Enemies still waiting behind a telegraph count as part of the wave
+    let living = enemy_query.iter().filter(|health| !health.is_dead()).count();
+    wave.enemies_remaining = (living + telegraph_query.iter().count()) as u32;
+    if wave.enemies_remaining > 0 {
+        return;
+    }
+
+    if wave.current_wave >= WaveState::FINAL_WAVE {
+        if !wave.all_cleared {
+            info!("All {} waves cleared", WaveState::FINAL_WAVE);
+            wave.all_cleared = true;
+        }
+        return;
+    }
+
+    let Some(remaining) = wave.intermission else {
+        info!("Wave {} cleared", wave.current_wave);
+        wave.intermission = Some(config.wave_rest_duration);
+        // Restock the arena for the rest, alternating health and ammo
+        for index in 0..config.wave_rest_pickups {
+            let pickup = if index % 2 == 0 { Pickup::health_pack() } else { Pickup::ammo_pack() };
+            let position = random_spawn_position(&mut rng, None);
+            spawn_pickup(&mut commands, &mut meshes, &mut materials, position, pickup);
+        }
+        return;
+    };
+
+    let remaining = remaining - time.delta_secs();
+    if remaining > 0.0 {
+        wave.intermission = Some(remaining);
+        return;
+    }
+
+    wave.intermission = None;
+    wave.current_wave += 1;
+
+    let (melee, ranged) = WaveState::enemy_counts(wave.current_wave);
+    let (melee, ranged) = (difficulty.scale_count(melee), difficulty.scale_count(ranged));
+    let player_pos = player_query.single().ok().map(|transform| transform.translation);
+    let boss = WaveState::has_boss(wave.current_wave);
+    let spawns: Vec<_> = (0..melee)
+        .map(|_| EnemyType::Melee)
+        .chain((0..ranged).map(|_| EnemyType::Ranged))
+        .chain(boss.then_some(EnemyType::Boss))
+        .map(|enemy_type| (random_spawn_position(&mut rng, player_pos), enemy_type))
+        .collect();
+
+    spawn_telegraphs(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &spawns,
+        WaveState::health_multiplier(wave.current_wave),
+        config.spawn_telegraph_time,
+    );
+    wave.enemies_remaining = spawns.len() as u32;
+    info!("Wave {} - {} enemies", wave.current_wave, spawns.len());
+}
+
+/// The skip key ends the rest between waves early
+fn skip_wave_rest(input: ActionInput, mut wave: ResMut<WaveState>) {
+    if wave.intermission.is_some() && input.just_pressed(Action::SkipRest) {
+        wave.intermission = Some(0.0);
+    }
 }
 
 /// Spawn a single enemy
+#[allow(clippy::too_many_arguments)]
 fn spawn_enemy(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
@@ -264,25 +723,40 @@ fn spawn_enemy(
     eye_material: &Handle<StandardMaterial>,
     position: Vec3,
     enemy_type: EnemyType,
+    health_multiplier: f32,
+    difficulty: Difficulty,
+    rng: &mut GameRng,
 ) {
-    let enemy_stats = match enemy_type {
+    let mut enemy_stats = match enemy_type {
         EnemyType::Melee => Enemy::melee(),
         EnemyType::Ranged => Enemy::ranged(),
+        EnemyType::Boss => Enemy::boss(),
     };
+    difficulty.apply_to_enemy(&mut enemy_stats);
+    let health_multiplier = health_multiplier * difficulty.health_multiplier();
+    // Start partway through a cooldown so a group's first attacks are staggered
+    enemy_stats.attack_cooldown = rng.range(0.0, enemy_stats.attack_interval);
 
     let health = match enemy_type {
-        EnemyType::Melee => Health::new(50.0),
-        EnemyType::Ranged => Health::new(35.0), // Ranged are squishier
+        EnemyType::Melee => Health::new(50.0 * health_multiplier),
+        EnemyType::Ranged => Health::new(35.0 * health_multiplier), // Ranged are squishier
+        EnemyType::Boss => Health::new(600.0 * health_multiplier),
     };
 
     let pattern = match enemy_type {
         EnemyType::Melee => AsciiPatternId::matrix_cycle(),
         EnemyType::Ranged => AsciiPatternId::binary(),
-    };
+        EnemyType::Boss => AsciiPatternId::blocks(),
+    }
+    .with_category(AsciiCategory::Enemy);
 
-    // Main body - tall capsule shape
+    // Main body - tall capsule shape, broader for the boss
+    let capsule = match enemy_type {
+        EnemyType::Boss => Capsule3d::new(0.7, 1.1),
+        _ => Capsule3d::new(0.5, 1.5),
+    };
     let body = commands.spawn((
-        Mesh3d(meshes.add(Capsule3d::new(0.5, 1.5))),
+        Mesh3d(meshes.add(capsule)),
         MeshMaterial3d(body_material.clone()),
         Transform::from_translation(position),
         enemy_type,
@@ -290,10 +764,17 @@ fn spawn_enemy(
         EnemyState::default(),
         health,
         Shootable,
+        Faction::Enemy,
+        Headbox::default(),
         HitReaction::default(),
-        WanderBehavior::new(position),
+        HurtFlash::default(),
+        // AI bookkeeping
+        (WanderBehavior::new(position), LineOfSight::default(), NavPath::default()),
         pattern,
     )).id();
+    if enemy_type == EnemyType::Boss {
+        commands.entity(body).insert(Boss::default());
+    }
 
     // Eyes - two small glowing spheres
     let eye_offset_y = 0.6;
@@ -375,10 +856,42 @@ fn update_wander_targets(
     }
 }
 
+/// Raycast from each enemy to the player through the level geometry.
+/// Only enemies close enough to care are checked; the rest are marked not visible.
+#[allow(clippy::type_complexity)]
+fn update_line_of_sight(
+    player_query: Query<&Transform, With<Player>>,
+    mut enemy_query: Query<(&Transform, &Enemy, &Health, &mut LineOfSight), Without<Player>>,
+    // Slope boxes cover empty space above the ramp, and nobody sees through the ground
+    collider_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Slope>, Without<Enemy>)>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let player_pos = player_transform.translation;
+    // Gathered once per frame, shared by every enemy
+    let colliders: Vec<_> = collider_query.iter().collect();
+
+    for (transform, enemy, health, mut los) in &mut enemy_query {
+        let enemy_pos = transform.translation;
+        // Matches the distance at which chasing enemies give up
+        let in_range = enemy_pos.distance(player_pos) < enemy.sight_range * 1.5;
+
+        los.visible = !health.is_dead()
+            && in_range
+            && line_of_sight_clear(colliders.iter().copied(), enemy_pos, player_pos);
+    }
+}
+
 /// Update enemy AI state based on player position
+#[allow(clippy::type_complexity)]
 fn enemy_ai_update(
     player_query: Query<&Transform, With<Player>>,
-    mut enemy_query: Query<(&Transform, &Enemy, &EnemyType, &mut EnemyState, &Health, &WanderBehavior), Without<Player>>,
+    mut enemy_query: Query<
+        (&Transform, &Enemy, &EnemyType, &mut EnemyState, &Health, &WanderBehavior, &LineOfSight),
+        Without<Player>,
+    >,
 ) {
     let Ok(player_transform) = player_query.single() else {
         return;
@@ -386,7 +899,7 @@ fn enemy_ai_update(
 
     let player_pos = player_transform.translation;
 
-    for (transform, enemy, enemy_type, mut state, health, wander) in &mut enemy_query {
+    for (transform, enemy, enemy_type, mut state, health, wander, los) in &mut enemy_query {
         if health.is_dead() {
             *state = EnemyState::Dead;
             continue;
@@ -395,18 +908,20 @@ fn enemy_ai_update(
         let enemy_pos = transform.translation;
         let to_player = player_pos - enemy_pos;
         let distance = to_player.length();
+        // Only aggro on players that are actually visible, not just near
+        let spotted = distance < enemy.sight_range && los.visible;
 
         match *state {
             EnemyState::Dead => {}
             EnemyState::Idle => {
-                if distance < enemy.sight_range {
+                if spotted {
                     *state = EnemyState::Chase;
                 } else if wander.target.is_some() && wander.wait_timer <= 0.0 {
                     *state = EnemyState::Wander;
                 }
             }
             EnemyState::Wander => {
-                if distance < enemy.sight_range {
+                if spotted {
                     *state = EnemyState::Chase;
                 } else if wander.target.is_none() || wander.wait_timer > 0.0 {
                     *state = EnemyState::Idle;
@@ -421,6 +936,12 @@ fn enemy_ai_update(
                             *state = EnemyState::Idle;
                         }
                     }
+                    // Bosses never lose interest
+                    EnemyType::Boss => {
+                        if distance < enemy.attack_range {
+                            *state = EnemyState::Attack;
+                        }
+                    }
                     EnemyType::Ranged => {
                         if distance < enemy.attack_range && distance > enemy.preferred_range * 0.8 {
                             *state = EnemyState::Attack;
@@ -434,7 +955,7 @@ fn enemy_ai_update(
             }
             EnemyState::Attack => {
                 match enemy_type {
-                    EnemyType::Melee => {
+                    EnemyType::Melee | EnemyType::Boss => {
                         if distance > enemy.attack_range * 1.5 {
                             *state = EnemyState::Chase;
                         }
@@ -459,10 +980,77 @@ fn enemy_ai_update(
     }
 }
 
-/// Move enemies based on their AI state
+/// Enemies closer than this push each other apart
+const SEPARATION_RADIUS: f32 = 1.5;
+/// Separation push speed at full overlap, as a fraction of the enemy's speed
+const SEPARATION_STRENGTH: f32 = 0.8;
+
+/// Living enemies bucketed into SEPARATION_RADIUS-sized XZ cells,
+/// so each enemy only checks the 3x3 cells around it instead of every other enemy
+pub struct SeparationBuckets {
+    buckets: HashMap<IVec2, Vec<(Entity, Vec3)>>,
+}
+
+impl SeparationBuckets {
+    pub fn new(enemies: impl IntoIterator<Item = (Entity, Vec3)>) -> Self {
+        let mut buckets: HashMap<IVec2, Vec<(Entity, Vec3)>> = HashMap::new();
+        for (entity, position) in enemies {
+            buckets.entry(Self::bucket(position)).or_default().push((entity, position));
+        }
+        Self { buckets }
+    }
+
+    fn bucket(position: Vec3) -> IVec2 {
+        (position.xz() / SEPARATION_RADIUS).floor().as_ivec2()
+    }
+
+    /// Horizontal push away from nearby enemies - each neighbour adds up to 1.0 the more it overlaps,
+    /// and the total is capped at 1.0
+    pub fn separation(&self, entity: Entity, position: Vec3) -> Vec3 {
+        let center = Self::bucket(position);
+        let mut push = Vec2::ZERO;
+
+        for dz in -1..=1 {
+            for dx in -1..=1 {
+                let Some(neighbours) = self.buckets.get(&(center + IVec2::new(dx, dz))) else {
+                    continue;
+                };
+                for &(other, other_position) in neighbours {
+                    if other == entity {
+                        continue;
+                    }
+                    let offset = (position - other_position).xz();
+                    let distance = offset.length();
+                    if distance >= SEPARATION_RADIUS {
+                        continue;
+                    }
+
+                    let direction = if distance > 0.0001 {
+                        offset / distance
+                    } else {
+                        // Exactly stacked: split along an angle picked from the lower entity, in opposite directions
+                        let low = entity.min(other);
+                        let away = Vec2::from_angle(low.index() as f32 * 2.4);
+                        if entity == low { away } else { -away }
+                    };
+                    push += direction * (1.0 - distance / SEPARATION_RADIUS);
+                }
+            }
+        }
+
+        let push = push.clamp_length_max(1.0);
+        Vec3::new(push.x, 0.0, push.y)
+    }
+}
+
+/// Move enemies based on their AI state, plus separation so they don't bunch into one spot
+#[allow(clippy::type_complexity)]
 fn enemy_movement(
     player_query: Query<&Transform, With<Player>>,
-    mut enemy_query: Query<(&mut Transform, &Enemy, &EnemyType, &EnemyState, &Health, &WanderBehavior), Without<Player>>,
+    mut enemy_query: Query<
+        (Entity, &mut Transform, &Enemy, &EnemyType, &EnemyState, &Health, &WanderBehavior, Option<&mut NavPath>),
+        Without<Player>,
+    >,
     time: Res<Time>,
 ) {
     let Ok(player_transform) = player_query.single() else {
@@ -472,7 +1060,14 @@ fn enemy_movement(
     let player_pos = player_transform.translation;
     let dt = time.delta_secs();
 
-    for (mut transform, enemy, _enemy_type, state, health, wander) in &mut enemy_query {
+    let buckets = SeparationBuckets::new(
+        enemy_query
+            .iter()
+            .filter(|(.., health, _, _)| !health.is_dead())
+            .map(|(entity, transform, ..)| (entity, transform.translation)),
+    );
+
+    for (entity, mut transform, enemy, _enemy_type, state, health, wander, path) in &mut enemy_query {
         if health.is_dead() {
             continue;
         }
@@ -499,7 +1094,12 @@ fn enemy_movement(
                 let horizontal = Vec3::new(to_player.x, 0.0, to_player.z);
 
                 if horizontal.length() > enemy.preferred_range * 0.8 {
-                    let direction = horizontal.normalize();
+                    // Follow the route around obstacles, if there is one
+                    let target = path
+                        .and_then(|mut path| path.next_waypoint(enemy_pos))
+                        .unwrap_or(player_pos);
+                    let to_target = target - enemy_pos;
+                    let direction = Vec3::new(to_target.x, 0.0, to_target.z).normalize_or_zero();
                     transform.translation += direction * enemy.speed * dt;
                 }
 
@@ -534,14 +1134,20 @@ fn enemy_movement(
             }
             _ => {}
         }
+
+        // Blend in separation from neighbours (computed from start-of-frame positions)
+        let push = buckets.separation(entity, enemy_pos);
+        transform.translation += push * enemy.speed * SEPARATION_STRENGTH * dt;
         // Ground height is now set by enemy_collision based on floor surfaces
     }
 }
 
 /// Handle enemy collision with walls and obstacles
+#[allow(clippy::type_complexity)]
 fn enemy_collision(
     mut enemy_query: Query<&mut Transform, With<Enemy>>,
     wall_query: Query<(&Transform, &BoxCollider), (With<WallCollider>, Without<Enemy>)>,
+    cylinder_query: Query<(&Transform, &CylinderCollider), Without<Enemy>>,
     floor_query: Query<(&Transform, &BoxCollider, Option<&Slope>), (Without<WallCollider>, Without<GroundFloor>, Without<Enemy>)>,
     slope_query: Query<(&Transform, &BoxCollider, &Slope), Without<Enemy>>,
 ) {
@@ -627,11 +1233,16 @@ fn enemy_collision(
             }
         }
 
-        // Collide with walls
+        // Collide with walls - only those overlapping the enemy's vertical band
+        let enemy_y = enemy_transform.translation.y;
         for (collider_transform, collider) in &wall_query {
             let collider_pos = collider_transform.translation;
             let half = collider.half_extents;
 
+            if (enemy_y - collider_pos.y).abs() >= half.y + enemy_height / 2.0 {
+                continue;
+            }
+
             let combined_x = half.x + enemy_radius;
             let combined_z = half.z + enemy_radius;
 
@@ -658,6 +1269,19 @@ fn enemy_collision(
             }
         }
 
+        // Round obstacles push out radially
+        for (collider_transform, collider) in &cylinder_query {
+            let collider_pos = collider_transform.translation;
+
+            if (enemy_y - collider_pos.y).abs() >= collider.half_height + enemy_height / 2.0 {
+                continue;
+            }
+
+            if let Some((pushed, _)) = collider.push_out(collider_pos, enemy_transform.translation, enemy_radius) {
+                enemy_transform.translation = pushed;
+            }
+        }
+
         // Clamp to arena bounds
         let bounds = ARENA_SIZE - 1.0;
         enemy_transform.translation.x = enemy_transform.translation.x.clamp(-bounds, bounds);
@@ -668,9 +1292,11 @@ fn enemy_collision(
 /// Melee enemy attack - damages player when in range
 fn enemy_melee_attack(
     player_query: Query<Entity, With<Player>>,
-    mut enemy_query: Query<(&Transform, &mut Enemy, &EnemyType, &EnemyState, &Health)>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Enemy, &EnemyType, &EnemyState, &Health)>,
     player_transform_query: Query<&Transform, With<Player>>,
     mut damage_events: EventWriter<DamageEvent>,
+    config: Res<EnemyConfig>,
+    mut rng: ResMut<GameRng>,
     time: Res<Time>,
 ) {
     let Ok(player_entity) = player_query.single() else {
@@ -684,7 +1310,7 @@ fn enemy_melee_attack(
     let player_pos = player_transform.translation;
     let dt = time.delta_secs();
 
-    for (transform, mut enemy, enemy_type, state, health) in &mut enemy_query {
+    for (enemy_entity, transform, mut enemy, enemy_type, state, health) in &mut enemy_query {
         if health.is_dead() || *enemy_type != EnemyType::Melee {
             continue;
         }
@@ -698,21 +1324,53 @@ fn enemy_melee_attack(
                 damage_events.write(DamageEvent {
                     target: player_entity,
                     amount: enemy.attack_damage,
-                    source: None,
+                    source: Some(enemy_entity),
                 });
-                enemy.attack_cooldown = 1.0;
+                enemy.attack_cooldown =
+                    varied_cooldown(enemy.attack_interval, config.attack_cooldown_variance, rng.f32());
             }
         }
     }
 }
 
-/// Ranged enemy attack - shoots projectiles at player
+/// Estimate the player's velocity from how far they moved since last frame
+fn track_player_motion(
+    player_query: Query<&Transform, With<Player>>,
+    mut motion: ResMut<PlayerMotion>,
+    time: Res<Time>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        motion.last_position = None;
+        motion.velocity = Vec3::ZERO;
+        return;
+    };
+
+    let position = player_transform.translation;
+    let dt = time.delta_secs();
+    if let Some(last) = motion.last_position
+        && dt > 0.0
+    {
+        motion.velocity = (position - last) / dt;
+    }
+    motion.last_position = Some(position);
+}
+
+/// Speed of ranged enemy projectiles
+const RANGED_PROJECTILE_SPEED: f32 = 20.0;
+
+/// Ranged enemy attack - shoots projectiles at player, leading their movement
+#[allow(clippy::too_many_arguments)]
 fn enemy_ranged_attack(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     player_query: Query<&Transform, With<Player>>,
-    mut enemy_query: Query<(&Transform, &mut Enemy, &EnemyType, &EnemyState, &Health)>,
+    mut enemy_query: Query<(Entity, &Transform, &mut Enemy, &EnemyType, &EnemyState, &Health)>,
+    config: Res<EnemyConfig>,
+    difficulty: Res<Difficulty>,
+    mut projectile_count: ResMut<EnemyProjectileCount>,
+    motion: Res<PlayerMotion>,
+    mut rng: ResMut<GameRng>,
     time: Res<Time>,
 ) {
     let Ok(player_transform) = player_query.single() else {
@@ -721,6 +1379,8 @@ fn enemy_ranged_attack(
 
     let player_pos = player_transform.translation;
     let dt = time.delta_secs();
+    let lead_accuracy = config.lead_accuracy.clamp(0.0, 1.0);
+    let projectile_cap = difficulty.scale_projectile_cap(config.max_projectiles);
 
     let projectile_material = materials.add(StandardMaterial {
         base_color: Color::srgb(0.8, 0.2, 1.0),
@@ -729,7 +1389,7 @@ fn enemy_ranged_attack(
         ..default()
     });
 
-    for (transform, mut enemy, enemy_type, state, health) in &mut enemy_query {
+    for (enemy_entity, transform, mut enemy, enemy_type, state, health) in &mut enemy_query {
         if health.is_dead() || *enemy_type != EnemyType::Ranged {
             continue;
         }
@@ -739,9 +1399,13 @@ fn enemy_ranged_attack(
         if matches!(state, EnemyState::Attack) && enemy.attack_cooldown <= 0.0 {
             let distance = (player_pos - transform.translation).length();
 
-            if distance < enemy.attack_range {
-                // Shoot a projectile
-                let direction = (player_pos - transform.translation).normalize();
+            // Hold fire while too many projectiles are in the air
+            if distance < enemy.attack_range && projectile_count.try_spawn(projectile_cap) {
+                // Shoot a projectile where the player is heading
+                let intercept =
+                    lead_target(transform.translation, player_pos, motion.velocity, RANGED_PROJECTILE_SPEED);
+                let aim = player_pos.lerp(intercept, lead_accuracy);
+                let direction = (aim - transform.translation).normalize();
                 let spawn_pos = transform.translation + direction * 0.8 + Vec3::Y * 0.3;
 
                 commands.spawn((
@@ -750,27 +1414,31 @@ fn enemy_ranged_attack(
                     Transform::from_translation(spawn_pos),
                     EnemyProjectile {
                         damage: enemy.attack_damage,
-                        speed: 20.0,
+                        speed: RANGED_PROJECTILE_SPEED,
                         direction,
                         lifetime: 5.0,
                         explosion_radius: 3.0,
+                        owner: Some(enemy_entity),
                     },
                 ));
 
-                enemy.attack_cooldown = 1.5; // Slower fire rate than melee attack speed
+                enemy.attack_cooldown =
+                    varied_cooldown(enemy.attack_interval, config.attack_cooldown_variance, rng.f32());
             }
         }
     }
 }
 
 /// Update enemy projectiles - move them and check for collisions
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn update_enemy_projectiles(
     mut commands: Commands,
-    mut projectile_query: Query<(Entity, &mut Transform, &EnemyProjectile), Without<Player>>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut EnemyProjectile), Without<Player>>,
     player_query: Query<&Transform, With<Player>>,
     collider_query: Query<(&Transform, &BoxCollider), (Without<Player>, Without<EnemyProjectile>)>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut projectile_count: ResMut<EnemyProjectileCount>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
@@ -781,7 +1449,7 @@ fn update_enemy_projectiles(
 
     let player_pos = player_transform.translation;
 
-    for (entity, mut transform, projectile) in &mut projectile_query {
+    for (entity, mut transform, mut projectile) in &mut projectile_query {
         // Move projectile
         transform.translation += projectile.direction * projectile.speed * dt;
 
@@ -790,24 +1458,21 @@ fn update_enemy_projectiles(
 
         // Check collision with player
         let dist_to_player = (proj_pos - player_pos).length();
-        if dist_to_player < 1.5 {
+        if dist_to_player < PROJECTILE_PLAYER_HIT_RADIUS {
             should_explode = true;
         }
 
         // Check collision with walls/floors (all BoxColliders)
-        for (collider_transform, collider) in &collider_query {
-            let collider_pos = collider_transform.translation;
-            let half = collider.half_extents;
-
-            let diff = proj_pos - collider_pos;
-            if diff.x.abs() < half.x + 0.2 && diff.y.abs() < half.y + 0.2 && diff.z.abs() < half.z + 0.2 {
-                should_explode = true;
-                break;
-            }
+        if collider_query
+            .iter()
+            .any(|(collider_transform, collider)| projectile_touches(proj_pos, collider_transform.translation, collider))
+        {
+            should_explode = true;
         }
 
-        // Check lifetime
-        if projectile.lifetime - dt <= 0.0 {
+        // Check lifetime - stray shots must expire so they free their slot under the cap
+        projectile.lifetime -= dt;
+        if projectile.lifetime <= 0.0 {
             should_explode = true;
         }
 
@@ -820,8 +1485,10 @@ fn update_enemy_projectiles(
                 proj_pos,
                 projectile.damage,
                 projectile.explosion_radius,
+                projectile.owner,
             );
             commands.entity(entity).despawn();
+            projectile_count.despawned();
         }
     }
 }
@@ -834,6 +1501,7 @@ fn spawn_enemy_explosion(
     position: Vec3,
     damage: f32,
     radius: f32,
+    owner: Option<Entity>,
 ) {
     let explosion_material = materials.add(StandardMaterial {
         base_color: Color::srgba(0.8, 0.2, 1.0, 0.7),
@@ -854,6 +1522,7 @@ fn spawn_enemy_explosion(
             damage,
             lifetime: 0.25,
             has_damaged: false,
+            owner,
         },
     ));
 
@@ -867,46 +1536,55 @@ fn spawn_enemy_explosion(
             ..default()
         },
         Transform::from_translation(position),
-        crate::combat::MuzzleFlash { lifetime: 0.15, max_lifetime: 0.15 },
+        crate::combat::MuzzleFlash { lifetime: 0.15 },
     ));
+
+    commands.spawn((SoundEmitter(SoundEffect::EnemyExplosion), Transform::from_translation(position)));
 }
 
 /// Update enemy explosions - expand and deal damage to player
 fn update_enemy_explosions(
     mut commands: Commands,
     mut explosion_query: Query<(Entity, &mut Transform, &mut EnemyExplosion)>,
-    player_query: Query<(Entity, &Transform), (With<Player>, Without<EnemyExplosion>)>,
+    target_query: Query<(Entity, &GlobalTransform, &Health, Option<&Faction>)>,
+    grid: Res<SpatialGrid>,
+    combat_config: Res<CombatConfig>,
     mut damage_events: EventWriter<DamageEvent>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
-    let Ok((player_entity, player_transform)) = player_query.single() else {
-        return;
-    };
-
-    let player_pos = player_transform.translation;
-
     for (entity, mut transform, mut explosion) in &mut explosion_query {
         // Expand explosion
         let expand_rate = explosion.max_radius / 0.12;
         explosion.radius = (explosion.radius + expand_rate * dt).min(explosion.max_radius);
         transform.scale = Vec3::splat(explosion.radius * 2.0);
 
-        // Deal damage to player once when near max size
+        // Deal damage once when near max size - the player, plus other enemies with friendly fire
         if !explosion.has_damaged && explosion.radius > explosion.max_radius * 0.5 {
             explosion.has_damaged = true;
 
-            let explosion_pos = transform.translation;
-            let dist = (player_pos - explosion_pos).length();
-            if dist < explosion.max_radius {
-                // Damage falls off with distance
-                let damage_mult = 1.0 - (dist / explosion.max_radius);
-                damage_events.write(DamageEvent {
-                    target: player_entity,
-                    amount: explosion.damage * damage_mult,
-                    source: None,
-                });
+            let blast = Blast {
+                center: transform.translation,
+                radius: explosion.max_radius,
+                damage: explosion.damage,
+                faction: Faction::Enemy,
+            };
+            for target in grid.query_radius(blast.center, blast.radius) {
+                let Ok((target, target_transform, health, faction)) = target_query.get(target) else {
+                    continue;
+                };
+                if health.is_dead() {
+                    continue;
+                }
+                let position = target_transform.translation();
+                if let Some(amount) = blast.damage_at(position, faction.copied(), combat_config.friendly_fire) {
+                    damage_events.write(DamageEvent {
+                        target,
+                        amount,
+                        source: explosion.owner,
+                    });
+                }
             }
         }
 
@@ -919,16 +1597,28 @@ fn update_enemy_explosions(
 }
 
 /// Handle enemy death - despawn after delay
+#[allow(clippy::too_many_arguments)]
 fn handle_enemy_death(
     mut commands: Commands,
     mut death_events: EventReader<DeathEvent>,
-    mut enemy_query: Query<(&mut Transform, &mut EnemyState), With<Enemy>>,
+    mut enemy_query: Query<(&mut Transform, &mut EnemyState, &EnemyType), With<Enemy>>,
     mut kill_counter: Query<&mut KillCounter>,
+    mut score: ResMut<Score>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
 ) {
     for event in death_events.read() {
-        if let Ok((mut transform, mut state)) = enemy_query.get_mut(event.entity) {
+        // Enemies blowing each other up don't earn the player points or drops
+        let player_kill = !event.killer.is_some_and(|killer| enemy_query.contains(killer));
+
+        if let Ok((mut transform, mut state, enemy_type)) = enemy_query.get_mut(event.entity) {
             *state = EnemyState::Dead;
 
+            if player_kill && let Some(pickup) = Pickup::roll_drop(&mut rng) {
+                spawn_pickup(&mut commands, &mut meshes, &mut materials, transform.translation, pickup);
+            }
+
             transform.rotation = Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2);
             transform.translation.y = 0.3;
 
@@ -937,6 +1627,9 @@ fn handle_enemy_death(
             if let Ok(mut counter) = kill_counter.single_mut() {
                 counter.kills += 1;
             }
+            if player_kill {
+                score.register_kill(*enemy_type);
+            }
         }
     }
 }
@@ -947,6 +1640,20 @@ pub struct DespawnTimer {
     pub remaining: f32,
 }
 
+/// Remove corpses once their despawn timer runs out
+fn update_despawn_timers(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DespawnTimer)>,
+    time: Res<Time>,
+) {
+    for (entity, mut timer) in &mut query {
+        timer.remaining -= time.delta_secs();
+        if timer.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 /// Kill counter HUD
 #[derive(Component)]
 pub struct KillCounter {
@@ -962,14 +1669,149 @@ fn update_kill_counter(
     }
 }
 
+/// Points for kills, multiplied by a combo that builds with quick consecutive kills
+#[derive(Resource)]
+pub struct Score {
+    pub total: u32,
+    /// Current multiplier - 1 with no combo going
+    pub combo: u32,
+    /// Seconds left to land the next kill before the combo resets
+    pub combo_timer: f32,
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self {
+            total: 0,
+            combo: 1,
+            combo_timer: 0.0,
+        }
+    }
+}
+
+impl Score {
+    /// Time allowed between kills to keep a combo going
+    pub const COMBO_WINDOW: f32 = 2.0;
+    pub const MAX_COMBO: u32 = 8;
+
+    /// Base points for killing each enemy type
+    pub fn points_for(enemy_type: EnemyType) -> u32 {
+        match enemy_type {
+            EnemyType::Melee => 100,
+            EnemyType::Ranged => 150, // Harder to reach
+            EnemyType::Boss => 1000,
+        }
+    }
+
+    /// Award a kill at the current multiplier, then grow the combo - returns the points awarded
+    pub fn register_kill(&mut self, enemy_type: EnemyType) -> u32 {
+        let points = Self::points_for(enemy_type) * self.combo;
+        self.total += points;
+        self.combo = (self.combo + 1).min(Self::MAX_COMBO);
+        self.combo_timer = Self::COMBO_WINDOW;
+        points
+    }
+
+    /// Run down the combo window, dropping back to x1 when it expires
+    pub fn tick(&mut self, dt: f32) {
+        if self.combo_timer <= 0.0 {
+            return;
+        }
+        self.combo_timer -= dt;
+        if self.combo_timer <= 0.0 {
+            self.combo_timer = 0.0;
+            self.combo = 1;
+        }
+    }
+}
+
+fn tick_score_combo(mut score: ResMut<Score>, time: Res<Time>) {
+    score.tick(time.delta_secs());
+}
+
+/// Score HUD
+#[derive(Component)]
+pub struct ScoreHud;
+
+/// Score HUD text - shows the multiplier while a combo is going
+pub fn score_text(score: &Score) -> String {
+    if score.combo > 1 {
+        format!("SCORE: {}  x{}", score.total, score.combo)
+    } else {
+        format!("SCORE: {}", score.total)
+    }
+}
+
+fn update_score_hud(score: Res<Score>, mut query: Query<&mut Text, With<ScoreHud>>) {
+    if !score.is_changed() {
+        return;
+    }
+    for mut text in &mut query {
+        **text = score_text(&score);
+    }
+}
+
+/// Wave number HUD
+#[derive(Component)]
+pub struct WaveHud;
+
+/// Wave HUD text - counts down to the next wave during the rest, with the key that skips it
+pub fn wave_text(wave: &WaveState, skip: Binding) -> String {
+    if wave.all_cleared {
+        return "ALL WAVES CLEARED".to_string();
+    }
+    match wave.intermission {
+        Some(remaining) => format!(
+            "WAVE {} IN {:.0}  [{}] SKIP",
+            wave.current_wave + 1,
+            remaining.ceil(),
+            skip.name()
+        ),
+        None => format!("WAVE: {}  LEFT: {}", wave.current_wave, wave.enemies_remaining),
+    }
+}
+
+fn update_wave_hud(wave: Res<WaveState>, input_map: Res<InputMap>, mut query: Query<&mut Text, With<WaveHud>>) {
+    for mut text in &mut query {
+        **text = wave_text(&wave, input_map.binding(Action::SkipRest));
+    }
+}
+
 /// Trigger hit reactions when enemies take damage
 fn trigger_hit_reactions(
     mut damage_events: EventReader<DamageEvent>,
-    mut enemy_query: Query<&mut HitReaction, With<Enemy>>,
+    mut enemy_query: Query<(&mut HitReaction, &mut HurtFlash, &MeshMaterial3d<StandardMaterial>), With<Enemy>>,
+    materials: Res<Assets<StandardMaterial>>,
 ) {
     for event in damage_events.read() {
-        if let Ok(mut hit_reaction) = enemy_query.get_mut(event.target) {
+        if let Ok((mut hit_reaction, mut hurt_flash, material_handle)) = enemy_query.get_mut(event.target) {
             hit_reaction.trigger(event.amount);
+
+            if hurt_flash.base_emissive.is_none() {
+                hurt_flash.base_emissive = materials.get(&material_handle.0).map(|m| m.emissive);
+            }
+            hurt_flash.trigger();
+        }
+    }
+}
+
+/// Update hurt flashes - write the decaying emissive tint to each enemy's own material
+fn update_hurt_flashes(
+    mut enemy_query: Query<(&mut HurtFlash, &MeshMaterial3d<StandardMaterial>), With<Enemy>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut hurt_flash, material_handle) in &mut enemy_query {
+        if hurt_flash.timer <= 0.0 {
+            continue;
+        }
+
+        // Ticks to exactly zero on the last frame, which restores the base emissive
+        hurt_flash.tick(dt);
+        if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.emissive = hurt_flash.emissive();
         }
     }
 }
@@ -1011,3 +1853,710 @@ fn update_hit_reactions(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    const EPSILON: f32 = 0.0001;
+
+    fn color_approx_eq(a: LinearRgba, b: LinearRgba) -> bool {
+        (a.red - b.red).abs() < EPSILON
+            && (a.green - b.green).abs() < EPSILON
+            && (a.blue - b.blue).abs() < EPSILON
+    }
+
+    // ==================== Line of Sight Tests ====================
+
+    /// Player at the origin and a wandering melee enemy 20 units away
+    fn sight_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.add_systems(Update, (update_line_of_sight, enemy_ai_update).chain());
+
+        app.world_mut().spawn((Player, Transform::from_xyz(0.0, 1.0, 0.0)));
+
+        let position = Vec3::new(0.0, 1.0, -20.0);
+        let mut wander = WanderBehavior::new(position);
+        wander.target = Some(position + Vec3::X);
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(position),
+                Enemy::melee(),
+                EnemyType::Melee,
+                EnemyState::Wander,
+                Health::new(50.0),
+                wander,
+                LineOfSight::default(),
+            ))
+            .id();
+
+        (app, enemy)
+    }
+
+    #[test]
+    fn test_enemy_stays_wandering_behind_wall() {
+        let (mut app, enemy) = sight_test_app();
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, -10.0),
+            BoxCollider { half_extents: Vec3::new(3.0, 2.0, 0.5) },
+        ));
+        app.update();
+
+        assert!(!app.world().get::<LineOfSight>(enemy).unwrap().visible);
+        assert!(matches!(app.world().get::<EnemyState>(enemy), Some(EnemyState::Wander)));
+    }
+
+    #[test]
+    fn test_enemy_chases_visible_player() {
+        let (mut app, enemy) = sight_test_app();
+        app.update();
+
+        assert!(app.world().get::<LineOfSight>(enemy).unwrap().visible);
+        assert!(matches!(app.world().get::<EnemyState>(enemy), Some(EnemyState::Chase)));
+    }
+
+    // ==================== Enemy Explosion Tests ====================
+
+    /// Enemy blast at the origin with the player and another enemy both 1 unit away
+    fn enemy_blast_app(friendly_fire: bool) -> (App, Entity, Entity) {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(50))
+            .insert_resource(CombatConfig {
+                friendly_fire,
+                ..default()
+            })
+            .add_event::<DamageEvent>()
+            .init_resource::<SpatialGrid>()
+            .add_systems(PreUpdate, crate::combat::rebuild_spatial_grid)
+            .add_systems(Update, update_enemy_explosions);
+
+        let mut spawn = |faction: Faction, position: Vec3| {
+            app.world_mut()
+                .spawn((faction, Health::new(100.0), GlobalTransform::from_translation(position)))
+                .id()
+        };
+        let player = spawn(Faction::Player, Vec3::X);
+        let bystander = spawn(Faction::Enemy, Vec3::NEG_X);
+
+        app.world_mut().spawn((
+            Transform::default(),
+            EnemyExplosion {
+                radius: 0.3,
+                max_radius: 4.0,
+                damage: 20.0,
+                lifetime: 0.4,
+                has_damaged: false,
+                owner: None,
+            },
+        ));
+        for _ in 0..4 {
+            app.update();
+        }
+
+        (app, player, bystander)
+    }
+
+    fn damaged(app: &App) -> Vec<Entity> {
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        cursor.read(events).map(|event| event.target).collect()
+    }
+
+    #[test]
+    fn test_enemy_blast_spares_enemies_without_friendly_fire() {
+        let (app, player, _) = enemy_blast_app(false);
+        assert_eq!(damaged(&app), vec![player]);
+    }
+
+    #[test]
+    fn test_enemy_blast_hits_enemies_with_friendly_fire() {
+        let (app, player, bystander) = enemy_blast_app(true);
+        let hits = damaged(&app);
+        assert!(hits.contains(&player));
+        assert!(hits.contains(&bystander));
+    }
+
+    // ==================== Wave Tests ====================
+
+    #[test]
+    fn test_waves_escalate() {
+        let total = |wave| {
+            let (melee, ranged) = WaveState::enemy_counts(wave);
+            melee + ranged
+        };
+        assert_eq!(WaveState::enemy_counts(1), (6, 4));
+        assert!(total(2) > total(1));
+        assert!(total(5) > total(4));
+
+        assert_eq!(WaveState::health_multiplier(1), 1.0);
+        assert!(WaveState::health_multiplier(3) > WaveState::health_multiplier(2));
+    }
+
+    #[test]
+    fn test_boss_every_fifth_wave() {
+        assert!(!WaveState::has_boss(1));
+        assert!(!WaveState::has_boss(4));
+        assert!(WaveState::has_boss(5));
+        assert!(WaveState::has_boss(10));
+    }
+
+    #[test]
+    fn test_wave_text() {
+        let mut wave = WaveState {
+            current_wave: 2,
+            enemies_remaining: 7,
+            intermission: None,
+            all_cleared: false,
+        };
+        let skip = Binding::Key(KeyCode::KeyN);
+        assert_eq!(wave_text(&wave, skip), "WAVE: 2  LEFT: 7");
+
+        wave.intermission = Some(2.4);
+        assert_eq!(wave_text(&wave, skip), "WAVE 3 IN 3  [N] SKIP");
+
+        wave.all_cleared = true;
+        assert_eq!(wave_text(&wave, skip), "ALL WAVES CLEARED");
+    }
+
+    fn living_enemies(app: &mut App) -> Vec<f32> {
+        app.world_mut()
+            .query_filtered::<&Health, With<Enemy>>()
+            .iter(app.world())
+            .filter(|health| !health.is_dead())
+            .map(|health| health.max)
+            .collect()
+    }
+
+    #[test]
+    fn test_clearing_wave_spawns_bigger_wave() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<WaveState>()
+            .init_resource::<EnemyConfig>()
+            .init_resource::<Difficulty>()
+            .add_systems(Startup, spawn_first_wave)
+            .add_systems(Update, (update_spawn_telegraphs, advance_waves).chain());
+
+        app.update();
+        let first_wave = living_enemies(&mut app);
+        assert_eq!(app.world().resource::<WaveState>().enemies_remaining, first_wave.len() as u32);
+
+        // Wipe out the first wave
+        for mut health in app.world_mut().query_filtered::<&mut Health, With<Enemy>>().iter_mut(app.world_mut()) {
+            health.current = 0.0;
+        }
+
+        // Cleared -> intermission counts down -> spawn telegraphs -> next wave arrives
+        for _ in 0..20 {
+            app.update();
+        }
+
+        let wave = app.world().resource::<WaveState>();
+        assert_eq!(wave.current_wave, 2);
+        assert!(wave.intermission.is_none());
+
+        let second_wave = living_enemies(&mut app);
+        assert!(second_wave.len() > first_wave.len());
+        // Tougher too
+        let max_first = first_wave.iter().copied().fold(0.0, f32::max);
+        let max_second = second_wave.iter().copied().fold(0.0, f32::max);
+        assert!(max_second > max_first);
+    }
+
+    #[test]
+    fn test_wave_rest_refreshes_pickups_and_can_be_skipped() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<WaveState>()
+            .insert_resource(EnemyConfig {
+                wave_rest_duration: 30.0,
+                wave_rest_pickups: 3,
+                // Enemies appear on the first frame
+                spawn_telegraph_time: 0.0,
+                ..default()
+            })
+            .init_resource::<Difficulty>()
+            .with_input()
+            .add_systems(Startup, spawn_first_wave)
+            .add_systems(Update, (update_spawn_telegraphs, skip_wave_rest, advance_waves).chain());
+
+        app.update();
+        for mut health in app.world_mut().query_filtered::<&mut Health, With<Enemy>>().iter_mut(app.world_mut()) {
+            health.current = 0.0;
+        }
+        for _ in 0..5 {
+            app.update();
+        }
+
+        // Still resting a second in, with the arena restocked
+        let wave = app.world().resource::<WaveState>();
+        assert_eq!(wave.current_wave, 1);
+        assert!(wave.intermission.is_some_and(|remaining| remaining > 28.0));
+        assert_eq!(app.world_mut().query::<&Pickup>().iter(app.world()).count(), 3);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyN);
+        app.update();
+
+        let wave = app.world().resource::<WaveState>();
+        assert_eq!(wave.current_wave, 2);
+        assert!(wave.intermission.is_none());
+        assert!(wave.enemies_remaining > 0);
+    }
+
+    #[test]
+    fn test_new_run_starts_from_a_clean_slate() {
+        use crate::menu::{record_high_score, HighScores};
+
+        let mut app = test_app();
+        app.with_game_state(GameState::Menu)
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<WaveState>()
+            .init_resource::<Difficulty>()
+            .init_resource::<Score>()
+            .init_resource::<EnemyProjectileCount>()
+            .init_resource::<KillFeed>()
+            .init_resource::<HighScores>()
+            .add_systems(Startup, (spawn_first_wave, spawn_wave_huds).chain())
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                (clear_last_run, spawn_first_wave).chain(),
+            )
+            .add_systems(OnEnter(GameState::GameOver), record_high_score);
+        let set_state = |app: &mut App, state: GameState| {
+            app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+            app.update();
+        };
+        app.update();
+        let first_wave = living_enemies(&mut app).len();
+
+        // First run: clears a wave on a combo, then dies
+        set_state(&mut app, GameState::Playing);
+        *app.world_mut().resource_mut::<Score>() = Score { total: 800, combo: 3, combo_timer: 1.0 };
+        app.world_mut().resource_mut::<WaveState>().current_wave = 3;
+        for mut counter in app.world_mut().query::<&mut KillCounter>().iter_mut(app.world_mut()) {
+            counter.kills = 12;
+        }
+        set_state(&mut app, GameState::GameOver);
+        set_state(&mut app, GameState::Menu);
+
+        // Second run starts over - no leftover enemies, score, combo, wave or kills
+        set_state(&mut app, GameState::Playing);
+        assert_eq!(living_enemies(&mut app).len(), first_wave);
+        let score = app.world().resource::<Score>();
+        assert_eq!((score.total, score.combo), (0, 1));
+        let wave = app.world().resource::<WaveState>();
+        assert_eq!((wave.current_wave, wave.enemies_remaining), (1, first_wave as u32));
+        let kills: Vec<_> = app.world_mut().query::<&KillCounter>().iter(app.world()).map(|c| c.kills).collect();
+        assert_eq!(kills, vec![0]);
+
+        // ...and its score goes on the table on its own
+        app.world_mut().resource_mut::<Score>().total = 300;
+        set_state(&mut app, GameState::GameOver);
+        let scores: Vec<_> = app.world().resource::<HighScores>().entries.iter().map(|e| (e.score, e.wave)).collect();
+        assert_eq!(scores, vec![(800, 3), (300, 1)]);
+    }
+
+    /// Max health of a melee enemy spawned on `difficulty`
+    fn melee_health(difficulty: Difficulty) -> f32 {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .insert_resource(difficulty)
+            .add_systems(
+                Update,
+                |mut commands: Commands,
+                 mut meshes: ResMut<Assets<Mesh>>,
+                 mut materials: ResMut<Assets<StandardMaterial>>,
+                 mut rng: ResMut<GameRng>,
+                 difficulty: Res<Difficulty>| {
+                    let spawns = [(Vec3::ZERO, EnemyType::Melee)];
+                    spawn_wave(&mut commands, &mut meshes, &mut materials, &mut rng, &spawns, 1.0, *difficulty);
+                },
+            );
+        app.update();
+        living_enemies(&mut app)[0]
+    }
+
+    #[test]
+    fn test_hard_enemies_have_more_health() {
+        assert!(melee_health(Difficulty::Hard) > melee_health(Difficulty::Normal));
+        assert!(melee_health(Difficulty::Easy) < melee_health(Difficulty::Normal));
+    }
+
+    // ==================== Score Tests ====================
+
+    #[test]
+    fn test_ranged_kills_worth_more() {
+        assert!(Score::points_for(EnemyType::Ranged) > Score::points_for(EnemyType::Melee));
+    }
+
+    #[test]
+    fn test_combo_resets_after_window() {
+        let mut score = Score::default();
+        score.register_kill(EnemyType::Melee);
+        assert_eq!(score.combo, 2);
+
+        score.tick(Score::COMBO_WINDOW + 0.1);
+        assert_eq!(score.combo, 1);
+        assert_eq!(score_text(&score), "SCORE: 100");
+    }
+
+    /// Kill two melee enemies `frames` quarter-second frames apart and return the final score
+    fn score_two_kills(frames: usize) -> u32 {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .add_event::<DeathEvent>()
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<Score>()
+            .add_systems(Update, (tick_score_combo, handle_enemy_death).chain());
+
+        let spawn_enemy = |app: &mut App| {
+            app.world_mut()
+                .spawn((Transform::default(), Enemy::melee(), EnemyType::Melee, EnemyState::Chase))
+                .id()
+        };
+        let first = spawn_enemy(&mut app);
+        let second = spawn_enemy(&mut app);
+
+        app.world_mut().send_event(DeathEvent { entity: first, killer: None });
+        app.update();
+        for _ in 0..frames {
+            app.update();
+        }
+        app.world_mut().send_event(DeathEvent { entity: second, killer: None });
+        app.update();
+
+        app.world().resource::<Score>().total
+    }
+
+    #[test]
+    fn test_quick_kills_score_combo_bonus() {
+        let quick = score_two_kills(2);
+        let spaced = score_two_kills(12);
+
+        assert_eq!(spaced, 2 * Score::points_for(EnemyType::Melee));
+        assert!(quick > spaced);
+    }
+
+    #[test]
+    fn test_enemy_kills_score_nothing() {
+        let mut app = test_app();
+        app.add_event::<DeathEvent>()
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<Score>()
+            .add_systems(Update, handle_enemy_death);
+
+        let spawn_enemy = |app: &mut App, enemy: Enemy, enemy_type: EnemyType| {
+            app.world_mut()
+                .spawn((Transform::default(), enemy, enemy_type, EnemyState::Chase))
+                .id()
+        };
+        let killer = spawn_enemy(&mut app, Enemy::ranged(), EnemyType::Ranged);
+        let victim = spawn_enemy(&mut app, Enemy::melee(), EnemyType::Melee);
+        app.world_mut().send_event(DeathEvent { entity: victim, killer: Some(killer) });
+        app.update();
+
+        assert!(matches!(app.world().get::<EnemyState>(victim), Some(EnemyState::Dead)));
+        assert_eq!(app.world().resource::<Score>().total, 0);
+    }
+
+    // ==================== Separation Tests ====================
+
+    #[test]
+    fn test_stacked_enemies_drift_apart() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(50))
+            .add_systems(Update, enemy_movement);
+        app.world_mut().spawn((Player, Transform::from_xyz(50.0, 1.0, 50.0)));
+
+        let position = Vec3::new(0.0, 1.0, 0.0);
+        let spawn_enemy = |app: &mut App| {
+            app.world_mut()
+                .spawn((
+                    Transform::from_translation(position),
+                    Enemy::melee(),
+                    EnemyType::Melee,
+                    EnemyState::Idle,
+                    Health::new(50.0),
+                    WanderBehavior::new(position),
+                ))
+                .id()
+        };
+        let a = spawn_enemy(&mut app);
+        let b = spawn_enemy(&mut app);
+
+        let gap = |app: &App| {
+            let position = |entity| app.world().get::<Transform>(entity).unwrap().translation;
+            position(a).distance(position(b))
+        };
+
+        app.update();
+        let mut previous = gap(&app);
+        for _ in 0..5 {
+            app.update();
+            let current = gap(&app);
+            assert!(current > previous, "enemies should keep drifting apart");
+            previous = current;
+        }
+
+        // The push fades out as they separate: bodies (radius 0.6) no longer overlap,
+        // and nobody gets shoved past the separation radius
+        for _ in 0..40 {
+            app.update();
+        }
+        let settled = gap(&app);
+        assert!(settled > 1.2 && settled <= SEPARATION_RADIUS);
+    }
+
+    #[test]
+    fn test_separation_ignores_distant_enemies() {
+        let mut world = World::new();
+        let (a, b) = (world.spawn_empty().id(), world.spawn_empty().id());
+        let buckets = SeparationBuckets::new([(a, Vec3::ZERO), (b, Vec3::new(SEPARATION_RADIUS * 2.0, 0.0, 0.0))]);
+        assert_eq!(buckets.separation(a, Vec3::ZERO), Vec3::ZERO);
+
+        let buckets = SeparationBuckets::new([(a, Vec3::ZERO), (b, Vec3::new(0.5, 0.0, 0.0))]);
+        assert!(buckets.separation(a, Vec3::ZERO).x < 0.0);
+    }
+
+    // ==================== Enemy Collision Tests ====================
+
+    fn collision_test_app(enemy_position: Vec3) -> (App, Entity) {
+        let mut app = test_app();
+        app.add_systems(Update, enemy_collision);
+        let enemy = app
+            .world_mut()
+            .spawn((Enemy::melee(), Transform::from_translation(enemy_position)))
+            .id();
+        (app, enemy)
+    }
+
+    #[test]
+    fn test_collider_above_enemy_does_not_push() {
+        let (mut app, enemy) = collision_test_app(Vec3::new(0.5, 1.0, 0.3));
+        // Overhead beam overlapping the enemy in XZ, well clear of its head
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 10.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(2.0, 1.0, 2.0) },
+            WallCollider,
+        ));
+        app.update();
+
+        let position = app.world().get::<Transform>(enemy).unwrap().translation;
+        assert_eq!(position.x, 0.5);
+        assert_eq!(position.z, 0.3);
+    }
+
+    #[test]
+    fn test_wall_at_enemy_height_pushes_out() {
+        let (mut app, enemy) = collision_test_app(Vec3::new(0.5, 1.0, 0.3));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(2.0, 2.0, 2.0) },
+            WallCollider,
+        ));
+        app.update();
+
+        let position = app.world().get::<Transform>(enemy).unwrap().translation;
+        assert!(position.x.abs() >= 2.6 - 0.0001 || position.z.abs() >= 2.6 - 0.0001);
+    }
+
+    #[test]
+    fn test_cylinder_pushes_enemy_out_radially() {
+        let (mut app, enemy) = collision_test_app(Vec3::new(0.6, 1.0, 0.8));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, 0.0),
+            CylinderCollider { radius: 1.0, half_height: 2.0 },
+        ));
+        app.update();
+
+        let position = app.world().get::<Transform>(enemy).unwrap().translation;
+        assert!((position.xz().length() - 1.6).abs() < 0.0001);
+        // Same direction as before, just further out
+        assert!(position.xz().normalize().dot(Vec2::new(0.6, 0.8)) > 0.9999);
+    }
+
+    // ==================== Attack Cooldown Tests ====================
+
+    #[test]
+    fn test_cooldown_variance_within_bounds() {
+        let mut rng = GameRng::new(1234);
+        let variance = EnemyConfig::default().attack_cooldown_variance;
+        let base = Enemy::melee().attack_interval;
+
+        let mut min_seen = f32::MAX;
+        let mut max_seen = f32::MIN;
+        for _ in 0..1000 {
+            let cooldown = varied_cooldown(base, variance, rng.f32());
+            assert!(cooldown >= base * (1.0 - variance) - EPSILON);
+            assert!(cooldown <= base * (1.0 + variance) + EPSILON);
+            min_seen = min_seen.min(cooldown);
+            max_seen = max_seen.max(cooldown);
+        }
+
+        // Actually spreads attacks out rather than collapsing to one value
+        assert!(max_seen - min_seen > base * variance);
+    }
+
+    #[test]
+    fn test_zero_variance_is_metronomic() {
+        assert!((varied_cooldown(1.5, 0.0, 0.0) - 1.5).abs() < EPSILON);
+        assert!((varied_cooldown(1.5, 0.0, 0.99) - 1.5).abs() < EPSILON);
+    }
+
+    // ==================== Projectile Cap Tests ====================
+
+    #[test]
+    fn test_projectile_cap_holds_fire() {
+        let mut count = EnemyProjectileCount::default();
+
+        for _ in 0..3 {
+            assert!(count.try_spawn(3));
+        }
+        assert!(!count.try_spawn(3));
+        assert_eq!(count.live, 3);
+
+        // A projectile exploding frees a slot
+        count.despawned();
+        assert!(count.try_spawn(3));
+        assert!(!count.try_spawn(3));
+    }
+
+    // ==================== Target Leading Tests ====================
+
+    #[test]
+    fn test_lead_target_stationary_is_target() {
+        let target = Vec3::new(3.0, 1.0, -20.0);
+        assert_eq!(lead_target(Vec3::ZERO, target, Vec3::ZERO, 20.0), target);
+    }
+
+    #[test]
+    fn test_lead_target_meets_projectile() {
+        let shooter = Vec3::ZERO;
+        let target = Vec3::new(0.0, 0.0, -20.0);
+        let velocity = Vec3::new(8.0, 0.0, 0.0);
+        let intercept = lead_target(shooter, target, velocity, 20.0);
+
+        // Projectile and target arrive at the intercept at the same time
+        let projectile_time = intercept.length() / 20.0;
+        let target_time = (intercept - target).length() / velocity.length();
+        assert!((projectile_time - target_time).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_lead_target_uncatchable_falls_back_to_target() {
+        // Running directly away faster than the projectile
+        let target = Vec3::new(0.0, 0.0, -20.0);
+        assert_eq!(lead_target(Vec3::ZERO, target, Vec3::new(0.0, 0.0, -30.0), 20.0), target);
+    }
+
+    #[test]
+    fn test_ranged_enemy_leads_strafing_player() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .with_mesh_assets()
+            .init_resource::<EnemyConfig>()
+            .init_resource::<Difficulty>()
+            .init_resource::<EnemyProjectileCount>()
+            .init_resource::<PlayerMotion>()
+            .insert_resource(GameRng::new(1))
+            .add_systems(Update, (track_player_motion, enemy_ranged_attack).chain());
+
+        let player = app.world_mut().spawn((Player, Transform::from_xyz(0.0, 1.0, -20.0))).id();
+        let mut enemy = Enemy::ranged();
+        // Hold fire until the player's velocity has been measured
+        enemy.attack_cooldown = 0.15;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            enemy,
+            EnemyType::Ranged,
+            EnemyState::Attack,
+            Health::new(35.0),
+        ));
+
+        // Strafe +X at 10 m/s
+        for _ in 0..3 {
+            app.update();
+            app.world_mut().get_mut::<Transform>(player).unwrap().translation.x += 1.0;
+        }
+
+        let world = app.world_mut();
+        let directions: Vec<Vec3> =
+            world.query::<&EnemyProjectile>().iter(world).map(|projectile| projectile.direction).collect();
+        assert_eq!(directions.len(), 1);
+
+        // The shot was fired at the player's position two strafes in (x = 2), but aimed ahead of it
+        let straight_at = Vec3::new(2.0, 0.0, -20.0).normalize();
+        assert!(directions[0].x > straight_at.x + 0.05);
+    }
+
+    #[test]
+    fn test_projectile_count_never_underflows() {
+        let mut count = EnemyProjectileCount::default();
+        count.despawned();
+        assert_eq!(count.live, 0);
+    }
+
+    // ==================== HurtFlash Tests ====================
+
+    fn flash_with_base() -> HurtFlash {
+        HurtFlash {
+            timer: 0.0,
+            base_emissive: Some(LinearRgba::rgb(0.4, 0.05, 0.05)),
+        }
+    }
+
+    #[test]
+    fn test_hurt_flash_starts_white() {
+        let mut flash = flash_with_base();
+        flash.trigger();
+
+        assert!(color_approx_eq(flash.emissive(), HurtFlash::FLASH_EMISSIVE));
+    }
+
+    #[test]
+    fn test_hurt_flash_decays_halfway() {
+        let mut flash = flash_with_base();
+        flash.trigger();
+        flash.tick(HurtFlash::DURATION / 2.0);
+
+        let expected = LinearRgba::rgb(0.4, 0.05, 0.05).mix(&HurtFlash::FLASH_EMISSIVE, 0.5);
+        assert!(color_approx_eq(flash.emissive(), expected));
+    }
+
+    #[test]
+    fn test_hurt_flash_returns_to_base_after_duration() {
+        let mut flash = flash_with_base();
+        flash.trigger();
+
+        // ~60fps frames until the flash has run out
+        for _ in 0..10 {
+            flash.tick(0.016);
+        }
+
+        assert_eq!(flash.timer, 0.0);
+        assert!(color_approx_eq(flash.emissive(), LinearRgba::rgb(0.4, 0.05, 0.05)));
+    }
+
+    #[test]
+    fn test_hurt_flash_retrigger_resets_timer() {
+        let mut flash = flash_with_base();
+        flash.trigger();
+        flash.tick(0.1);
+        flash.trigger();
+
+        assert_eq!(flash.timer, HurtFlash::DURATION);
+    }
+}
@@ -0,0 +1,397 @@
+// Grid pathfinding so chasing enemies route around walls and pillars
+// The arena is rasterized into a coarse walkability grid; paths are A* over it, string-pulled to a few waypoints
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use bevy::prelude::*;
+
+use super::{Enemy, EnemyState};
+use crate::level::{BoxCollider, CylinderCollider, WallCollider, ARENA_SIZE};
+use crate::player::Player;
+
+/// World units per grid cell
+pub const NAV_CELL_SIZE: f32 = 2.0;
+/// Clearance kept from obstacles - matches the enemy collision radius
+const NAV_MARGIN: f32 = 0.6;
+/// Obstacles entirely above this don't block (enemies are ~2 units tall)
+const NAV_CLEARANCE_HEIGHT: f32 = 2.0;
+/// Obstacles topping out below this are stepped over
+const NAV_STEP_HEIGHT: f32 = 0.6;
+/// Seconds between path refreshes for a chasing enemy
+const REPATH_INTERVAL: f32 = 0.5;
+/// A* gives up after this many expanded cells and the enemy chases directly
+const MAX_EXPANDED: usize = 4000;
+/// Waypoints closer than this count as reached
+const WAYPOINT_RADIUS: f32 = 1.0;
+
+/// Walkability grid over the arena floor, rebuilt whenever obstacles are added
+#[derive(Resource, Clone)]
+pub struct NavGrid {
+    /// World XZ of the grid's minimum corner
+    origin: Vec2,
+    cell_size: f32,
+    width: usize,
+    depth: usize,
+    blocked: Vec<bool>,
+}
+
+impl NavGrid {
+    /// Empty grid covering -half_extent..half_extent on X and Z
+    pub fn new(half_extent: f32, cell_size: f32) -> Self {
+        let cells = ((half_extent * 2.0) / cell_size).ceil().max(1.0) as usize;
+        Self {
+            origin: Vec2::splat(-half_extent),
+            cell_size,
+            width: cells,
+            depth: cells,
+            blocked: vec![false; cells * cells],
+        }
+    }
+
+    /// Block every cell a box (inflated by `margin`) touches
+    pub fn block_box(&mut self, center: Vec3, half_extents: Vec3, margin: f32) {
+        let reach = half_extents.xz() + Vec2::splat(margin + self.cell_size / 2.0);
+        let min = self.cell_coords(center.xz() - reach);
+        let max = self.cell_coords(center.xz() + reach);
+
+        for z in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                let cell_center = self.cell_center(x, z).xz();
+                let offset = (cell_center - center.xz()).abs();
+                if offset.x < reach.x && offset.y < reach.y {
+                    self.blocked[z * self.width + x] = true;
+                }
+            }
+        }
+    }
+
+    /// Cell containing a world XZ position, clamped onto the grid
+    fn cell_coords(&self, position: Vec2) -> (usize, usize) {
+        let local = ((position - self.origin) / self.cell_size).floor();
+        (
+            (local.x.max(0.0) as usize).min(self.width - 1),
+            (local.y.max(0.0) as usize).min(self.depth - 1),
+        )
+    }
+
+    fn cell_center(&self, x: usize, z: usize) -> Vec3 {
+        let center = self.origin + (Vec2::new(x as f32, z as f32) + 0.5) * self.cell_size;
+        Vec3::new(center.x, 0.0, center.y)
+    }
+
+    /// Whether the cell under a world position is free
+    pub fn is_walkable(&self, position: Vec3) -> bool {
+        let (x, z) = self.cell_coords(position.xz());
+        !self.blocked[z * self.width + x]
+    }
+
+    /// Whether a straight walk between two points stays on free cells
+    pub fn line_walkable(&self, from: Vec3, to: Vec3) -> bool {
+        let offset = (to - from).xz();
+        let samples = (offset.length() / (self.cell_size * 0.5)).ceil().max(1.0) as usize;
+        (0..=samples).all(|i| {
+            let point = from.xz() + offset * (i as f32 / samples as f32);
+            self.is_walkable(Vec3::new(point.x, 0.0, point.y))
+        })
+    }
+
+    /// Nearest free cell to a blocked one (e.g. a player standing on a pillar), within a few rings
+    fn nearest_walkable(&self, cell: (usize, usize)) -> Option<(usize, usize)> {
+        for ring in 0..4isize {
+            for dz in -ring..=ring {
+                for dx in -ring..=ring {
+                    if dx.abs() != ring && dz.abs() != ring {
+                        continue;
+                    }
+                    let (x, z) = (cell.0 as isize + dx, cell.1 as isize + dz);
+                    if x >= 0 && z >= 0 && (x as usize) < self.width && (z as usize) < self.depth
+                        && !self.blocked[z as usize * self.width + x as usize]
+                    {
+                        return Some((x as usize, z as usize));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Waypoints from `from` to `to` around blocked cells, ending at `to`.
+    /// None when there's no route (or the search gets too expensive).
+    pub fn find_path(&self, from: Vec3, to: Vec3) -> Option<Vec<Vec3>> {
+        if self.line_walkable(from, to) {
+            return Some(vec![to]);
+        }
+
+        // The mover itself may be squeezed against an obstacle - start from its own cell regardless
+        let start = self.cell_coords(from.xz());
+        let goal = self.nearest_walkable(self.cell_coords(to.xz()))?;
+        let index = |(x, z): (usize, usize)| z * self.width + x;
+        // Octile distance in tenths of a cell: 10 straight, 14 diagonal
+        let heuristic = |(x, z): (usize, usize)| {
+            let (dx, dz) = (x.abs_diff(goal.0), z.abs_diff(goal.1));
+            (10 * dx.max(dz) + 4 * dx.min(dz)) as u32
+        };
+
+        let mut cost = vec![u32::MAX; self.blocked.len()];
+        let mut came_from = vec![usize::MAX; self.blocked.len()];
+        let mut open = BinaryHeap::new();
+        cost[index(start)] = 0;
+        open.push(Reverse((heuristic(start), start)));
+
+        let mut expanded = 0;
+        while let Some(Reverse((_, cell))) = open.pop() {
+            if cell == goal {
+                break;
+            }
+            expanded += 1;
+            if expanded > MAX_EXPANDED {
+                return None;
+            }
+
+            for dz in -1isize..=1 {
+                for dx in -1isize..=1 {
+                    if dx == 0 && dz == 0 {
+                        continue;
+                    }
+                    let (x, z) = (cell.0 as isize + dx, cell.1 as isize + dz);
+                    if x < 0 || z < 0 || x as usize >= self.width || z as usize >= self.depth {
+                        continue;
+                    }
+                    let next = (x as usize, z as usize);
+                    if self.blocked[index(next)] {
+                        continue;
+                    }
+                    // No cutting corners past an obstacle
+                    if dx != 0 && dz != 0
+                        && (self.blocked[index((next.0, cell.1))] || self.blocked[index((cell.0, next.1))])
+                    {
+                        continue;
+                    }
+
+                    let step = if dx != 0 && dz != 0 { 14 } else { 10 };
+                    let next_cost = cost[index(cell)] + step;
+                    if next_cost < cost[index(next)] {
+                        cost[index(next)] = next_cost;
+                        came_from[index(next)] = index(cell);
+                        open.push(Reverse((next_cost + heuristic(next), next)));
+                    }
+                }
+            }
+        }
+
+        if cost[index(goal)] == u32::MAX {
+            return None;
+        }
+
+        // Walk back from the goal to get the cell centers in order
+        let mut cell = index(goal);
+        let mut cells = vec![cell];
+        while came_from[cell] != usize::MAX {
+            cell = came_from[cell];
+            cells.push(cell);
+        }
+        cells.reverse();
+        let mut points: Vec<Vec3> = cells
+            .iter()
+            .skip(1)
+            .map(|&cell| self.cell_center(cell % self.width, cell / self.width))
+            .collect();
+        points.push(to);
+
+        Some(self.string_pull(from, &points))
+    }
+
+    /// Drop waypoints that can be skipped with a straight walk
+    fn string_pull(&self, from: Vec3, points: &[Vec3]) -> Vec<Vec3> {
+        let mut waypoints = Vec::new();
+        let mut anchor = from;
+        let mut i = 0;
+        while i < points.len() {
+            // Furthest point still in a straight line from the anchor
+            let mut furthest = i;
+            while furthest + 1 < points.len() && self.line_walkable(anchor, points[furthest + 1]) {
+                furthest += 1;
+            }
+            waypoints.push(points[furthest]);
+            anchor = points[furthest];
+            i = furthest + 1;
+        }
+        waypoints
+    }
+}
+
+/// Route a chasing enemy is following - empty means head straight for the player
+#[derive(Component, Default)]
+pub struct NavPath {
+    pub waypoints: Vec<Vec3>,
+    /// Seconds until the path is recomputed
+    pub repath_timer: f32,
+}
+
+impl NavPath {
+    /// Where to head next: the first waypoint not yet reached, if any
+    pub fn next_waypoint(&mut self, position: Vec3) -> Option<Vec3> {
+        while let Some(&waypoint) = self.waypoints.first() {
+            if (waypoint - position).xz().length() > WAYPOINT_RADIUS || self.waypoints.len() == 1 {
+                return Some(waypoint);
+            }
+            self.waypoints.remove(0);
+        }
+        None
+    }
+}
+
+/// Rasterize walls and pillars into the NavGrid - reruns when level obstacles are added or removed
+/// (e.g. a crate gets destroyed)
+#[allow(clippy::type_complexity)]
+pub fn build_nav_grid(
+    mut commands: Commands,
+    added: Query<(), Or<(Added<WallCollider>, Added<CylinderCollider>)>>,
+    mut removed: RemovedComponents<WallCollider>,
+    walls: Query<(&Transform, &BoxCollider), With<WallCollider>>,
+    pillars: Query<(&Transform, &CylinderCollider)>,
+) {
+    // Always drain removals so old ones don't trigger a rebuild later
+    let any_removed = removed.read().count() > 0;
+    if added.is_empty() && !any_removed {
+        return;
+    }
+
+    let mut grid = NavGrid::new(ARENA_SIZE, NAV_CELL_SIZE);
+    let obstacles = walls.iter().map(|(transform, collider)| (transform.translation, collider.half_extents)).chain(
+        pillars.iter().map(|(transform, pillar)| {
+            (transform.translation, Vec3::new(pillar.radius, pillar.half_height, pillar.radius))
+        }),
+    );
+
+    for (center, half_extents) in obstacles {
+        let (bottom, top) = (center.y - half_extents.y, center.y + half_extents.y);
+        if bottom < NAV_CLEARANCE_HEIGHT && top > NAV_STEP_HEIGHT {
+            grid.block_box(center, half_extents, NAV_MARGIN);
+        }
+    }
+    commands.insert_resource(grid);
+}
+
+/// Refresh chasing enemies' routes to the player every REPATH_INTERVAL
+#[allow(clippy::type_complexity)]
+pub fn update_enemy_paths(
+    player_query: Query<&Transform, With<Player>>,
+    grid: Option<Res<NavGrid>>,
+    mut enemy_query: Query<(&Transform, &EnemyState, &mut NavPath), (With<Enemy>, Without<Player>)>,
+    time: Res<Time>,
+) {
+    let (Ok(player_transform), Some(grid)) = (player_query.single(), grid) else {
+        return;
+    };
+    let player_pos = player_transform.translation;
+
+    for (transform, state, mut path) in &mut enemy_query {
+        if !matches!(state, EnemyState::Chase) {
+            path.waypoints.clear();
+            path.repath_timer = 0.0;
+            continue;
+        }
+
+        path.repath_timer -= time.delta_secs();
+        if path.repath_timer > 0.0 {
+            continue;
+        }
+        path.repath_timer = REPATH_INTERVAL;
+        path.waypoints = grid.find_path(transform.translation, player_pos).unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// 40x40 grid with a wall across the straight line from (-10, 0) to (10, 0)
+    fn walled_grid() -> NavGrid {
+        let mut grid = NavGrid::new(20.0, NAV_CELL_SIZE);
+        grid.block_box(Vec3::new(0.0, 2.0, 0.0), Vec3::new(0.25, 2.0, 6.0), NAV_MARGIN);
+        grid
+    }
+
+    #[test]
+    fn test_path_routes_around_wall() {
+        let grid = walled_grid();
+        let (from, to) = (Vec3::new(-10.0, 1.0, 0.0), Vec3::new(10.0, 1.0, 0.0));
+        assert!(!grid.line_walkable(from, to));
+
+        let path = grid.find_path(from, to).expect("there's a way around the wall");
+        assert_eq!(path.last(), Some(&to));
+        // At least one waypoint leaves the straight line to get around the wall's end
+        assert!(path.iter().any(|waypoint| waypoint.z.abs() > 6.0));
+
+        // Every leg of the route is walkable
+        let mut previous = from;
+        for &waypoint in &path {
+            assert!(grid.line_walkable(previous, waypoint));
+            previous = waypoint;
+        }
+    }
+
+    #[test]
+    fn test_open_ground_path_is_direct() {
+        let grid = NavGrid::new(20.0, NAV_CELL_SIZE);
+        let to = Vec3::new(10.0, 1.0, 5.0);
+        assert_eq!(grid.find_path(Vec3::new(-10.0, 1.0, 0.0), to), Some(vec![to]));
+    }
+
+    #[test]
+    fn test_enclosed_goal_has_no_path() {
+        let mut grid = NavGrid::new(20.0, NAV_CELL_SIZE);
+        // Solid block far bigger than the search's fallback rings
+        grid.block_box(Vec3::new(10.0, 1.0, 0.0), Vec3::new(9.0, 1.0, 9.0), 0.0);
+        assert!(grid.find_path(Vec3::new(-15.0, 1.0, 0.0), Vec3::new(10.0, 1.0, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_chasing_enemy_gets_path_around_wall() {
+        let mut app = test_app();
+        app.add_systems(Update, (build_nav_grid, update_enemy_paths).chain());
+
+        app.world_mut().spawn((Player, Transform::from_xyz(10.0, 1.0, 0.0)));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(0.25, 2.0, 6.0) },
+            WallCollider,
+        ));
+        // Low cover is stepped over, so it doesn't block the grid
+        app.world_mut().spawn((
+            Transform::from_xyz(-5.0, 0.25, 0.0),
+            BoxCollider { half_extents: Vec3::new(1.0, 0.25, 1.0) },
+            WallCollider,
+        ));
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy::melee(),
+                EnemyState::Chase,
+                NavPath::default(),
+                Transform::from_xyz(-10.0, 1.0, 0.0),
+            ))
+            .id();
+        app.update();
+
+        assert!(app.world().resource::<NavGrid>().is_walkable(Vec3::new(-5.0, 0.0, 0.0)));
+        let path = &app.world().get::<NavPath>(enemy).unwrap().waypoints;
+        assert!(path.len() > 1);
+        assert!(path.iter().any(|waypoint| waypoint.z.abs() > 6.0));
+    }
+
+    #[test]
+    fn test_next_waypoint_skips_reached_ones() {
+        let mut path = NavPath {
+            waypoints: vec![Vec3::new(1.0, 0.0, 0.0), Vec3::new(5.0, 0.0, 0.0)],
+            repath_timer: 0.0,
+        };
+        assert_eq!(path.next_waypoint(Vec3::new(0.5, 1.0, 0.0)), Some(Vec3::new(5.0, 0.0, 0.0)));
+        assert_eq!(path.waypoints.len(), 1);
+        // The last waypoint (the player) is never dropped
+        assert_eq!(path.next_waypoint(Vec3::new(5.0, 1.0, 0.0)), Some(Vec3::new(5.0, 0.0, 0.0)));
+    }
+}
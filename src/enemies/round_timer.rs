@@ -0,0 +1,288 @@
+// Round timer - runs from the start of each run until the final wave is cleared or the
+// player dies, with the fastest full clear saved to disk
+
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use super::WaveState;
+use crate::menu::user_data_dir;
+
+/// Time spent in the current round - only ticks while `Playing`, so time paused doesn't count
+#[derive(Resource, Default, Debug)]
+pub struct RoundTimer {
+    pub elapsed: f32,
+    pub started: bool,
+    /// The round is over (all waves cleared or the player died)
+    pub stopped: bool,
+}
+
+impl RoundTimer {
+    pub fn start(&mut self) {
+        self.started = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.stopped = true;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started && !self.stopped
+    }
+
+    pub fn tick(&mut self, dt: f32) {
+        if self.is_running() {
+            self.elapsed += dt;
+        }
+    }
+}
+
+/// `mm:ss.mmm`
+pub fn format_time(seconds: f32) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u32;
+    format!("{:02}:{:02}.{:03}", millis / 60_000, millis / 1000 % 60, millis % 1000)
+}
+
+/// Fastest clear of every wave, saved as JSON in the user data directory
+#[derive(Resource, Default, Debug)]
+pub struct BestTime {
+    pub seconds: Option<f32>,
+    /// Where the best time is saved - None keeps it in memory only
+    pub path: Option<PathBuf>,
+}
+
+impl BestTime {
+    /// `<data dir>/ascii_shooter/best_time.json`, if the platform's data directory is known
+    pub fn default_path() -> Option<PathBuf> {
+        user_data_dir().map(|dir| dir.join("ascii_shooter").join("best_time.json"))
+    }
+
+    /// Read the best time from `path` - a missing or corrupt file means no best yet
+    pub fn load(path: PathBuf) -> Self {
+        let seconds = match std::fs::read_to_string(&path) {
+            Ok(source) => serde_json::from_str(&source).unwrap_or_else(|err| {
+                warn!("Ignoring corrupt best time {}: {err}", path.display());
+                None
+            }),
+            Err(_) => None,
+        };
+        Self { seconds, path: Some(path) }
+    }
+
+    /// Write the best time to its path (creating the directory if needed)
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string(&self.seconds).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Record a finished clear - returns true if it beat the previous best
+    pub fn submit(&mut self, seconds: f32) -> bool {
+        if self.seconds.is_some_and(|best| best <= seconds) {
+            return false;
+        }
+        self.seconds = Some(seconds);
+        true
+    }
+}
+
+pub fn load_best_time(mut commands: Commands) {
+    let best_time = match BestTime::default_path() {
+        Some(path) => BestTime::load(path),
+        None => BestTime::default(),
+    };
+    commands.insert_resource(best_time);
+}
+
+/// Starts when the game enters `Playing` - resuming from pause doesn't restart it
+pub fn start_round_timer(mut timer: ResMut<RoundTimer>) {
+    timer.start();
+}
+
+/// A new run from the main menu starts the clock from zero, even after the last one stopped
+pub fn reset_round_timer(mut timer: ResMut<RoundTimer>) {
+    *timer = RoundTimer::default();
+}
+
+pub fn tick_round_timer(mut timer: ResMut<RoundTimer>, time: Res<Time>) {
+    timer.tick(time.delta_secs());
+}
+
+/// The player died - the round ends without a time
+pub fn stop_round_timer(mut timer: ResMut<RoundTimer>) {
+    timer.stop();
+}
+
+/// Stop the timer once the final wave is cleared and save it if it's a new best
+pub fn finish_round_timer(wave: Res<WaveState>, mut timer: ResMut<RoundTimer>, mut best_time: ResMut<BestTime>) {
+    if !wave.all_cleared || !timer.is_running() {
+        return;
+    }
+    timer.stop();
+    if !best_time.submit(timer.elapsed) {
+        return;
+    }
+    info!("New best time: {}", format_time(timer.elapsed));
+
+    if let Err(err) = best_time.save() {
+        let path = best_time.path.as_deref().unwrap_or(Path::new("?"));
+        warn!("Failed to save best time to {}: {err}", path.display());
+    }
+}
+
+/// Round timer HUD
+#[derive(Component)]
+pub struct RoundTimerHud;
+
+/// Round timer HUD text, with the best time once there is one
+pub fn round_timer_text(timer: &RoundTimer, best: Option<f32>) -> String {
+    match best {
+        Some(best) => format!("TIME: {}  BEST: {}", format_time(timer.elapsed), format_time(best)),
+        None => format!("TIME: {}", format_time(timer.elapsed)),
+    }
+}
+
+/// Round timer (top-left, under the speed display)
+pub fn spawn_round_timer_hud(mut commands: Commands) {
+    commands.spawn((
+        Text::new(round_timer_text(&RoundTimer::default(), None)),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.7, 0.9, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(40.0),
+            ..default()
+        },
+        RoundTimerHud,
+    ));
+}
+
+pub fn update_round_timer_hud(
+    timer: Res<RoundTimer>,
+    best_time: Option<Res<BestTime>>,
+    mut query: Query<&mut Text, With<RoundTimerHud>>,
+) {
+    let best = best_time.and_then(|best_time| best_time.seconds);
+    for mut text in &mut query {
+        **text = round_timer_text(&timer, best);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::GameState;
+
+    fn set_state(app: &mut App, state: GameState) {
+        app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+    }
+
+    #[test]
+    fn test_paused_time_is_excluded() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .with_game_state(GameState::Menu)
+            .init_resource::<RoundTimer>()
+            .add_systems(OnEnter(GameState::Playing), start_round_timer)
+            .add_systems(Update, tick_round_timer.run_if(in_state(GameState::Playing)));
+        // First frame has no delta
+        app.update();
+
+        set_state(&mut app, GameState::Playing);
+        for _ in 0..5 {
+            app.update();
+        }
+        set_state(&mut app, GameState::Paused);
+        for _ in 0..20 {
+            app.update();
+        }
+        // Resuming re-enters Playing without resetting the timer
+        set_state(&mut app, GameState::Playing);
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let timer = app.world().resource::<RoundTimer>();
+        assert!(timer.is_running());
+        assert!((timer.elapsed - 0.8).abs() < 0.001, "elapsed {}", timer.elapsed);
+    }
+
+    #[test]
+    fn test_new_run_restarts_the_timer() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .with_game_state(GameState::Menu)
+            .init_resource::<RoundTimer>()
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                reset_round_timer,
+            )
+            .add_systems(OnEnter(GameState::Playing), start_round_timer)
+            .add_systems(OnEnter(GameState::GameOver), stop_round_timer)
+            .add_systems(Update, tick_round_timer.run_if(in_state(GameState::Playing)));
+        app.update();
+
+        // First run: dies after 1s
+        set_state(&mut app, GameState::Playing);
+        for _ in 0..10 {
+            app.update();
+        }
+        set_state(&mut app, GameState::GameOver);
+        app.update();
+        assert!(!app.world().resource::<RoundTimer>().is_running());
+
+        // Second run from the menu counts from zero again
+        set_state(&mut app, GameState::Menu);
+        app.update();
+        set_state(&mut app, GameState::Playing);
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let timer = app.world().resource::<RoundTimer>();
+        assert!(timer.is_running());
+        assert!((timer.elapsed - 0.3).abs() < 0.001, "elapsed {}", timer.elapsed);
+    }
+
+    #[test]
+    fn test_clearing_every_wave_records_best_time() {
+        let mut app = test_app();
+        app.insert_resource(RoundTimer { elapsed: 95.0, started: true, stopped: false })
+            .insert_resource(BestTime { seconds: Some(120.0), path: None })
+            .init_resource::<WaveState>()
+            .add_systems(Update, finish_round_timer);
+
+        app.update();
+        assert!(app.world().resource::<RoundTimer>().is_running());
+
+        app.world_mut().resource_mut::<WaveState>().all_cleared = true;
+        app.update();
+        assert!(!app.world().resource::<RoundTimer>().is_running());
+        assert_eq!(app.world().resource::<BestTime>().seconds, Some(95.0));
+
+        // A slower clear doesn't replace it
+        let mut best = BestTime { seconds: Some(95.0), path: None };
+        assert!(!best.submit(130.0));
+        assert_eq!(best.seconds, Some(95.0));
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(0.0), "00:00.000");
+        assert_eq!(format_time(83.456), "01:23.456");
+        assert_eq!(format_time(600.5), "10:00.500");
+    }
+}
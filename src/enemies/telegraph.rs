@@ -0,0 +1,163 @@
+// Spawn telegraphs - a growing, pulsing marker on the floor at each upcoming spawn point,
+// so wave enemies don't just pop into existence next to the player
+
+use bevy::prelude::*;
+
+use super::{spawn_wave, Difficulty, EnemyType};
+use crate::rendering::{AsciiCategory, AsciiPatternId};
+use crate::rng::GameRng;
+
+/// Marker at an upcoming spawn point - its enemy appears when the timer runs out
+#[derive(Component)]
+pub struct SpawnTelegraph {
+    pub enemy_type: EnemyType,
+    /// Where the enemy will spawn
+    pub spawn_position: Vec3,
+    pub health_multiplier: f32,
+    /// Seconds until the enemy spawns
+    pub remaining: f32,
+    pub duration: f32,
+}
+
+impl SpawnTelegraph {
+    /// Marker radius once fully grown
+    pub const MAX_RADIUS: f32 = 1.2;
+    /// Pulses over the telegraph's lifetime
+    pub const PULSES: f32 = 4.0;
+
+    /// 0 when it appears, 1 when the enemy spawns
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - self.remaining / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Current marker radius - grows with progress and pulses on top
+    pub fn radius(&self) -> f32 {
+        let progress = self.progress();
+        let pulse = 1.0 + 0.15 * (progress * Self::PULSES * std::f32::consts::TAU).sin();
+        Self::MAX_RADIUS * (0.2 + 0.8 * progress) * pulse
+    }
+}
+
+/// Put a telegraph at each spawn point - the enemies follow after `delay` seconds
+pub fn spawn_telegraphs(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    spawns: &[(Vec3, EnemyType)],
+    health_multiplier: f32,
+    delay: f32,
+) {
+    let mesh = meshes.add(Cylinder::new(1.0, 0.05));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::srgba(1.0, 0.2, 0.1, 0.7),
+        emissive: LinearRgba::rgb(2.0, 0.3, 0.1),
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+
+    for &(position, enemy_type) in spawns {
+        let telegraph = SpawnTelegraph {
+            enemy_type,
+            spawn_position: position,
+            health_multiplier,
+            remaining: delay,
+            duration: delay,
+        };
+        let radius = telegraph.radius();
+        commands.spawn((
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            // Flat on the floor under the spawn point
+            Transform::from_xyz(position.x, 0.05, position.z).with_scale(Vec3::new(radius, 1.0, radius)),
+            AsciiPatternId::matrix_cycle().with_category(AsciiCategory::Effect),
+            telegraph,
+        ));
+    }
+}
+
+/// Grow and pulse telegraphs, replacing each with its enemy once its time is up
+pub fn update_spawn_telegraphs(
+    mut commands: Commands,
+    mut telegraph_query: Query<(Entity, &mut Transform, &mut SpawnTelegraph)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut rng: ResMut<GameRng>,
+    difficulty: Res<Difficulty>,
+    time: Res<Time>,
+) {
+    for (entity, mut transform, mut telegraph) in &mut telegraph_query {
+        telegraph.remaining -= time.delta_secs();
+        if telegraph.remaining > 0.0 {
+            let radius = telegraph.radius();
+            transform.scale = Vec3::new(radius, 1.0, radius);
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        spawn_wave(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &mut rng,
+            &[(telegraph.spawn_position, telegraph.enemy_type)],
+            telegraph.health_multiplier,
+            *difficulty,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{advance_waves, Enemy, EnemyConfig, WaveState};
+    use super::*;
+    use crate::test_utils::*;
+
+    fn count<T: Component>(app: &mut App) -> usize {
+        app.world_mut().query::<&T>().iter(app.world()).count()
+    }
+
+    #[test]
+    fn test_enemies_spawn_after_telegraph_delay() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .with_mesh_assets()
+            .insert_resource(GameRng::new(1))
+            .init_resource::<Difficulty>()
+            .insert_resource(EnemyConfig {
+                spawn_telegraph_time: 1.0,
+                ..default()
+            })
+            // Wave 1 cleared, intermission just about over
+            .insert_resource(WaveState {
+                intermission: Some(0.01),
+                ..default()
+            })
+            .add_systems(Update, (update_spawn_telegraphs, advance_waves).chain());
+        // First frame has no delta
+        app.update();
+        app.update();
+
+        let wave = app.world().resource::<WaveState>();
+        assert_eq!(wave.current_wave, 2);
+        let (melee, ranged) = WaveState::enemy_counts(2);
+        let expected = (melee + ranged) as usize;
+        assert_eq!(count::<SpawnTelegraph>(&mut app), expected);
+        assert_eq!(count::<Enemy>(&mut app), 0);
+
+        // Pending spawns keep the wave from counting as cleared
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(app.world().resource::<WaveState>().enemies_remaining, expected as u32);
+            assert!(app.world().resource::<WaveState>().intermission.is_none());
+        }
+        assert_eq!(count::<Enemy>(&mut app), 0);
+
+        app.update();
+        assert_eq!(count::<SpawnTelegraph>(&mut app), 0);
+        assert_eq!(count::<Enemy>(&mut app), expected);
+    }
+}
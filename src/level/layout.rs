@@ -0,0 +1,301 @@
+// Level layouts - arenas described in RON files under assets/levels, spawned as colliders,
+// lights and enemy spawn points, with the built-in test arena as a fallback
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use super::{
+    spawn_jump_pad, spawn_moving_platform, spawn_teleporter, BoxCollider, CylinderCollider, GroundFloor, LevelGeometry,
+    Teleporter, WallCollider,
+};
+use crate::combat::spawn_crate;
+use crate::rendering::AsciiPatternId;
+
+/// Level file loaded at startup - the built-in test arena is used when it's missing
+pub const LEVEL_PATH: &str = "assets/levels/arena.ron";
+
+/// Data-driven level layout, deserialized from RON
+#[derive(Resource, Deserialize, Clone, Debug)]
+pub struct LevelDef {
+    /// Width/depth of the square ground plane
+    pub floor_size: f32,
+    /// Boxes that block movement
+    #[serde(default)]
+    pub walls: Vec<BoxDef>,
+    /// Round obstacles - the top can be stood on
+    #[serde(default)]
+    pub pillars: Vec<PillarDef>,
+    /// Walkable boxes (platforms, stairs) that don't block horizontal movement
+    #[serde(default)]
+    pub platforms: Vec<BoxDef>,
+    /// Platforms that travel between waypoints, carrying whoever stands on them
+    #[serde(default)]
+    pub moving_platforms: Vec<MovingPlatformDef>,
+    #[serde(default)]
+    pub jump_pads: Vec<JumpPadDef>,
+    #[serde(default)]
+    pub teleporters: Vec<TeleporterDef>,
+    /// Breakable crates (base position) - each drops a pickup
+    #[serde(default)]
+    pub crates: Vec<[f32; 3]>,
+    #[serde(default)]
+    pub lights: Vec<[f32; 3]>,
+    #[serde(default)]
+    pub melee_spawns: Vec<[f32; 3]>,
+    #[serde(default)]
+    pub ranged_spawns: Vec<[f32; 3]>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct BoxDef {
+    pub position: [f32; 3],
+    pub half_extents: [f32; 3],
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PillarDef {
+    /// Base of the pillar, on the ground
+    pub position: [f32; 3],
+    pub radius: f32,
+    pub height: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct MovingPlatformDef {
+    pub half_extents: [f32; 3],
+    /// Box centers to travel between, starting at the first
+    pub waypoints: Vec<[f32; 3]>,
+    pub speed: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct JumpPadDef {
+    /// Base of the pad, on the surface it sits on
+    pub position: [f32; 3],
+    /// Launch velocity (see JumpPad)
+    pub impulse: [f32; 3],
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct TeleporterDef {
+    pub id: u32,
+    /// `id` of the teleporter this one sends the player to
+    pub target: u32,
+    /// Base of the pad, on the surface it sits on
+    pub position: [f32; 3],
+    /// Exit facing in degrees (0 = -Z, 90 = -X)
+    #[serde(default)]
+    pub yaw_degrees: f32,
+}
+
+impl LevelDef {
+    /// Read and parse a level file - None (with a warning for bad files) falls back to the built-in arena
+    pub fn load(path: &str) -> Option<Self> {
+        let source = std::fs::read_to_string(path).ok()?;
+        match ron::from_str(&source) {
+            Ok(level) => Some(level),
+            Err(err) => {
+                warn!("Failed to parse level {path}: {err}");
+                None
+            }
+        }
+    }
+}
+
+/// Spawn every entity described by a level file
+pub fn spawn_level_def(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    level: &LevelDef,
+) {
+    let half_floor = level.floor_size / 2.0;
+    commands.spawn((
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(level.floor_size, level.floor_size))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.3, 0.3, 0.35),
+            perceptual_roughness: 0.9,
+            ..default()
+        })),
+        Transform::default(),
+        LevelGeometry,
+        BoxCollider { half_extents: Vec3::new(half_floor, 0.5, half_floor) },
+        GroundFloor,
+        AsciiPatternId::standard(),
+    ));
+
+    let wall_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.5, 0.4, 0.35),
+        perceptual_roughness: 0.8,
+        ..default()
+    });
+    for wall in &level.walls {
+        let half = Vec3::from_array(wall.half_extents);
+        commands.spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(half * 2.0))),
+            MeshMaterial3d(wall_material.clone()),
+            Transform::from_translation(Vec3::from_array(wall.position)),
+            LevelGeometry,
+            BoxCollider { half_extents: half },
+            WallCollider,
+            AsciiPatternId::blocks(),
+        ));
+    }
+
+    let pillar_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.6, 0.55, 0.5),
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    for pillar in &level.pillars {
+        let half_height = pillar.height / 2.0;
+        let center = Vec3::from_array(pillar.position) + Vec3::Y * half_height;
+        commands.spawn((
+            Mesh3d(meshes.add(Cylinder::new(pillar.radius, pillar.height))),
+            MeshMaterial3d(pillar_material.clone()),
+            Transform::from_translation(center),
+            LevelGeometry,
+            BoxCollider { half_extents: Vec3::new(pillar.radius, half_height, pillar.radius) },
+            CylinderCollider { radius: pillar.radius, half_height },
+            AsciiPatternId::slashes(),
+        ));
+    }
+
+    let platform_material = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.4, 0.35, 0.5),
+        emissive: LinearRgba::rgb(0.1, 0.05, 0.15),
+        perceptual_roughness: 0.6,
+        ..default()
+    });
+    for platform in &level.platforms {
+        let half = Vec3::from_array(platform.half_extents);
+        commands.spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(half * 2.0))),
+            MeshMaterial3d(platform_material.clone()),
+            Transform::from_translation(Vec3::from_array(platform.position)),
+            LevelGeometry,
+            BoxCollider { half_extents: half },
+            AsciiPatternId::binary(),
+        ));
+    }
+
+    for platform in &level.moving_platforms {
+        spawn_moving_platform(
+            commands,
+            meshes,
+            materials,
+            Vec3::from_array(platform.half_extents),
+            platform.waypoints.iter().copied().map(Vec3::from_array).collect(),
+            platform.speed,
+        );
+    }
+
+    for pad in &level.jump_pads {
+        spawn_jump_pad(
+            commands,
+            meshes,
+            materials,
+            Vec3::from_array(pad.position),
+            Vec3::from_array(pad.impulse),
+        );
+    }
+
+    for teleporter in &level.teleporters {
+        spawn_teleporter(
+            commands,
+            meshes,
+            materials,
+            Teleporter { id: teleporter.id, target: teleporter.target },
+            Vec3::from_array(teleporter.position),
+            teleporter.yaw_degrees.to_radians(),
+        );
+    }
+
+    for &position in &level.crates {
+        spawn_crate(commands, meshes, materials, Vec3::from_array(position));
+    }
+
+    for &position in &level.lights {
+        commands.spawn((
+            PointLight {
+                intensity: 1000000.0,
+                shadows_enabled: true,
+                range: 80.0,
+                ..default()
+            },
+            Transform::from_translation(Vec3::from_array(position)),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::Destructible;
+    use crate::level::{JumpPad, MovingPlatform};
+
+    const SMALL_LEVEL: &str = "(
+        floor_size: 40.0,
+        walls: [
+            (position: (0.0, 2.0, -20.0), half_extents: (20.0, 2.0, 0.25)),
+            (position: (0.0, 2.0, 20.0), half_extents: (20.0, 2.0, 0.25)),
+        ],
+        pillars: [(position: (5.0, 0.0, 5.0), radius: 1.0, height: 4.0)],
+        platforms: [(position: (-5.0, 1.0, 0.0), half_extents: (2.0, 0.25, 2.0))],
+        moving_platforms: [(half_extents: (1.0, 0.25, 1.0), waypoints: [(0.0, 0.5, 0.0), (0.0, 3.0, 0.0)], speed: 2.0)],
+        jump_pads: [(position: (0.0, 0.0, 10.0), impulse: (0.0, 12.0, 0.0))],
+        teleporters: [
+            (id: 0, target: 1, position: (-10.0, 0.0, -10.0)),
+            (id: 1, target: 0, position: (10.0, 0.0, -10.0), yaw_degrees: 180.0),
+        ],
+        crates: [(3.0, 0.0, 3.0)],
+        lights: [(0.0, 10.0, 0.0)],
+        melee_spawns: [(10.0, 1.0, 10.0)],
+    )";
+
+    #[test]
+    fn test_level_def_spawns_colliders() {
+        let level: LevelDef = ron::from_str(SMALL_LEVEL).expect("level should parse");
+        assert!(level.ranged_spawns.is_empty());
+
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .insert_resource(level)
+            .add_systems(
+                Startup,
+                |mut commands: Commands,
+                 mut meshes: ResMut<Assets<Mesh>>,
+                 mut materials: ResMut<Assets<StandardMaterial>>,
+                 level: Res<LevelDef>| {
+                    spawn_level_def(&mut commands, &mut meshes, &mut materials, &level);
+                },
+            );
+        app.update();
+
+        let world = app.world_mut();
+        // Floor + 2 walls + pillar + platform + moving platform + jump pad + crate
+        assert_eq!(world.query::<&BoxCollider>().iter(world).count(), 8);
+        assert_eq!(world.query::<&MovingPlatform>().iter(world).count(), 1);
+        assert_eq!(world.query::<&JumpPad>().iter(world).count(), 1);
+        assert_eq!(world.query::<&Teleporter>().iter(world).count(), 2);
+        // 2 walls + crate
+        assert_eq!(world.query::<&WallCollider>().iter(world).count(), 3);
+        assert_eq!(world.query::<&Destructible>().iter(world).count(), 1);
+        assert_eq!(world.query::<&CylinderCollider>().iter(world).count(), 1);
+        assert_eq!(world.query::<&PointLight>().iter(world).count(), 1);
+    }
+
+    #[test]
+    fn test_example_level_parses() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/levels/example.ron");
+        let level = LevelDef::load(path).expect("example level should load");
+        assert!(!level.walls.is_empty());
+        assert!(!level.melee_spawns.is_empty());
+    }
+
+    #[test]
+    fn test_missing_level_file_falls_back() {
+        assert!(LevelDef::load("assets/levels/does_not_exist.ron").is_none());
+    }
+}
@@ -1,8 +1,16 @@
 use bevy::prelude::*;
 use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::view::RenderLayers;
+use crate::combat::spawn_crate;
 use crate::rendering::AsciiPatternId;
+use crate::GameState;
+
+pub mod layout;
+
+pub use layout::*;
 
 /// Helper to add a quad to mesh data
+#[allow(clippy::too_many_arguments)]
 fn add_quad(
     verts: &mut Vec<[f32; 3]>,
     norms: &mut Vec<[f32; 3]>,
@@ -130,7 +138,14 @@ pub struct LevelPlugin;
 
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_test_level);
+        // Loaded up front so other plugins' startup systems (enemy spawns) can read it
+        if let Some(level) = LevelDef::load(LEVEL_PATH) {
+            info!("Loaded level from {LEVEL_PATH}");
+            app.insert_resource(level);
+        }
+        app.add_systems(Startup, (spawn_level, spawn_skybox))
+            // Before Update, so player movement sees where platforms are this frame
+            .add_systems(PreUpdate, move_platforms.run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -143,6 +158,123 @@ pub struct BoxCollider {
     pub half_extents: Vec3,
 }
 
+/// Upright cylinder collider for round obstacles - movers slide around it instead of along flat faces
+#[derive(Component)]
+pub struct CylinderCollider {
+    pub radius: f32,
+    pub half_height: f32,
+}
+
+impl CylinderCollider {
+    /// Push a circle of `mover_radius` at `position` out of the cylinder centered at `center` (XZ only).
+    /// Returns the corrected position and the outward normal, or None if they don't overlap.
+    pub fn push_out(&self, center: Vec3, position: Vec3, mover_radius: f32) -> Option<(Vec3, Vec3)> {
+        let offset = Vec3::new(position.x - center.x, 0.0, position.z - center.z);
+        let min_distance = self.radius + mover_radius;
+        if offset.length_squared() >= min_distance * min_distance {
+            return None;
+        }
+
+        // Dead center has no outward direction - pick one
+        let normal = offset.normalize_or(Vec3::X);
+        let pushed = center + normal * min_distance;
+        Some((Vec3::new(pushed.x, position.y, pushed.z), normal))
+    }
+}
+
+/// Result of a ray hitting a collider surface
+#[derive(Clone, Copy, Debug)]
+pub struct RayHit {
+    /// Distance along the ray to the hit point
+    pub distance: f32,
+    /// World-space hit point
+    pub point: Vec3,
+    /// Outward surface normal at the hit point (axis-aligned)
+    pub normal: Vec3,
+}
+
+impl BoxCollider {
+    /// Ray vs AABB intersection (slab method)
+    /// Returns the entry point and face normal, or None if the ray misses within `max_distance`.
+    /// Rays starting inside the box report no hit.
+    pub fn ray_intersection(
+        &self,
+        center: Vec3,
+        origin: Vec3,
+        direction: Vec3,
+        max_distance: f32,
+    ) -> Option<RayHit> {
+        let min = center - self.half_extents;
+        let max = center + self.half_extents;
+
+        let mut t_enter = f32::NEG_INFINITY;
+        let mut t_exit = f32::INFINITY;
+        let mut normal = Vec3::ZERO;
+
+        for axis in 0..3 {
+            let o = origin[axis];
+            let d = direction[axis];
+
+            if d.abs() < 1e-6 {
+                // Parallel to this slab - must already be between the planes
+                if o < min[axis] || o > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let mut t0 = (min[axis] - o) / d;
+            let mut t1 = (max[axis] - o) / d;
+            // Entering through the min face means the surface faces -axis
+            let mut face_sign = -1.0;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+                face_sign = 1.0;
+            }
+
+            if t0 > t_enter {
+                t_enter = t0;
+                normal = Vec3::ZERO;
+                normal[axis] = face_sign;
+            }
+            t_exit = t_exit.min(t1);
+
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        if t_enter < 0.0 || t_enter > max_distance {
+            return None;
+        }
+
+        Some(RayHit {
+            distance: t_enter,
+            point: origin + direction * t_enter,
+            normal,
+        })
+    }
+}
+
+/// Whether the straight segment `from` -> `to` passes through none of the colliders
+pub fn line_of_sight_clear<'a>(
+    colliders: impl IntoIterator<Item = (&'a Transform, &'a BoxCollider)>,
+    from: Vec3,
+    to: Vec3,
+) -> bool {
+    let offset = to - from;
+    let distance = offset.length();
+    let Some(direction) = offset.try_normalize() else {
+        return true;
+    };
+
+    colliders.into_iter().all(|(transform, collider)| {
+        collider
+            .ray_intersection(transform.translation, from, direction, distance)
+            .is_none()
+    })
+}
+
 /// Marker for wall colliders that block player/enemy movement
 /// (Floors only block projectiles, not movement)
 #[derive(Component)]
@@ -171,14 +303,6 @@ impl Slope {
         }
     }
 
-    /// Create a slope that rises in the -Z direction
-    pub fn falling_z(rise_per_unit: f32) -> Self {
-        Self {
-            direction: Vec2::new(0.0, -1.0),
-            rise_per_unit,
-        }
-    }
-
     /// Create a slope that rises in the +X direction
     pub fn rising_x(rise_per_unit: f32) -> Self {
         Self {
@@ -187,12 +311,10 @@ impl Slope {
         }
     }
 
-    /// Create a slope that rises in the -X direction
-    pub fn falling_x(rise_per_unit: f32) -> Self {
-        Self {
-            direction: Vec2::new(-1.0, 0.0),
-            rise_per_unit,
-        }
+    /// Upward-facing surface normal
+    pub fn normal(&self) -> Vec3 {
+        let rise = self.direction * self.rise_per_unit;
+        Vec3::new(-rise.x, 1.0, -rise.y).normalize()
     }
 
     /// Calculate the ground height at a given world position
@@ -211,12 +333,307 @@ impl Slope {
     }
 }
 
+/// Launch pad - stepping onto its top sets the player's velocity to `impulse`
+#[derive(Component)]
+pub struct JumpPad {
+    /// Launch velocity - horizontal parts of zero keep the player's own horizontal momentum
+    pub impulse: Vec3,
+}
+
+impl JumpPad {
+    /// Pad size - flat enough to walk onto without a step
+    pub const HALF_EXTENTS: Vec3 = Vec3::new(1.5, 0.1, 1.5);
+
+    /// Whether feet at `feet` are standing on (or just touching) the top of a pad centered at `center`
+    pub fn touches(center: Vec3, half_extents: Vec3, feet: Vec3) -> bool {
+        let top = center.y + half_extents.y;
+        (feet.x - center.x).abs() <= half_extents.x
+            && (feet.z - center.z).abs() <= half_extents.z
+            && feet.y >= top - 0.1
+            && feet.y <= top + 0.15
+    }
+
+    /// Velocity after being launched from `velocity`
+    pub fn launch(&self, velocity: Vec3) -> Vec3 {
+        let horizontal = Vec3::new(self.impulse.x, 0.0, self.impulse.z);
+        if horizontal == Vec3::ZERO {
+            Vec3::new(velocity.x, self.impulse.y, velocity.z)
+        } else {
+            self.impulse
+        }
+    }
+}
+
+/// Spawn a jump pad resting on the surface at `position`
+pub fn spawn_jump_pad(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    impulse: Vec3,
+) -> Entity {
+    let half = JumpPad::HALF_EXTENTS;
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(half * 2.0))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(1.0, 0.6, 0.1),
+                emissive: LinearRgba::rgb(0.6, 0.3, 0.0),
+                perceptual_roughness: 0.4,
+                ..default()
+            })),
+            Transform::from_translation(position + Vec3::Y * half.y),
+            LevelGeometry,
+            // Walkable like a platform - no WallCollider
+            BoxCollider { half_extents: half },
+            JumpPad { impulse },
+            AsciiPatternId::matrix_cycle(),
+        ))
+        .id()
+}
+
+/// One end of a teleporter pair - walking into it sends the player to the teleporter whose `id` is `target`.
+/// The transform sits at the pad's base; its forward direction is the exit facing.
+#[derive(Component)]
+pub struct Teleporter {
+    pub id: u32,
+    pub target: u32,
+}
+
+impl Teleporter {
+    /// Trigger volume around the pad, measured from its base up
+    pub const TRIGGER_HALF_EXTENTS: Vec3 = Vec3::new(1.0, 1.5, 1.0);
+    /// How far in front of the exit pad the player arrives - clear of its trigger
+    pub const EXIT_DISTANCE: f32 = 2.0;
+    /// Seconds after teleporting before another teleporter can fire
+    pub const COOLDOWN: f32 = 0.5;
+
+    /// Whether `point` is inside the trigger volume of a teleporter at `transform`
+    pub fn contains(transform: &Transform, point: Vec3) -> bool {
+        let half = Self::TRIGGER_HALF_EXTENTS;
+        let center = transform.translation + Vec3::Y * half.y;
+        (point - center).abs().cmple(half).all()
+    }
+
+    /// Where the player's feet land when arriving at a teleporter at `transform`
+    pub fn exit_point(transform: &Transform) -> Vec3 {
+        transform.translation + Self::exit_direction(transform) * Self::EXIT_DISTANCE
+    }
+
+    /// Horizontal direction the player faces (and moves) when arriving
+    pub fn exit_direction(transform: &Transform) -> Vec3 {
+        let forward = transform.forward().as_vec3();
+        Vec3::new(forward.x, 0.0, forward.z).normalize_or(Vec3::NEG_Z)
+    }
+}
+
+/// Spawn one end of a teleporter pair at `position` (base on the surface), exiting toward `yaw`
+pub fn spawn_teleporter(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    teleporter: Teleporter,
+    position: Vec3,
+    yaw: f32,
+) -> Entity {
+    let half = Teleporter::TRIGGER_HALF_EXTENTS;
+    commands
+        .spawn((
+            Transform::from_translation(position).with_rotation(Quat::from_rotation_y(yaw)),
+            Visibility::default(),
+            LevelGeometry,
+            teleporter,
+        ))
+        .with_children(|parent| {
+            // Thin glowing disc on the floor - nothing to collide with
+            parent.spawn((
+                Mesh3d(meshes.add(Cylinder::new(half.x, 0.05))),
+                MeshMaterial3d(materials.add(StandardMaterial {
+                    base_color: Color::srgb(0.3, 0.6, 1.0),
+                    emissive: LinearRgba::rgb(0.2, 0.5, 1.5),
+                    ..default()
+                })),
+                Transform::from_xyz(0.0, 0.025, 0.0),
+                AsciiPatternId::matrix_fall(),
+            ));
+        })
+        .id()
+}
+
+/// Platform that travels back and forth along its waypoints (looping back to the first).
+/// Needs a BoxCollider; players standing on top ride along.
+#[derive(Component)]
+pub struct MovingPlatform {
+    pub waypoints: Vec<Vec3>,
+    /// Travel speed (units/sec)
+    pub speed: f32,
+    /// Index of the waypoint being moved toward
+    pub next: usize,
+    /// How far the platform moved this frame - riders are carried by the same amount
+    pub delta: Vec3,
+}
+
+impl MovingPlatform {
+    pub fn new(waypoints: Vec<Vec3>, speed: f32) -> Self {
+        Self {
+            waypoints,
+            speed,
+            next: 0,
+            delta: Vec3::ZERO,
+        }
+    }
+
+    /// Move from `position` toward the waypoints for `dt` seconds, turning at each one.
+    /// Returns the new position.
+    pub fn step(&mut self, mut position: Vec3, dt: f32) -> Vec3 {
+        if self.waypoints.is_empty() {
+            return position;
+        }
+
+        let mut travel = self.speed * dt;
+        // Bounded so a huge dt can't spin forever on tiny segments
+        for _ in 0..self.waypoints.len() * 2 {
+            let target = self.waypoints[self.next % self.waypoints.len()];
+            let distance = position.distance(target);
+            if distance > travel {
+                position += (target - position) / distance * travel;
+                break;
+            }
+            position = target;
+            travel -= distance;
+            self.next = (self.next + 1) % self.waypoints.len();
+        }
+        position
+    }
+
+    /// Whether feet at `feet` are standing on top of this platform, given its center and size
+    /// before this frame's move
+    pub fn carries(previous_center: Vec3, half_extents: Vec3, feet: Vec3, rider_radius: f32) -> bool {
+        let top = previous_center.y + half_extents.y;
+        (feet.x - previous_center.x).abs() < half_extents.x + rider_radius
+            && (feet.z - previous_center.z).abs() < half_extents.z + rider_radius
+            && (feet.y - top).abs() < 0.15
+    }
+}
+
+/// Spawn a moving platform starting at the first waypoint
+pub fn spawn_moving_platform(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    half_extents: Vec3,
+    waypoints: Vec<Vec3>,
+    speed: f32,
+) -> Entity {
+    let start = waypoints.first().copied().unwrap_or_default();
+    commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.35, 0.5, 0.6),
+                emissive: LinearRgba::rgb(0.05, 0.1, 0.15),
+                perceptual_roughness: 0.6,
+                ..default()
+            })),
+            Transform::from_translation(start),
+            LevelGeometry,
+            // A floor, not a wall - like static platforms
+            BoxCollider { half_extents },
+            MovingPlatform::new(waypoints, speed),
+            AsciiPatternId::binary(),
+        ))
+        .id()
+}
+
+/// Advance moving platforms along their waypoints
+pub fn move_platforms(mut platform_query: Query<(&mut Transform, &mut MovingPlatform)>, time: Res<Time>) {
+    let dt = time.delta_secs();
+
+    for (mut transform, mut platform) in &mut platform_query {
+        let previous = transform.translation;
+        transform.translation = platform.step(previous, dt);
+        platform.delta = transform.translation - previous;
+    }
+}
+
 pub const ARENA_SIZE: f32 = 100.0;
 
-fn spawn_test_level(
+/// Sky dome radius - well outside the arena corners, inside the camera's far plane
+pub const SKYBOX_RADIUS: f32 = 400.0;
+
+/// Marker for the gradient sky dome around the arena
+#[derive(Component)]
+pub struct Skybox;
+
+/// Inside-out sphere whose vertex colors fade from `horizon` (at and below eye level) up to `zenith`
+fn create_sky_mesh(radius: f32, horizon: Color, zenith: Color) -> Mesh {
+    let mut mesh = Sphere::new(radius).mesh().uv(32, 16);
+    let horizon = horizon.to_linear();
+    let zenith = zenith.to_linear();
+
+    let colors: Vec<[f32; 4]> = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(|positions| positions.as_float3())
+        .unwrap_or_default()
+        .iter()
+        .map(|position| {
+            let height = (position[1] / radius).clamp(0.0, 1.0);
+            horizon.mix(&zenith, height.sqrt()).to_f32_array()
+        })
+        .collect();
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh
+}
+
+/// Spawn the sky dome on the main camera's layer only - it has no AsciiPatternId, so the
+/// pattern pass never sees it, but the ASCII pass still turns it into characters
+fn spawn_skybox(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = create_sky_mesh(SKYBOX_RADIUS, Color::srgb(0.35, 0.3, 0.45), Color::srgb(0.02, 0.02, 0.08));
+    commands.spawn((
+        Mesh3d(meshes.add(mesh)),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            // Vertex colors carry the gradient
+            base_color: Color::WHITE,
+            unlit: true,
+            // Seen from inside
+            cull_mode: None,
+            fog_enabled: false,
+            ..default()
+        })),
+        Transform::default(),
+        RenderLayers::layer(0),
+        Skybox,
+    ));
+}
+
+/// Spawn the loaded level file, or the built-in test arena without one
+fn spawn_level(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    level: Option<Res<LevelDef>>,
+) {
+    match level {
+        Some(level) => spawn_level_def(&mut commands, &mut meshes, &mut materials, &level),
+        None => spawn_test_level(&mut commands, &mut meshes, &mut materials),
+    }
+
+    // Ambient light
+    commands.insert_resource(AmbientLight {
+        color: Color::srgb(0.7, 0.75, 0.8),
+        brightness: 300.0,
+        ..default()
+    });
+}
+
+fn spawn_test_level(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
 ) {
     // Floor - big arena for testing bunny hop (Standard ASCII pattern)
     // Has a thin BoxCollider for projectile collision detection
@@ -310,25 +727,18 @@ fn spawn_test_level(
 
     for pos in pillar_positions {
         let half_height = pos.y;
-        let pillar_top = pos.y + half_height;
 
-        // Pillar body (blocks horizontal movement)
+        // Pillar body - round for movement, boxed for projectiles and raycasts.
+        // Its top is the floor surface for standing on.
         commands.spawn((
-            Mesh3d(meshes.add(Cuboid::new(2.0, half_height * 2.0, 2.0))),
+            Mesh3d(meshes.add(Cylinder::new(1.0, half_height * 2.0))),
             MeshMaterial3d(pillar_material.clone()),
             Transform::from_translation(pos),
             LevelGeometry,
             BoxCollider { half_extents: Vec3::new(1.0, half_height, 1.0) },
-            WallCollider,
+            CylinderCollider { radius: 1.0, half_height },
             AsciiPatternId::slashes(),
         ));
-
-        // Pillar top (floor surface for standing on)
-        commands.spawn((
-            Transform::from_xyz(pos.x, pillar_top, pos.z),
-            BoxCollider { half_extents: Vec3::new(1.0, 0.1, 1.0) },
-            // No WallCollider, no mesh - just collision for floor detection
-        ));
     }
 
     // Pattern showcase area - 4 cubes near spawn to compare all patterns
@@ -571,6 +981,42 @@ fn spawn_test_level(
         AsciiPatternId::matrix_cycle(),
     ));
 
+    // Jump pads: one straight up beside the spawn, one flinging toward the raised platform
+    spawn_jump_pad(commands, meshes, materials, Vec3::new(-6.0, 0.0, 4.0), Vec3::new(0.0, 14.0, 0.0));
+    spawn_jump_pad(commands, meshes, materials, Vec3::new(10.0, 0.0, 30.0), Vec3::new(9.0, 10.0, 0.0));
+
+    // Teleporter pair: arena floor <-> the raised platform, arriving facing away from the pad
+    spawn_teleporter(commands, meshes, materials, Teleporter { id: 0, target: 1 }, Vec3::new(-20.0, 0.0, -10.0), 0.0);
+    spawn_teleporter(
+        commands,
+        meshes,
+        materials,
+        Teleporter { id: 1, target: 0 },
+        Vec3::new(30.0, 3.25, 25.0),
+        std::f32::consts::PI,
+    );
+
+    // Ferry platform - low enough to step onto, shuttles along the north side of the arena
+    spawn_moving_platform(
+        commands,
+        meshes,
+        materials,
+        Vec3::new(2.0, 0.25, 2.0),
+        vec![Vec3::new(-25.0, 0.3, -45.0), Vec3::new(15.0, 0.3, -45.0)],
+        3.0,
+    );
+
+    // Breakable crates scattered as light cover - each drops a pickup
+    for position in [
+        Vec3::new(-12.0, 0.0, 12.0),
+        Vec3::new(-13.2, 0.0, 12.4),
+        Vec3::new(15.0, 0.0, -20.0),
+        Vec3::new(-35.0, 0.0, -5.0),
+        Vec3::new(30.0, 3.25, 36.0),
+    ] {
+        spawn_crate(commands, meshes, materials, position);
+    }
+
     // Multiple lights for the larger arena
     let light_positions = [
         Vec3::new(0.0, 15.0, 0.0),
@@ -591,11 +1037,193 @@ fn spawn_test_level(
             Transform::from_translation(pos),
         ));
     }
+}
 
-    // Ambient light
-    commands.insert_resource(AmbientLight {
-        color: Color::srgb(0.7, 0.75, 0.8),
-        brightness: 300.0,
-        ..default()
-    });
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::rendering::PATTERN_RENDER_LAYER;
+
+    // ==================== Skybox Tests ====================
+
+    #[test]
+    fn test_skybox_spawns_on_main_layer() {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .add_systems(Startup, spawn_skybox);
+        app.update();
+
+        let world = app.world_mut();
+        let (layers, has_pattern) = world
+            .query_filtered::<(&RenderLayers, Has<AsciiPatternId>), With<Skybox>>()
+            .single(world)
+            .unwrap();
+        assert!(layers.intersects(&RenderLayers::layer(0)));
+        assert!(!layers.intersects(&RenderLayers::layer(PATTERN_RENDER_LAYER)));
+        assert!(!has_pattern);
+    }
+
+    #[test]
+    fn test_sky_gradient_darkens_toward_zenith() {
+        let horizon = Color::srgb(0.4, 0.4, 0.4);
+        let zenith = Color::BLACK;
+        let mesh = create_sky_mesh(10.0, horizon, zenith);
+
+        let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION).unwrap().as_float3().unwrap();
+        let Some(bevy::render::mesh::VertexAttributeValues::Float32x4(colors)) = mesh.attribute(Mesh::ATTRIBUTE_COLOR)
+        else {
+            panic!("sky mesh has no vertex colors");
+        };
+        let color_at = |height: f32| {
+            let index = positions.iter().position(|position| (position[1] - height).abs() < 0.01).unwrap();
+            colors[index][0]
+        };
+
+        assert_eq!(color_at(10.0), 0.0);
+        assert!((color_at(-10.0) - horizon.to_linear().red).abs() < 0.001);
+    }
+
+    // ==================== Jump Pad Tests ====================
+
+    #[test]
+    fn test_slope_normal_faces_up_and_away_from_rise() {
+        let slope = Slope::rising_z(1.0);
+        let normal = slope.normal();
+        assert!((normal.length() - 1.0).abs() < 1e-5);
+        assert!(normal.y > 0.0 && normal.z < 0.0 && normal.x == 0.0);
+        assert!((normal.angle_between(Vec3::Y) - slope.rise_per_unit.atan()).abs() < 1e-5);
+        assert_eq!(Slope::rising_x(0.0).normal(), Vec3::Y);
+    }
+
+    #[test]
+    fn test_jump_pad_touches_top_only() {
+        let center = Vec3::new(0.0, 0.1, 0.0);
+        let half = JumpPad::HALF_EXTENTS;
+
+        assert!(JumpPad::touches(center, half, Vec3::new(0.5, 0.2, -1.0)));
+        // Beside the pad
+        assert!(!JumpPad::touches(center, half, Vec3::new(2.0, 0.2, 0.0)));
+        // Flying over it
+        assert!(!JumpPad::touches(center, half, Vec3::new(0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_jump_pad_launch() {
+        let velocity = Vec3::new(3.0, -2.0, 1.0);
+
+        // Straight up keeps horizontal momentum
+        let up = JumpPad { impulse: Vec3::new(0.0, 12.0, 0.0) };
+        assert_eq!(up.launch(velocity), Vec3::new(3.0, 12.0, 1.0));
+
+        // Angled pads set the whole velocity
+        let angled = JumpPad { impulse: Vec3::new(8.0, 10.0, 0.0) };
+        assert_eq!(angled.launch(velocity), angled.impulse);
+    }
+
+    // ==================== Teleporter Tests ====================
+
+    #[test]
+    fn test_teleporter_trigger_volume() {
+        let transform = Transform::from_xyz(5.0, 0.0, 5.0);
+
+        assert!(Teleporter::contains(&transform, Vec3::new(5.5, 0.9, 4.5)));
+        assert!(!Teleporter::contains(&transform, Vec3::new(7.0, 0.9, 5.0)));
+        assert!(!Teleporter::contains(&transform, Vec3::new(5.0, 4.0, 5.0)));
+    }
+
+    #[test]
+    fn test_teleporter_exit_in_front_and_outside_trigger() {
+        // Facing +X
+        let transform = Transform::from_xyz(0.0, 1.0, 0.0)
+            .with_rotation(Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2));
+
+        assert!((Teleporter::exit_direction(&transform) - Vec3::X).length() < 0.0001);
+        let exit = Teleporter::exit_point(&transform);
+        assert!((exit - Vec3::new(Teleporter::EXIT_DISTANCE, 1.0, 0.0)).length() < 0.0001);
+        // Arriving must not immediately trigger the exit pad
+        assert!(!Teleporter::contains(&transform, exit + Vec3::Y));
+    }
+
+    // ==================== Moving Platform Tests ====================
+
+    #[test]
+    fn test_moving_platform_steps_and_turns_at_waypoints() {
+        let mut platform = MovingPlatform::new(vec![Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0)], 2.0);
+
+        // Starts at the first waypoint, so the first step only turns around
+        let position = platform.step(Vec3::ZERO, 1.0);
+        assert_eq!(position, Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(platform.next, 1);
+
+        // Overshooting the end wraps the leftover travel back toward the start
+        let position = platform.step(position, 1.5);
+        assert_eq!(position, Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(platform.next, 0);
+    }
+
+    #[test]
+    fn test_moving_platform_carries_only_riders_on_top() {
+        let center = Vec3::new(0.0, 1.0, 0.0);
+        let half = Vec3::new(2.0, 0.25, 2.0);
+
+        assert!(MovingPlatform::carries(center, half, Vec3::new(1.0, 1.25, -1.0), 0.4));
+        // Standing beside it, or jumping over it
+        assert!(!MovingPlatform::carries(center, half, Vec3::new(3.0, 1.25, 0.0), 0.4));
+        assert!(!MovingPlatform::carries(center, half, Vec3::new(0.0, 2.5, 0.0), 0.4));
+    }
+
+    // ==================== Ray Tests ====================
+
+    #[test]
+    fn test_ray_hits_box_front_face() {
+        let collider = BoxCollider { half_extents: Vec3::splat(1.0) };
+        let hit = collider
+            .ray_intersection(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::NEG_Z, 100.0)
+            .expect("ray should hit the box");
+
+        assert!((hit.distance - 9.0).abs() < 0.0001);
+        assert_eq!(hit.normal, Vec3::Z);
+        assert!((hit.point - Vec3::new(0.0, 0.0, -9.0)).length() < 0.0001);
+    }
+
+    #[test]
+    fn test_ray_misses_box() {
+        let collider = BoxCollider { half_extents: Vec3::splat(1.0) };
+        let hit = collider.ray_intersection(Vec3::new(5.0, 0.0, -10.0), Vec3::ZERO, Vec3::NEG_Z, 100.0);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_ray_box_beyond_max_distance() {
+        let collider = BoxCollider { half_extents: Vec3::splat(1.0) };
+        let hit = collider.ray_intersection(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::NEG_Z, 5.0);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_ray_hits_side_face_normal() {
+        let collider = BoxCollider { half_extents: Vec3::new(0.5, 4.0, 10.0) };
+        let hit = collider
+            .ray_intersection(Vec3::new(10.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 3.0), Vec3::X, 100.0)
+            .expect("ray should hit the wall");
+
+        assert_eq!(hit.normal, Vec3::NEG_X);
+        assert!((hit.point.x - 9.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_line_of_sight_blocked_by_box_between() {
+        let pillar = (
+            Transform::from_xyz(0.0, 0.0, -5.0),
+            BoxCollider { half_extents: Vec3::splat(1.0) },
+        );
+        let colliders = [(&pillar.0, &pillar.1)];
+
+        assert!(!line_of_sight_clear(colliders, Vec3::ZERO, Vec3::new(0.0, 0.0, -10.0)));
+        // Box beyond the target doesn't block
+        assert!(line_of_sight_clear(colliders, Vec3::ZERO, Vec3::new(0.0, 0.0, -3.0)));
+        // Off to the side doesn't block
+        assert!(line_of_sight_clear(colliders, Vec3::new(5.0, 0.0, 0.0), Vec3::new(5.0, 0.0, -10.0)));
+    }
 }
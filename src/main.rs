@@ -1,13 +1,20 @@
 use bevy::prelude::*;
 
+mod audio;
 mod player;
 mod level;
 mod rendering;
 mod combat;
 mod enemies;
+mod menu;
+mod rng;
+mod dev;
+#[cfg(test)]
+mod test_utils;
 
 use combat::CombatPlugin;
 use enemies::EnemyPlugin;
+use player::{Action, ActionInput};
 
 /// Game states
 #[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
@@ -16,6 +23,10 @@ pub enum GameState {
     Menu,
     Playing,
     Paused,
+    /// Player died - death cam and game over overlay
+    GameOver,
+    /// Settings menu, opened from the main menu
+    Settings,
 }
 
 fn main() {
@@ -23,7 +34,7 @@ fn main() {
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "ASCII Boomer Shooter".into(),
-                resolution: (1280.0, 720.0).into(),
+                resolution: (rendering::WINDOWED_RESOLUTION.x, rendering::WINDOWED_RESOLUTION.y).into(),
                 ..default()
             }),
             ..default()
@@ -35,43 +46,90 @@ fn main() {
             rendering::AsciiRenderPlugin,
             CombatPlugin,
             EnemyPlugin,
+            menu::MenuPlugin,
+            dev::DevPlugin,
+            audio::GameAudioPlugin,
         ))
-        .init_resource::<rendering::AsciiPreset>()
-        .add_systems(Update, (
-            handle_game_state_input,
-            rendering::update_ascii_resolution,
-            rendering::update_ascii_time,
-            rendering::cycle_ascii_preset,
-            rendering::toggle_ascii_monochrome,
-            rendering::toggle_per_object_mode,
-            rendering::cycle_global_pattern,
-        ))
+        .init_resource::<rng::GameRng>()
+        // After rebind capture, which swallows the key it binds (or the Escape that cancels it)
+        .add_systems(Update, handle_game_state_input.after(player::capture_rebind))
         .run();
 }
 
 fn handle_game_state_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     current_state: Res<State<GameState>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
     match current_state.get() {
+        // Enter activates the focused menu button instead
         GameState::Menu => {
-            if keyboard.just_pressed(KeyCode::Space) || keyboard.just_pressed(KeyCode::Enter) {
+            if input.just_pressed(Action::Jump) {
                 next_state.set(GameState::Playing);
             }
         }
         GameState::Playing => {
-            if keyboard.just_pressed(KeyCode::Escape) {
+            if input.just_pressed(Action::Pause) {
                 next_state.set(GameState::Paused);
             }
         }
         GameState::Paused => {
-            if keyboard.just_pressed(KeyCode::Escape) {
+            if input.just_pressed(Action::Pause) {
                 next_state.set(GameState::Playing);
             }
-            if keyboard.just_pressed(KeyCode::KeyQ) {
+            if input.just_pressed(Action::QuitToMenu) {
+                next_state.set(GameState::Menu);
+            }
+        }
+        // The game over overlay's button returns to the menu
+        GameState::GameOver => {}
+        GameState::Settings => {
+            if input.just_pressed(Action::Pause) {
                 next_state.set(GameState::Menu);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    use combat::{DamageEvent, DeathEvent};
+    use enemies::Enemy;
+
+    /// Headless app with the combat and enemy plugins (no window or renderer)
+    fn combat_test_app() -> App {
+        let mut app = test_app();
+        app.with_game_state(GameState::Menu)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .with_mesh_assets()
+            .insert_resource(rng::GameRng::new(1))
+            .add_plugins((CombatPlugin, EnemyPlugin));
+        app
+    }
+
+    // ==================== Plugin Wiring Tests ====================
+
+    #[test]
+    fn test_combat_events_registered() {
+        let app = combat_test_app();
+
+        assert!(app.world().contains_resource::<Events<DamageEvent>>());
+        assert!(app.world().contains_resource::<Events<DeathEvent>>());
+    }
+
+    #[test]
+    fn test_enemies_spawn_at_startup() {
+        let mut app = combat_test_app();
+        app.update();
+
+        let enemies = app
+            .world_mut()
+            .query_filtered::<(), With<Enemy>>()
+            .iter(app.world())
+            .count();
+        assert!(enemies > 0);
+    }
+}
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+
+/// Button colors - focused buttons must always stand out from the rest
+pub const NORMAL_BUTTON: Color = Color::srgb(0.1, 0.1, 0.1);
+pub const FOCUSED_BUTTON: Color = Color::srgb(0.2, 0.45, 0.2);
+pub const NORMAL_BORDER: Color = Color::srgb(0.3, 0.3, 0.3);
+pub const FOCUSED_BORDER: Color = Color::srgb(0.4, 1.0, 0.4);
+
+/// Menu button that can receive keyboard/gamepad focus.
+/// Buttons are navigated in ascending `order`.
+#[derive(Component)]
+pub struct Focusable {
+    pub order: u32,
+}
+
+/// Spawn a labelled menu button under `parent`, tagged with `marker` for its click handler
+pub fn spawn_menu_button(parent: &mut ChildSpawnerCommands, label: &str, order: u32, marker: impl Component) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(24.0), Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(NORMAL_BUTTON),
+            BorderColor(NORMAL_BORDER),
+            Focusable { order },
+            marker,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Index (into the ordered focusable buttons) of the focused button
+#[derive(Resource, Default)]
+pub struct MenuFocus {
+    pub index: usize,
+}
+
+/// Move focus by `step` through `len` buttons, wrapping at both ends
+pub fn move_focus(current: usize, len: usize, step: i32) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (current as i64 + step as i64).rem_euclid(len as i64) as usize
+}
+
+/// Sort buttons into navigation order and return the focused one
+pub fn focused_button(buttons: &mut [(u32, Entity)], index: usize) -> Option<Entity> {
+    buttons.sort_by_key(|(order, _)| *order);
+    buttons.get(index.min(buttons.len().saturating_sub(1))).map(|(_, entity)| *entity)
+}
+
+/// Reset focus to the first button (when a menu opens)
+pub fn reset_menu_focus(mut focus: ResMut<MenuFocus>) {
+    focus.index = 0;
+}
+
+/// Move focus with arrows/WASD or the gamepad d-pad; mouse hover also takes focus
+pub fn navigate_menu_focus(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut focus: ResMut<MenuFocus>,
+    buttons: Query<(Entity, &Focusable, &Interaction)>,
+) {
+    let len = buttons.iter().len();
+    if len == 0 {
+        return;
+    }
+
+    let pressed = |keys: [KeyCode; 4], pad: [GamepadButton; 2]| {
+        keyboard.any_just_pressed(keys)
+            || gamepads.iter().any(|gamepad| gamepad.any_just_pressed(pad))
+    };
+
+    let mut step = 0;
+    if pressed(
+        [KeyCode::ArrowUp, KeyCode::KeyW, KeyCode::ArrowLeft, KeyCode::KeyA],
+        [GamepadButton::DPadUp, GamepadButton::DPadLeft],
+    ) {
+        step -= 1;
+    }
+    if pressed(
+        [KeyCode::ArrowDown, KeyCode::KeyS, KeyCode::ArrowRight, KeyCode::KeyD],
+        [GamepadButton::DPadDown, GamepadButton::DPadRight],
+    ) {
+        step += 1;
+    }
+
+    // Buttons may have been despawned since focus was last set
+    focus.index = move_focus(focus.index.min(len - 1), len, step);
+
+    // Hovering a button with the mouse moves focus to it
+    if step == 0 {
+        let mut ordered: Vec<_> = buttons.iter().map(|(e, f, i)| (f.order, e, *i)).collect();
+        ordered.sort_by_key(|(order, _, _)| *order);
+        if let Some(hovered) = ordered.iter().position(|(_, _, i)| *i == Interaction::Hovered) {
+            focus.index = hovered;
+        }
+    }
+}
+
+/// Activate the focused button with Enter or gamepad A.
+/// Activation is a simulated press, so click handlers don't need to know about focus.
+pub fn activate_focused_button(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    focus: Res<MenuFocus>,
+    mut buttons: Query<(Entity, &Focusable, &mut Interaction)>,
+) {
+    // Alt+Enter toggles fullscreen instead
+    let alt = keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    let activate = (keyboard.just_pressed(KeyCode::Enter) && !alt)
+        || gamepads.iter().any(|gamepad| gamepad.just_pressed(GamepadButton::South));
+    if !activate {
+        return;
+    }
+
+    let mut ordered: Vec<_> = buttons.iter().map(|(e, f, _)| (f.order, e)).collect();
+    let Some(focused) = focused_button(&mut ordered, focus.index) else {
+        return;
+    };
+
+    if let Ok((_, _, mut interaction)) = buttons.get_mut(focused) {
+        *interaction = Interaction::Pressed;
+    }
+}
+
+/// Highlight the focused button so it is always visually distinct
+pub fn highlight_focused_button(
+    focus: Res<MenuFocus>,
+    mut buttons: Query<(Entity, &Focusable, &mut BackgroundColor, &mut BorderColor)>,
+) {
+    let mut ordered: Vec<_> = buttons.iter().map(|(e, f, _, _)| (f.order, e)).collect();
+    let focused = focused_button(&mut ordered, focus.index);
+
+    for (entity, _, mut background, mut border) in &mut buttons {
+        let is_focused = Some(entity) == focused;
+        background.0 = if is_focused { FOCUSED_BUTTON } else { NORMAL_BUTTON };
+        border.0 = if is_focused { FOCUSED_BORDER } else { NORMAL_BORDER };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_focus_steps() {
+        assert_eq!(move_focus(0, 3, 1), 1);
+        assert_eq!(move_focus(1, 3, 1), 2);
+        assert_eq!(move_focus(2, 3, -1), 1);
+        assert_eq!(move_focus(1, 3, 0), 1);
+    }
+
+    #[test]
+    fn test_move_focus_wraps() {
+        assert_eq!(move_focus(2, 3, 1), 0);
+        assert_eq!(move_focus(0, 3, -1), 2);
+    }
+
+    #[test]
+    fn test_move_focus_empty_menu() {
+        assert_eq!(move_focus(0, 0, 1), 0);
+        assert_eq!(move_focus(0, 0, -1), 0);
+    }
+
+    #[test]
+    fn test_focused_button_uses_order_not_spawn_order() {
+        // Mock focus list: Quit spawned first but ordered last
+        let quit = Entity::from_raw(1);
+        let start = Entity::from_raw(2);
+        let settings = Entity::from_raw(3);
+        let mut buttons = vec![(2, quit), (0, start), (1, settings)];
+
+        assert_eq!(focused_button(&mut buttons, 0), Some(start));
+        assert_eq!(focused_button(&mut buttons, 1), Some(settings));
+        assert_eq!(focused_button(&mut buttons, 2), Some(quit));
+
+        // Walk down through the whole list and wrap back to the top
+        let mut index = 0;
+        let mut visited = Vec::new();
+        for _ in 0..4 {
+            visited.push(focused_button(&mut buttons, index).unwrap());
+            index = move_focus(index, buttons.len(), 1);
+        }
+        assert_eq!(visited, vec![start, settings, quit, start]);
+    }
+
+    #[test]
+    fn test_focused_button_clamps_stale_index() {
+        let only = Entity::from_raw(5);
+        let mut buttons = vec![(0, only)];
+        assert_eq!(focused_button(&mut buttons, 3), Some(only));
+        assert_eq!(focused_button(&mut [], 0), None);
+    }
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+
+use super::focus::spawn_menu_button;
+use crate::combat::RuleSet;
+use crate::GameState;
+
+/// Root of the game over overlay (despawned with all its children)
+#[derive(Component)]
+pub struct GameOverOverlay;
+
+/// Button that leaves game over for the main menu
+#[derive(Component)]
+pub struct ReturnToMenuButton;
+
+/// Darken the screen over the death cam, show which mode the run was played in,
+/// and offer a way back to the menu
+pub fn spawn_game_over_overlay(mut commands: Commands, ruleset: Res<RuleSet>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GameOverOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("GAME OVER"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.2, 0.2)),
+            ));
+
+            parent.spawn((
+                Text::new(format!("MODE: {}", ruleset.name())),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            spawn_menu_button(parent, "RETURN TO MENU", 0, ReturnToMenuButton);
+        });
+}
+
+pub fn despawn_game_over_overlay(mut commands: Commands, overlay_query: Query<Entity, With<GameOverOverlay>>) {
+    for entity in &overlay_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Click (or Enter/gamepad A via menu focus) returns to the menu
+pub fn handle_return_to_menu(
+    button_query: Query<&Interaction, With<ReturnToMenuButton>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if button_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Menu);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_shows_ruleset() {
+        let mut app = App::new();
+        app.insert_resource(RuleSet::Instagib)
+            .add_systems(Update, spawn_game_over_overlay);
+        app.update();
+
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<(), With<GameOverOverlay>>().iter(world).count(), 1);
+        assert_eq!(world.query_filtered::<(), With<ReturnToMenuButton>>().iter(world).count(), 1);
+        let texts: Vec<String> = world.query::<&Text>().iter(world).map(|text| text.0.clone()).collect();
+        assert!(texts.iter().any(|text| text == "MODE: INSTAGIB"), "overlay text: {texts:?}");
+    }
+}
@@ -0,0 +1,176 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::enemies::{Score, WaveState};
+
+/// One finished run on the high score table
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct HighScoreEntry {
+    pub score: u32,
+    /// Wave the run ended on
+    pub wave: u32,
+}
+
+/// Top scores across runs, saved as JSON in the user data directory
+#[derive(Resource, Default, Debug)]
+pub struct HighScores {
+    /// Best first
+    pub entries: Vec<HighScoreEntry>,
+    /// Where the table is saved - None keeps it in memory only
+    pub path: Option<PathBuf>,
+}
+
+impl HighScores {
+    pub const MAX_ENTRIES: usize = 10;
+
+    /// `<data dir>/ascii_shooter/high_scores.json`, if the platform's data directory is known
+    pub fn default_path() -> Option<PathBuf> {
+        user_data_dir().map(|dir| dir.join("ascii_shooter").join("high_scores.json"))
+    }
+
+    /// Read the table from `path` - a missing or corrupt file starts a fresh table
+    pub fn load(path: PathBuf) -> Self {
+        let entries = match std::fs::read_to_string(&path) {
+            Ok(source) => serde_json::from_str(&source).unwrap_or_else(|err| {
+                warn!("Ignoring corrupt high scores {}: {err}", path.display());
+                Vec::new()
+            }),
+            Err(_) => Vec::new(),
+        };
+
+        let mut high_scores = Self { entries: Vec::new(), path: Some(path) };
+        for entry in entries {
+            high_scores.insert(entry);
+        }
+        high_scores
+    }
+
+    /// Write the table to its path (creating the directory if needed)
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&self.entries).map_err(std::io::Error::other)?;
+        std::fs::write(path, json)
+    }
+
+    /// Add a run if it makes the table - returns its rank (0 = best)
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        if entry.score == 0 {
+            return None;
+        }
+        // Ties go below existing entries
+        let rank = self.entries.partition_point(|existing| existing.score >= entry.score);
+        if rank >= Self::MAX_ENTRIES {
+            return None;
+        }
+        self.entries.insert(rank, entry);
+        self.entries.truncate(Self::MAX_ENTRIES);
+        Some(rank)
+    }
+}
+
+/// Per-user data directory for the platform
+pub fn user_data_dir() -> Option<PathBuf> {
+    let env_dir = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+
+    if cfg!(target_os = "windows") {
+        env_dir("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| env_dir("HOME").map(|home| home.join(".local").join("share")))
+    }
+}
+
+pub fn load_high_scores(mut commands: Commands) {
+    let high_scores = match HighScores::default_path() {
+        Some(path) => HighScores::load(path),
+        None => {
+            warn!("No user data directory - high scores won't be saved");
+            HighScores::default()
+        }
+    };
+    commands.insert_resource(high_scores);
+}
+
+/// Put the finished run on the table and save it
+pub fn record_high_score(score: Res<Score>, wave: Res<WaveState>, mut high_scores: ResMut<HighScores>) {
+    let entry = HighScoreEntry { score: score.total, wave: wave.current_wave };
+    let Some(rank) = high_scores.insert(entry) else {
+        return;
+    };
+    info!("High score #{}: {}", rank + 1, entry.score);
+
+    if let Err(err) = high_scores.save() {
+        let path = high_scores.path.as_deref().unwrap_or(Path::new("?"));
+        warn!("Failed to save high scores to {}: {err}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(score: u32) -> HighScoreEntry {
+        HighScoreEntry { score, wave: 1 }
+    }
+
+    /// Fresh path under the system temp dir, never the real user data directory
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("ascii_shooter_test_{}", std::process::id()))
+            .join(name)
+    }
+
+    #[test]
+    fn test_insert_keeps_top_ten_in_order() {
+        let mut high_scores = HighScores::default();
+        for score in 1..=12 {
+            high_scores.insert(entry(score * 100));
+        }
+
+        assert_eq!(high_scores.entries.len(), HighScores::MAX_ENTRIES);
+        assert_eq!(high_scores.entries[0].score, 1200);
+        assert!(high_scores.entries.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        // Too low to make the table
+        assert_eq!(high_scores.insert(entry(100)), None);
+        assert_eq!(high_scores.insert(entry(1250)), Some(0));
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let path = temp_path("round_trip.json");
+        let mut high_scores = HighScores { path: Some(path.clone()), ..default() };
+        high_scores.insert(HighScoreEntry { score: 900, wave: 4 });
+        high_scores.insert(HighScoreEntry { score: 2500, wave: 7 });
+        high_scores.save().unwrap();
+
+        let loaded = HighScores::load(path.clone());
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.entries, high_scores.entries);
+        assert_eq!(loaded.entries[0].score, 2500);
+    }
+
+    #[test]
+    fn test_missing_or_corrupt_file_starts_fresh() {
+        let missing = HighScores::load(temp_path("missing.json"));
+        assert!(missing.entries.is_empty());
+
+        let path = temp_path("corrupt.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{ not json").unwrap();
+        let corrupt = HighScores::load(path.clone());
+        let _ = std::fs::remove_file(&path);
+
+        assert!(corrupt.entries.is_empty());
+        assert_eq!(corrupt.path, Some(path));
+    }
+}
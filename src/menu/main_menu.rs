@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+
+use super::focus::spawn_menu_button;
+use crate::player::{Action, InputMap};
+use crate::GameState;
+
+/// Root of the main menu (despawned with all its children)
+#[derive(Component)]
+pub struct MainMenu;
+
+/// Button that starts a run
+#[derive(Component)]
+pub struct StartButton;
+
+/// Button that opens the settings menu
+#[derive(Component)]
+pub struct SettingsButton;
+
+/// Button that closes the game
+#[derive(Component)]
+pub struct QuitButton;
+
+/// Title, Start/Settings/Quit buttons and a hint for the start key (the jump binding)
+pub fn spawn_main_menu(mut commands: Commands, input_map: Res<InputMap>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            MainMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("ASCII BOOMER SHOOTER"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 1.0, 0.4)),
+            ));
+
+            spawn_menu_button(parent, "START", 0, StartButton);
+            spawn_menu_button(parent, "SETTINGS", 1, SettingsButton);
+            spawn_menu_button(parent, "QUIT", 2, QuitButton);
+
+            parent.spawn((
+                Text::new(format!("{} to start", input_map.binding(Action::Jump).name())),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+            ));
+        });
+}
+
+pub fn despawn_main_menu(mut commands: Commands, menu_query: Query<Entity, With<MainMenu>>) {
+    for entity in &menu_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Click (or Enter/gamepad A via menu focus) starts the game, opens settings, or quits
+pub fn handle_main_menu_buttons(
+    start_query: Query<&Interaction, With<StartButton>>,
+    settings_query: Query<&Interaction, With<SettingsButton>>,
+    quit_query: Query<&Interaction, With<QuitButton>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if start_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Playing);
+    } else if settings_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Settings);
+    }
+    if quit_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        exit.write(AppExit::Success);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn menu_test_app() -> App {
+        let mut app = test_app();
+        app.with_game_state(GameState::Menu)
+            .with_input()
+            .add_systems(OnEnter(GameState::Menu), spawn_main_menu)
+            .add_systems(OnExit(GameState::Menu), despawn_main_menu)
+            .add_systems(Update, handle_main_menu_buttons.run_if(in_state(GameState::Menu)));
+        app.update();
+        app
+    }
+
+    fn press<T: Component>(app: &mut App) {
+        let world = app.world_mut();
+        let mut interaction = world.query_filtered::<&mut Interaction, With<T>>().single_mut(world).unwrap();
+        *interaction = Interaction::Pressed;
+    }
+
+    #[test]
+    fn test_clicking_start_begins_game() {
+        let mut app = menu_test_app();
+        press::<StartButton>(&mut app);
+
+        // Button handled this frame, state transition applied the next
+        app.update();
+        app.update();
+
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Playing);
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<(), With<MainMenu>>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn test_clicking_settings_opens_settings() {
+        let mut app = menu_test_app();
+        press::<SettingsButton>(&mut app);
+        app.update();
+        app.update();
+
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Settings);
+    }
+
+    #[test]
+    fn test_clicking_quit_exits() {
+        let mut app = menu_test_app();
+        press::<QuitButton>(&mut app);
+        app.update();
+
+        assert!(!app.world().resource::<Events<AppExit>>().is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::player::capture_rebind;
+use crate::GameState;
+
+pub mod focus;
+pub mod game_over;
+pub mod high_scores;
+pub mod main_menu;
+pub mod pause;
+pub mod settings;
+
+pub use focus::*;
+pub use game_over::*;
+pub use high_scores::*;
+pub use main_menu::*;
+pub use pause::*;
+pub use settings::*;
+
+/// Menu UI shared across menu states (main menu, pause, game over)
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuFocus>()
+            .add_systems(Startup, load_high_scores)
+            .add_systems(OnEnter(GameState::Menu), (reset_menu_focus, spawn_main_menu))
+            .add_systems(OnExit(GameState::Menu), despawn_main_menu)
+            .add_systems(OnEnter(GameState::Paused), (reset_menu_focus, spawn_pause_overlay))
+            .add_systems(OnExit(GameState::Paused), despawn_pause_overlay)
+            .add_systems(OnEnter(GameState::Settings), (reset_menu_focus, spawn_settings_menu))
+            .add_systems(OnExit(GameState::Settings), despawn_settings_menu)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (reset_menu_focus, spawn_game_over_overlay, record_high_score),
+            )
+            .add_systems(OnExit(GameState::GameOver), despawn_game_over_overlay)
+            .add_systems(
+                Update,
+                (
+                    // Capture first, so the key being bound doesn't also move focus or press a button
+                    capture_rebind.run_if(in_state(GameState::Settings)),
+                    navigate_menu_focus,
+                    activate_focused_button,
+                    highlight_focused_button,
+                    handle_main_menu_buttons.run_if(in_state(GameState::Menu)),
+                    handle_pause_buttons.run_if(in_state(GameState::Paused)),
+                    (handle_settings_buttons, update_setting_values).run_if(in_state(GameState::Settings)),
+                    handle_return_to_menu.run_if(in_state(GameState::GameOver)),
+                )
+                    .chain()
+                    .run_if(not(in_state(GameState::Playing))),
+            );
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use super::focus::spawn_menu_button;
+use crate::GameState;
+
+/// Root of the pause overlay (despawned with all its children)
+#[derive(Component)]
+pub struct PauseOverlay;
+
+/// Button that goes back into the game (same as Escape)
+#[derive(Component)]
+pub struct ResumeButton;
+
+/// Button that abandons the run for the main menu (same as Q)
+#[derive(Component)]
+pub struct QuitToMenuButton;
+
+/// Dim the frozen game and offer Resume / Quit to Menu
+pub fn spawn_pause_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            PauseOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("PAUSED"),
+                TextFont {
+                    font_size: 64.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            spawn_menu_button(parent, "RESUME", 0, ResumeButton);
+            spawn_menu_button(parent, "QUIT TO MENU", 1, QuitToMenuButton);
+        });
+}
+
+pub fn despawn_pause_overlay(mut commands: Commands, overlay_query: Query<Entity, With<PauseOverlay>>) {
+    for entity in &overlay_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Click (or Enter/gamepad A via menu focus) resumes or quits to the menu
+pub fn handle_pause_buttons(
+    resume_query: Query<&Interaction, With<ResumeButton>>,
+    quit_query: Query<&Interaction, With<QuitToMenuButton>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if resume_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Playing);
+    } else if quit_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Menu);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    /// Headless app already in the Paused state, overlay spawned
+    fn paused_test_app() -> App {
+        let mut app = test_app();
+        app.with_game_state(GameState::Paused)
+            .add_systems(OnEnter(GameState::Paused), spawn_pause_overlay)
+            .add_systems(OnExit(GameState::Paused), despawn_pause_overlay)
+            .add_systems(Update, handle_pause_buttons.run_if(in_state(GameState::Paused)));
+        app.update();
+        app
+    }
+
+    fn press<T: Component>(app: &mut App) {
+        let world = app.world_mut();
+        let mut interaction = world.query_filtered::<&mut Interaction, With<T>>().single_mut(world).unwrap();
+        *interaction = Interaction::Pressed;
+    }
+
+    fn state(app: &App) -> GameState {
+        *app.world().resource::<State<GameState>>().get()
+    }
+
+    #[test]
+    fn test_clicking_resume_returns_to_game() {
+        let mut app = paused_test_app();
+        press::<ResumeButton>(&mut app);
+        app.update();
+        app.update();
+
+        assert_eq!(state(&app), GameState::Playing);
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<(), With<PauseOverlay>>().iter(world).count(), 0);
+    }
+
+    #[test]
+    fn test_clicking_quit_returns_to_menu() {
+        let mut app = paused_test_app();
+        press::<QuitToMenuButton>(&mut app);
+        app.update();
+        app.update();
+
+        assert_eq!(state(&app), GameState::Menu);
+    }
+}
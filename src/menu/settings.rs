@@ -0,0 +1,351 @@
+use bevy::prelude::*;
+
+use super::focus::spawn_menu_button;
+use crate::enemies::Difficulty;
+use crate::player::{InputMap, LookConfig, Rebinding};
+use crate::rendering::AsciiPreset;
+use crate::GameState;
+
+/// Options the settings menu can change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Setting {
+    Sensitivity,
+    Fov,
+    AsciiPreset,
+    Difficulty,
+    /// Which action the REBIND button rebinds
+    Controls,
+}
+
+impl Setting {
+    /// Menu rows, top to bottom
+    pub const ALL: [Setting; 5] = [
+        Setting::Sensitivity,
+        Setting::Fov,
+        Setting::AsciiPreset,
+        Setting::Difficulty,
+        Setting::Controls,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Setting::Sensitivity => "SENSITIVITY",
+            Setting::Fov => "FOV",
+            Setting::AsciiPreset => "ASCII PRESET",
+            Setting::Difficulty => "DIFFICULTY",
+            Setting::Controls => "CONTROLS",
+        }
+    }
+
+    /// Current value as shown in the menu
+    pub fn value_text(
+        self,
+        look: &LookConfig,
+        preset: AsciiPreset,
+        difficulty: Difficulty,
+        input_map: &InputMap,
+        rebinding: &Rebinding,
+    ) -> String {
+        match self {
+            Setting::Sensitivity => format!("{:.5}", look.sensitivity),
+            Setting::Fov => format!("{:.0}", look.fov_degrees),
+            Setting::AsciiPreset => preset.name().to_string(),
+            Setting::Difficulty => difficulty.name().to_string(),
+            Setting::Controls if rebinding.capturing => format!("{}: PRESS A KEY", rebinding.selected.name()),
+            Setting::Controls => {
+                format!("{}: {}", rebinding.selected.name(), input_map.binding(rebinding.selected).name())
+            }
+        }
+    }
+}
+
+/// Root of the settings menu (despawned with all its children)
+#[derive(Component)]
+pub struct SettingsMenu;
+
+/// `<` / `>` button that steps a setting down or up
+#[derive(Component)]
+pub struct SettingStepper {
+    pub setting: Setting,
+    /// -1 or +1
+    pub direction: i32,
+}
+
+/// Text showing a setting's current value
+#[derive(Component)]
+pub struct SettingValue(pub Setting);
+
+/// Button that binds the next key or mouse button pressed to the selected action
+#[derive(Component)]
+pub struct RebindButton;
+
+/// Button that goes back to the main menu (same as Escape)
+#[derive(Component)]
+pub struct BackButton;
+
+/// One row per setting (label, `<`, value, `>`), then Rebind and Back buttons
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    look: Res<LookConfig>,
+    preset: Res<AsciiPreset>,
+    difficulty: Res<Difficulty>,
+    input_map: Res<InputMap>,
+    rebinding: Res<Rebinding>,
+) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            SettingsMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("SETTINGS"),
+                TextFont {
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.4, 1.0, 0.4)),
+            ));
+
+            // Steppers are focused left to right, row by row
+            for (row, setting) in Setting::ALL.into_iter().enumerate() {
+                let order = row as u32 * 2;
+                parent
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(12.0),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Node {
+                                width: Val::Px(200.0),
+                                ..default()
+                            },
+                            Text::new(setting.label()),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        spawn_menu_button(row, "<", order, SettingStepper { setting, direction: -1 });
+                        row.spawn((
+                            Node {
+                                width: Val::Px(200.0),
+                                justify_content: JustifyContent::Center,
+                                ..default()
+                            },
+                            Text::new(setting.value_text(&look, *preset, *difficulty, &input_map, &rebinding)),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextLayout::new_with_justify(JustifyText::Center),
+                            TextColor(Color::srgb(1.0, 0.9, 0.3)),
+                            SettingValue(setting),
+                        ));
+                        spawn_menu_button(row, ">", order + 1, SettingStepper { setting, direction: 1 });
+                    });
+            }
+
+            spawn_menu_button(parent, "REBIND", Setting::ALL.len() as u32 * 2, RebindButton);
+            spawn_menu_button(parent, "BACK", Setting::ALL.len() as u32 * 2 + 1, BackButton);
+        });
+}
+
+pub fn despawn_settings_menu(mut commands: Commands, menu_query: Query<Entity, With<SettingsMenu>>) {
+    for entity in &menu_query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Steppers change their setting once per press, Rebind waits for a key (see `capture_rebind`),
+/// Back returns to the main menu
+#[allow(clippy::too_many_arguments)]
+pub fn handle_settings_buttons(
+    stepper_query: Query<(&Interaction, &SettingStepper), Changed<Interaction>>,
+    rebind_query: Query<&Interaction, (With<RebindButton>, Changed<Interaction>)>,
+    back_query: Query<&Interaction, With<BackButton>>,
+    mut look: ResMut<LookConfig>,
+    mut preset: ResMut<AsciiPreset>,
+    mut difficulty: ResMut<Difficulty>,
+    mut rebinding: ResMut<Rebinding>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, stepper) in &stepper_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let steps = stepper.direction as f32;
+        match stepper.setting {
+            Setting::Sensitivity => look.adjust_sensitivity(steps),
+            Setting::Fov => look.adjust_fov(steps),
+            Setting::AsciiPreset => {
+                *preset = if stepper.direction < 0 { preset.prev() } else { preset.next() };
+            }
+            Setting::Difficulty => {
+                *difficulty = if stepper.direction < 0 { difficulty.prev() } else { difficulty.next() };
+            }
+            Setting::Controls => {
+                let selected = rebinding.selected;
+                rebinding.selected = if stepper.direction < 0 { selected.prev() } else { selected.next() };
+                rebinding.capturing = false;
+            }
+        }
+    }
+
+    if rebind_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        rebinding.capturing = true;
+    }
+
+    if back_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        next_state.set(GameState::Menu);
+    }
+}
+
+/// Keep the value texts in sync with the resources
+pub fn update_setting_values(
+    look: Res<LookConfig>,
+    preset: Res<AsciiPreset>,
+    difficulty: Res<Difficulty>,
+    input_map: Res<InputMap>,
+    rebinding: Res<Rebinding>,
+    mut value_query: Query<(&mut Text, &SettingValue)>,
+) {
+    for (mut text, value) in &mut value_query {
+        let current = value.0.value_text(&look, *preset, *difficulty, &input_map, &rebinding);
+        if **text != current {
+            **text = current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::player::{capture_rebind, Action, Binding};
+
+    /// Headless app already in the Settings state, menu spawned
+    fn settings_test_app() -> App {
+        let mut app = test_app();
+        app.with_game_state(GameState::Settings)
+            .init_resource::<LookConfig>()
+            .init_resource::<AsciiPreset>()
+            .init_resource::<Difficulty>()
+            .with_input()
+            .init_resource::<Rebinding>()
+            .add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
+            .add_systems(OnExit(GameState::Settings), despawn_settings_menu)
+            .add_systems(
+                Update,
+                (capture_rebind, handle_settings_buttons, update_setting_values)
+                    .chain()
+                    .run_if(in_state(GameState::Settings)),
+            );
+        app.update();
+        app
+    }
+
+    fn press_stepper(app: &mut App, setting: Setting, direction: i32) {
+        let world = app.world_mut();
+        let mut query = world.query::<(&mut Interaction, &SettingStepper)>();
+        let (mut interaction, _) = query
+            .iter_mut(world)
+            .find(|(_, stepper)| stepper.setting == setting && stepper.direction == direction)
+            .unwrap();
+        *interaction = Interaction::Pressed;
+    }
+
+    fn value_text(app: &mut App, setting: Setting) -> String {
+        let world = app.world_mut();
+        let mut query = world.query::<(&Text, &SettingValue)>();
+        query.iter(world).find(|(_, value)| value.0 == setting).unwrap().0.0.clone()
+    }
+
+    #[test]
+    fn test_sensitivity_stepper_changes_look_config() {
+        let mut app = settings_test_app();
+        let before = app.world().resource::<LookConfig>().sensitivity;
+
+        press_stepper(&mut app, Setting::Sensitivity, 1);
+        app.update();
+
+        let after = app.world().resource::<LookConfig>().sensitivity;
+        assert!(after > before);
+        assert_eq!(value_text(&mut app, Setting::Sensitivity), format!("{after:.5}"));
+
+        // A held press only steps once
+        app.update();
+        assert_eq!(app.world().resource::<LookConfig>().sensitivity, after);
+    }
+
+    #[test]
+    fn test_fov_and_preset_steppers() {
+        let mut app = settings_test_app();
+        press_stepper(&mut app, Setting::Fov, -1);
+        press_stepper(&mut app, Setting::AsciiPreset, 1);
+        app.update();
+
+        let fov = app.world().resource::<LookConfig>().fov_degrees;
+        assert_eq!(fov, LookConfig::default().fov_degrees - LookConfig::FOV_STEP);
+        assert_eq!(*app.world().resource::<AsciiPreset>(), AsciiPreset::default().next());
+    }
+
+    #[test]
+    fn test_difficulty_stepper() {
+        let mut app = settings_test_app();
+        press_stepper(&mut app, Setting::Difficulty, 1);
+        app.update();
+
+        assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+        assert_eq!(value_text(&mut app, Setting::Difficulty), "HARD");
+    }
+
+    #[test]
+    fn test_rebind_button_captures_next_key() {
+        let mut app = settings_test_app();
+        assert_eq!(value_text(&mut app, Setting::Controls), "JUMP: SPACE");
+        press_stepper(&mut app, Setting::Controls, -1);
+        app.update();
+        assert_eq!(app.world().resource::<Rebinding>().selected, Action::MoveRight);
+        assert_eq!(value_text(&mut app, Setting::Controls), "RIGHT: D");
+
+        let world = app.world_mut();
+        let mut interaction = world.query_filtered::<&mut Interaction, With<RebindButton>>().single_mut(world).unwrap();
+        *interaction = Interaction::Pressed;
+        app.update();
+        assert!(app.world().resource::<Rebinding>().capturing);
+        assert_eq!(value_text(&mut app, Setting::Controls), "RIGHT: PRESS A KEY");
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyG);
+        app.update();
+        let bound = app.world().resource::<InputMap>().binding(Action::MoveRight);
+        assert_eq!(bound, Binding::Key(KeyCode::KeyG));
+        assert_eq!(value_text(&mut app, Setting::Controls), "RIGHT: G");
+    }
+
+    #[test]
+    fn test_back_returns_to_menu() {
+        let mut app = settings_test_app();
+        let world = app.world_mut();
+        let mut interaction = world.query_filtered::<&mut Interaction, With<BackButton>>().single_mut(world).unwrap();
+        *interaction = Interaction::Pressed;
+        app.update();
+        app.update();
+
+        assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Menu);
+        let world = app.world_mut();
+        assert_eq!(world.query_filtered::<(), With<SettingsMenu>>().iter(world).count(), 0);
+    }
+}
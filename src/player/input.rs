@@ -1,2 +1,305 @@
-// Player input handling will be implemented here
-// Clean input abstraction for movement and combat
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+/// Logical game actions - systems ask for these instead of hardcoding keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveForward,
+    MoveBack,
+    MoveLeft,
+    MoveRight,
+    Jump,
+    Crouch,
+    Dash,
+    Fire,
+    Aim,
+    Reload,
+    Weapon1,
+    Weapon2,
+    Weapon3,
+    Weapon4,
+    Weapon5,
+    /// Pause / resume, and back out of the settings menu
+    Pause,
+    /// Quit to the main menu while paused
+    QuitToMenu,
+    /// Cut the rest between waves short
+    SkipRest,
+}
+
+impl Action {
+    /// Every action, in the order the settings menu lists them
+    pub const ALL: [Action; 18] = [
+        Action::MoveForward,
+        Action::MoveBack,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Jump,
+        Action::Crouch,
+        Action::Dash,
+        Action::Fire,
+        Action::Aim,
+        Action::Reload,
+        Action::Weapon1,
+        Action::Weapon2,
+        Action::Weapon3,
+        Action::Weapon4,
+        Action::Weapon5,
+        Action::Pause,
+        Action::QuitToMenu,
+        Action::SkipRest,
+    ];
+
+    /// Weapon slot actions, by inventory index
+    pub const WEAPON_SLOTS: [Action; 5] =
+        [Action::Weapon1, Action::Weapon2, Action::Weapon3, Action::Weapon4, Action::Weapon5];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::MoveForward => "FORWARD",
+            Action::MoveBack => "BACK",
+            Action::MoveLeft => "LEFT",
+            Action::MoveRight => "RIGHT",
+            Action::Jump => "JUMP",
+            Action::Crouch => "CROUCH",
+            Action::Dash => "DASH",
+            Action::Fire => "FIRE",
+            Action::Aim => "AIM",
+            Action::Reload => "RELOAD",
+            Action::Weapon1 => "WEAPON 1",
+            Action::Weapon2 => "WEAPON 2",
+            Action::Weapon3 => "WEAPON 3",
+            Action::Weapon4 => "WEAPON 4",
+            Action::Weapon5 => "WEAPON 5",
+            Action::Pause => "PAUSE",
+            Action::QuitToMenu => "QUIT TO MENU",
+            Action::SkipRest => "SKIP REST",
+        }
+    }
+
+    /// Cycle to the next action (settings menu stepper)
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&action| action == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Cycle to the previous action
+    pub fn prev(self) -> Self {
+        let index = Self::ALL.iter().position(|&action| action == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Out-of-the-box binding
+    pub fn default_binding(&self) -> Binding {
+        match self {
+            Action::MoveForward => Binding::Key(KeyCode::KeyW),
+            Action::MoveBack => Binding::Key(KeyCode::KeyS),
+            Action::MoveLeft => Binding::Key(KeyCode::KeyA),
+            Action::MoveRight => Binding::Key(KeyCode::KeyD),
+            Action::Jump => Binding::Key(KeyCode::Space),
+            Action::Crouch => Binding::Key(KeyCode::ControlLeft),
+            Action::Dash => Binding::Key(KeyCode::ShiftLeft),
+            Action::Fire => Binding::Mouse(MouseButton::Left),
+            Action::Aim => Binding::Mouse(MouseButton::Right),
+            Action::Reload => Binding::Key(KeyCode::KeyR),
+            Action::Weapon1 => Binding::Key(KeyCode::Digit1),
+            Action::Weapon2 => Binding::Key(KeyCode::Digit2),
+            Action::Weapon3 => Binding::Key(KeyCode::Digit3),
+            Action::Weapon4 => Binding::Key(KeyCode::Digit4),
+            Action::Weapon5 => Binding::Key(KeyCode::Digit5),
+            Action::Pause => Binding::Key(KeyCode::Escape),
+            Action::QuitToMenu => Binding::Key(KeyCode::KeyQ),
+            Action::SkipRest => Binding::Key(KeyCode::KeyN),
+        }
+    }
+}
+
+/// A key or mouse button an action is bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl Binding {
+    /// Short display name, e.g. "W", "SPACE", "MOUSE LEFT"
+    pub fn name(&self) -> String {
+        match self {
+            Binding::Key(key) => {
+                let name = format!("{key:?}");
+                let name = name.strip_prefix("Key").or(name.strip_prefix("Digit")).unwrap_or(&name);
+                name.to_uppercase()
+            }
+            Binding::Mouse(button) => format!("MOUSE {button:?}").to_uppercase(),
+        }
+    }
+}
+
+/// Which key or mouse button triggers each action
+#[derive(Resource)]
+pub struct InputMap {
+    bindings: HashMap<Action, Binding>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self {
+            bindings: Action::ALL
+                .into_iter()
+                .map(|action| (action, action.default_binding()))
+                .collect(),
+        }
+    }
+}
+
+impl InputMap {
+    pub fn binding(&self, action: Action) -> Binding {
+        self.bindings.get(&action).copied().unwrap_or(action.default_binding())
+    }
+
+    /// Bind an action - an action already using that input takes over this one's old binding,
+    /// so nothing ends up with two actions or none
+    pub fn bind(&mut self, action: Action, binding: Binding) {
+        let previous = self.binding(action);
+        if let Some(other) = Action::ALL
+            .into_iter()
+            .find(|&other| other != action && self.binding(other) == binding)
+        {
+            self.bindings.insert(other, previous);
+        }
+        self.bindings.insert(action, binding);
+    }
+
+    pub fn pressed(&self, action: Action, keyboard: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self.binding(action) {
+            Binding::Key(key) => keyboard.pressed(key),
+            Binding::Mouse(button) => mouse.pressed(button),
+        }
+    }
+
+    pub fn just_pressed(
+        &self,
+        action: Action,
+        keyboard: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        match self.binding(action) {
+            Binding::Key(key) => keyboard.just_pressed(key),
+            Binding::Mouse(button) => mouse.just_pressed(button),
+        }
+    }
+}
+
+/// Input map plus the keyboard and mouse state it reads - the system param gameplay systems take
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    map: Res<'w, InputMap>,
+    keyboard: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+}
+
+impl ActionInput<'_> {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.map.pressed(action, &self.keyboard, &self.mouse)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.map.just_pressed(action, &self.keyboard, &self.mouse)
+    }
+}
+
+/// Settings menu rebind flow - the selected action, and whether the next press gets bound to it
+#[derive(Resource)]
+pub struct Rebinding {
+    pub selected: Action,
+    pub capturing: bool,
+}
+
+impl Default for Rebinding {
+    fn default() -> Self {
+        Self {
+            selected: Action::Jump,
+            capturing: false,
+        }
+    }
+}
+
+/// While capturing, bind the next key or mouse button pressed to the selected action
+/// (Escape cancels). The press is consumed so later systems don't also act on it.
+pub fn capture_rebind(
+    mut rebinding: ResMut<Rebinding>,
+    mut map: ResMut<InputMap>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+) {
+    if !rebinding.capturing {
+        return;
+    }
+
+    if keyboard.clear_just_pressed(KeyCode::Escape) {
+        rebinding.capturing = false;
+        return;
+    }
+
+    let pressed = keyboard
+        .get_just_pressed()
+        .next()
+        .map(|&key| Binding::Key(key))
+        .or_else(|| mouse.get_just_pressed().next().map(|&button| Binding::Mouse(button)));
+    let Some(binding) = pressed else {
+        return;
+    };
+
+    match binding {
+        Binding::Key(key) => keyboard.clear_just_pressed(key),
+        Binding::Mouse(button) => mouse.clear_just_pressed(button),
+    };
+    map.bind(rebinding.selected, binding);
+    rebinding.capturing = false;
+    info!("{} bound to {}", rebinding.selected.name(), binding.name());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_swaps_conflicting_action() {
+        let mut map = InputMap::default();
+        map.bind(Action::Jump, Binding::Key(KeyCode::KeyW));
+
+        assert_eq!(map.binding(Action::Jump), Binding::Key(KeyCode::KeyW));
+        // Forward takes Jump's old key rather than sharing W
+        assert_eq!(map.binding(Action::MoveForward), Binding::Key(KeyCode::Space));
+    }
+
+    #[test]
+    fn test_capture_binds_next_press_and_consumes_it() {
+        let mut app = App::new();
+        app.init_resource::<InputMap>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .insert_resource(Rebinding {
+                selected: Action::Fire,
+                capturing: true,
+            })
+            .add_systems(Update, capture_rebind);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyF);
+        app.update();
+
+        assert_eq!(app.world().resource::<InputMap>().binding(Action::Fire), Binding::Key(KeyCode::KeyF));
+        assert!(!app.world().resource::<Rebinding>().capturing);
+        assert!(!app.world().resource::<ButtonInput<KeyCode>>().just_pressed(KeyCode::KeyF));
+    }
+
+    #[test]
+    fn test_binding_names() {
+        assert_eq!(Binding::Key(KeyCode::KeyW).name(), "W");
+        assert_eq!(Binding::Key(KeyCode::Digit3).name(), "3");
+        assert_eq!(Binding::Key(KeyCode::Space).name(), "SPACE");
+        assert_eq!(Binding::Mouse(MouseButton::Left).name(), "MOUSE LEFT");
+    }
+}
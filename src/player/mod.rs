@@ -5,45 +5,85 @@ use bevy::input::mouse::MouseMotion;
 use bevy::window::{CursorGrabMode, WindowFocused};
 
 use crate::GameState;
-use crate::level::{BoxCollider, GroundFloor, Slope, WallCollider};
-use crate::rendering::AsciiSettings;
-use crate::combat::{DamageFlash, Health, Weapon, WeaponInventory, AmmoHud, WeaponHud};
+use crate::level::{BoxCollider, CylinderCollider, GroundFloor, JumpPad, MovingPlatform, Slope, Teleporter, WallCollider};
+use crate::rendering::{AsciiPreset, AsciiSettings};
+use crate::combat::{Armor, DamageEvent, DamageFlash, DeathEvent, Faction, Health, RuleSet, SwordLunge, WeaponInventory, WeaponStats, WeaponType, AmmoHud, WeaponHud};
 
 pub mod movement;
 pub mod input;
+pub mod noclip;
+pub mod tuning;
 
+pub use input::{capture_rebind, Action, ActionInput, Binding, InputMap, Rebinding};
 use movement::*;
+use noclip::*;
+use tuning::*;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MovementConfig>()
-            .add_systems(Startup, (spawn_player, spawn_player_hud))
+            .init_resource::<GravityConfig>()
+            .init_resource::<MovementTuning>()
+            .init_resource::<LookConfig>()
+            .init_resource::<FogConfig>()
+            .init_resource::<Hitmarker>()
+            .init_resource::<InputMap>()
+            .init_resource::<Rebinding>()
+            .add_event::<FootstepEvent>()
+            .add_event::<LandingEvent>()
+            .add_systems(Startup, ((spawn_player, sync_camera_fog).chain(), spawn_player_hud))
+            .add_systems(Update, sync_camera_fog)
             .add_systems(OnEnter(GameState::Playing), grab_cursor)
+            .add_systems(
+                OnTransition {
+                    exited: GameState::Menu,
+                    entered: GameState::Playing,
+                },
+                reset_player_position,
+            )
             .add_systems(OnEnter(GameState::Paused), release_cursor)
             .add_systems(OnEnter(GameState::Menu), release_cursor)
+            .add_systems(OnEnter(GameState::GameOver), release_cursor)
+            .add_systems(OnExit(GameState::GameOver), end_death_cam)
+            .add_systems(Update, (toggle_noclip, fly_noclip_camera).chain())
             .add_systems(
                 Update,
                 (
                     handle_window_focus,
-                    player_look,      // Update camera angles FIRST
-                    player_input,     // Then calculate wish_dir from updated angles
-                    ground_check,
-                    player_movement,
+                    player_look.run_if(not_in_death_cam).run_if(not_in_noclip),  // Update camera angles FIRST
+                    player_input.run_if(not_in_death_cam).run_if(not_in_noclip), // Then calculate wish_dir from updated angles
+                    update_crouch,
+                    (ride_moving_platforms, ground_check, apply_jump_pads).chain(),
+                    start_mantle,
+                    update_mantle,
+                    (player_movement, player_dash).chain(),
                     apply_gravity,
                     player_collision,
-                    apply_velocity,
-                    update_view_sway,
+                    (apply_velocity, use_teleporters).chain(),
+                    update_ads,
+                    update_view_sway.run_if(not_in_death_cam).run_if(not_in_noclip),
                     update_velocity_hud,
                     update_health_hud,
                     update_weapon_hud,
                     update_ammo_hud,
+                    update_ruleset_hud,
                     update_crosshair,
                     check_player_death,
                 )
                     .chain()
                     .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (tune_movement, adjust_look, update_hitmarker, update_armor_hud).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (check_player_death, update_death_cam)
+                    .chain()
+                    .run_if(in_state(GameState::GameOver)),
             );
     }
 }
@@ -55,6 +95,10 @@ pub struct Player;
 pub struct PlayerCamera {
     pub pitch: f32,
     pub yaw: f32,
+    /// Extra upward pitch from weapon recoil, on top of the look pitch
+    pub recoil: f32,
+    /// Rate of change of `recoil` for the return spring
+    pub recoil_velocity: f32,
 }
 
 impl Default for PlayerCamera {
@@ -62,10 +106,37 @@ impl Default for PlayerCamera {
         Self {
             pitch: 0.0,
             yaw: 0.0,
+            recoil: 0.0,
+            recoil_velocity: 0.0,
         }
     }
 }
 
+/// Angular frequency of the recoil return spring - higher snaps back faster
+const RECOIL_SPRING: f32 = 14.0;
+/// Most recoil that can stack up from sustained fire
+const MAX_RECOIL: f32 = 0.25;
+
+impl PlayerCamera {
+    /// Kick the view upward by a weapon's recoil
+    pub fn kick(&mut self, amount: f32) {
+        self.recoil = (self.recoil + amount).min(MAX_RECOIL);
+    }
+
+    /// Pull recoil back to zero with a critically damped spring (exact step, stable for any dt)
+    pub fn settle_recoil(&mut self, dt: f32) {
+        let decay = (-RECOIL_SPRING * dt).exp();
+        let slope = self.recoil_velocity + RECOIL_SPRING * self.recoil;
+        self.recoil = (self.recoil + slope * dt) * decay;
+        self.recoil_velocity = (self.recoil_velocity - RECOIL_SPRING * slope * dt) * decay;
+    }
+
+    /// Pitch the camera is drawn at - look pitch plus recoil
+    pub fn view_pitch(&self) -> f32 {
+        (self.pitch + self.recoil).clamp(-1.5, 1.5)
+    }
+}
+
 /// Tracks view effects: bob, sway, landing impact
 #[derive(Component)]
 pub struct ViewSway {
@@ -75,6 +146,8 @@ pub struct ViewSway {
     pub velocity_tilt: Vec2,  // Roll and pitch from velocity
     pub prev_grounded: bool,
     pub prev_velocity_y: f32,
+    /// Camera height above the player's center - eases down when crouching
+    pub eye_height: f32,
 }
 
 impl Default for ViewSway {
@@ -86,20 +159,166 @@ impl Default for ViewSway {
             velocity_tilt: Vec2::ZERO,
             prev_grounded: true,
             prev_velocity_y: 0.0,
+            eye_height: MovementConfig::default().player_height / 2.0 - 0.1,
         }
     }
 }
 
+/// View bob phase per footstep - the vertical bob |sin(2t)| dips once every PI/2
+pub const FOOTSTEP_PHASE: f32 = std::f32::consts::FRAC_PI_2;
+
+/// Sent on every footstep while walking on the ground
+#[derive(Event)]
+pub struct FootstepEvent;
+
+/// Sent when the player lands after falling
+#[derive(Event)]
+pub struct LandingEvent {
+    /// Downward speed just before landing (m/s)
+    pub fall_speed: f32,
+}
+
 /// Marker for the viewmodel (arms/weapon)
 #[derive(Component)]
 pub struct ViewModel;
 
-const MOUSE_SENSITIVITY: f32 = 0.0004;
+/// Default field of view when not aiming (see LookConfig::fov_degrees)
+const BASE_FOV_DEGREES: f32 = 100.0;
+/// Field of view when fully aimed down sights
+const ADS_FOV_DEGREES: f32 = 55.0;
+/// How fast ADS blends in/out (full transition in 1/ADS_SPEED seconds)
+const ADS_SPEED: f32 = 8.0;
+
+/// Aim-down-sights state - right click with the machinegun zooms in and tightens spread
+#[derive(Component, Default)]
+pub struct Ads {
+    pub active: bool,
+    /// Blend from hip (0.0) to fully aimed (1.0)
+    pub t: f32,
+}
+
+impl Ads {
+    /// Move the blend toward the active state
+    pub fn update(&mut self, active: bool, dt: f32) {
+        self.active = active;
+        let target = if active { 1.0 } else { 0.0 };
+        self.t = if self.t < target {
+            (self.t + ADS_SPEED * dt).min(target)
+        } else {
+            (self.t - ADS_SPEED * dt).max(target)
+        };
+    }
+
+    /// Current field of view in radians, blending from the hip-fire `base_degrees`
+    pub fn fov(&self, base_degrees: f32) -> f32 {
+        base_degrees.lerp(ADS_FOV_DEGREES, self.t).to_radians()
+    }
+
+    /// Multiplier on weapon spread - aiming is much tighter
+    pub fn spread_multiplier(&self) -> f32 {
+        1.0 - 0.8 * self.t
+    }
+}
+
+/// Mouse look settings - PageUp/PageDown change sensitivity, F7 toggles inverted Y
+#[derive(Resource)]
+pub struct LookConfig {
+    /// Radians of rotation per pixel of mouse motion
+    pub sensitivity: f32,
+    pub invert_y: bool,
+    /// Hip-fire field of view
+    pub fov_degrees: f32,
+}
+
+impl LookConfig {
+    pub const SENSITIVITY_STEP: f32 = 0.00005;
+    pub const MIN_SENSITIVITY: f32 = 0.00005;
+    pub const MAX_SENSITIVITY: f32 = 0.003;
+    pub const FOV_STEP: f32 = 5.0;
+    pub const MIN_FOV: f32 = 70.0;
+    pub const MAX_FOV: f32 = 120.0;
+
+    /// Step sensitivity by `steps` increments, staying within bounds
+    pub fn adjust_sensitivity(&mut self, steps: f32) {
+        self.sensitivity = (self.sensitivity + Self::SENSITIVITY_STEP * steps)
+            .clamp(Self::MIN_SENSITIVITY, Self::MAX_SENSITIVITY);
+    }
+
+    /// Step the field of view by `steps` increments, staying within bounds
+    pub fn adjust_fov(&mut self, steps: f32) {
+        self.fov_degrees = (self.fov_degrees + Self::FOV_STEP * steps).clamp(Self::MIN_FOV, Self::MAX_FOV);
+    }
+
+    /// Yaw/pitch change for a mouse delta - moving the mouse down looks down unless inverted
+    pub fn look_delta(&self, delta: Vec2) -> Vec2 {
+        let pitch_sign = if self.invert_y { -1.0 } else { 1.0 };
+        Vec2::new(-delta.x, -delta.y * pitch_sign) * self.sensitivity
+    }
+}
+
+impl Default for LookConfig {
+    fn default() -> Self {
+        Self {
+            sensitivity: 0.0004,
+            invert_y: false,
+            fov_degrees: BASE_FOV_DEGREES,
+        }
+    }
+}
+
+/// Distance fog on the player camera - distant geometry fades toward `color` before the ASCII pass
+#[derive(Resource)]
+pub struct FogConfig {
+    pub color: Color,
+    /// Distance where fog starts
+    pub start: f32,
+    /// Distance where geometry is fully fogged
+    pub end: f32,
+}
+
+impl Default for FogConfig {
+    fn default() -> Self {
+        Self {
+            // Close to the sky's horizon so far walls fade into it
+            color: Color::srgb(0.3, 0.27, 0.38),
+            start: 40.0,
+            end: 160.0,
+        }
+    }
+}
+
+impl FogConfig {
+    pub fn distance_fog(&self) -> DistanceFog {
+        DistanceFog {
+            color: self.color,
+            falloff: FogFalloff::Linear {
+                start: self.start,
+                end: self.end,
+            },
+            ..default()
+        }
+    }
+}
+
+/// Give the player camera fog, and refresh it whenever FogConfig changes
+fn sync_camera_fog(
+    mut commands: Commands,
+    fog: Res<FogConfig>,
+    camera_query: Query<(Entity, Has<DistanceFog>), With<PlayerCamera>>,
+) {
+    for (camera, has_fog) in &camera_query {
+        if fog.is_changed() || !has_fog {
+            commands.entity(camera).insert(fog.distance_fog());
+        }
+    }
+}
 
 fn spawn_player(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    ruleset: Res<RuleSet>,
+    preset: Res<AsciiPreset>,
 ) {
     let config = MovementConfig::default();
 
@@ -107,29 +326,38 @@ fn spawn_player(
     let player = commands
         .spawn((
             Player,
-            Transform::from_xyz(0.0, config.player_height / 2.0 + 1.0, 10.0),
+            player_spawn_transform(&config),
             Visibility::default(),
             Velocity::default(),
             PlayerState::default(),
             WishDir::default(),
-            Health::new(100.0),
-            WeaponInventory::default(),
+            DashState::default(),
+            Health::new(ruleset.player_max_health()),
+            Armor::default(), // No armor until pickups grant some
+            Faction::Player,
+            ruleset.build_inventory(),
             DamageFlash::default(),
+            Ads::default(),
         ))
         .id();
 
     // Spawn camera as child, offset to eye height
     let eye_offset = config.player_height / 2.0 - 0.1;
+    // Starting on the terminal preset gets the whole look, not just its cell size
+    let ascii_settings = match *preset {
+        AsciiPreset::Terminal => AsciiSettings::terminal(),
+        preset => AsciiSettings::default().with_preset(preset),
+    };
     let camera = commands.spawn((
         Camera3d::default(),
         Projection::Perspective(PerspectiveProjection {
-            fov: 100.0_f32.to_radians(), // Wide FOV for fast movement feel
+            fov: BASE_FOV_DEGREES.to_radians(), // Wide FOV for fast movement feel
             ..default()
         }),
         Transform::from_xyz(0.0, eye_offset, 0.0),
         PlayerCamera::default(),
         ViewSway::default(),
-        AsciiSettings::default(), // Enable ASCII post-processing
+        ascii_settings,           // Enable ASCII post-processing
         DepthPrepass,             // Required for per-object ASCII patterns
         Msaa::Off,                // Disable MSAA for pattern prepass compatibility
         ChildOf(player),
@@ -168,9 +396,72 @@ pub struct VelocityHud;
 #[derive(Component)]
 pub struct HealthHud;
 
+/// Armor readout next to the health display
+#[derive(Component, Default)]
+pub struct ArmorHud {
+    /// Armor shown last frame, to spot it going down
+    pub previous: f32,
+    /// Seconds left of the "depleting" highlight
+    pub depleting: f32,
+}
+
+impl ArmorHud {
+    /// How long the readout stays highlighted after armor absorbs a hit
+    pub const DEPLETING_TIME: f32 = 0.6;
+}
+
 #[derive(Component)]
 pub struct Crosshair;
 
+/// One of the four diagonal ticks around the crosshair that flash on a hit
+#[derive(Component)]
+pub struct HitmarkerTick;
+
+/// Seconds the hitmarker takes to fade out
+const HITMARKER_DURATION: f32 = 0.15;
+
+/// Hit confirmation - set when player damage lands on something else, fades to zero
+#[derive(Resource, Default)]
+pub struct Hitmarker {
+    /// Seconds of fade left (0 = hidden)
+    pub timer: f32,
+}
+
+impl Hitmarker {
+    /// Tick opacity: full right after a hit, fading out over HITMARKER_DURATION
+    pub fn alpha(&self) -> f32 {
+        (self.timer / HITMARKER_DURATION).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Component)]
+pub struct RuleSetHud;
+
+/// Where the player stands at the start of every run
+fn player_spawn_transform(config: &MovementConfig) -> Transform {
+    Transform::from_xyz(0.0, config.player_height / 2.0 + 1.0, 10.0)
+}
+
+/// A new run from the menu puts the player back on the spawn point, at rest and facing forward
+fn reset_player_position(
+    mut player_query: Query<(&mut Transform, &mut Velocity, &mut PlayerState, &mut WishDir, &mut DashState), With<Player>>,
+    mut camera_query: Query<&mut PlayerCamera>,
+) {
+    let Ok((mut transform, mut velocity, mut state, mut wish_dir, mut dash)) = player_query.single_mut() else {
+        return;
+    };
+
+    *transform = player_spawn_transform(&MovementConfig::default());
+    *velocity = Velocity::default();
+    *state = PlayerState::default();
+    *wish_dir = WishDir::default();
+    *dash = DashState::default();
+    for mut camera in &mut camera_query {
+        camera.yaw = 0.0;
+        camera.pitch = 0.0;
+    }
+}
+
 /// Spawn all player HUD elements in one place
 fn spawn_player_hud(mut commands: Commands) {
     // Speed display (top-left)
@@ -207,6 +498,23 @@ fn spawn_player_hud(mut commands: Commands) {
         HealthHud,
     ));
 
+    // Armor display (bottom-left, right of health)
+    commands.spawn((
+        Text::new("ARMOR: 0"),
+        TextFont {
+            font_size: 24.0,
+            ..default()
+        },
+        TextColor(armor_hud_color(0.0, false)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(190.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        },
+        ArmorHud::default(),
+    ));
+
     // Weapon name display (bottom-right, above ammo)
     commands.spawn((
         Text::new("[1] MACHINEGUN"),
@@ -226,7 +534,7 @@ fn spawn_player_hud(mut commands: Commands) {
 
     // Ammo display (bottom-right)
     commands.spawn((
-        Text::new("AMMO: 200/200"),
+        Text::new("AMMO: 50/150"),
         TextFont {
             font_size: 24.0,
             ..default()
@@ -241,6 +549,27 @@ fn spawn_player_hud(mut commands: Commands) {
         AmmoHud,
     ));
 
+    // Active rule set (top-center)
+    commands.spawn((
+        Text::new("MODE: NORMAL"),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.4, 0.8)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Px(10.0),
+            margin: UiRect {
+                left: Val::Px(-60.0),
+                ..default()
+            },
+            ..default()
+        },
+        RuleSetHud,
+    ));
+
     // Crosshair (center) - cycling ASCII character
     commands.spawn((
         Text::new("+"),
@@ -262,6 +591,30 @@ fn spawn_player_hud(mut commands: Commands) {
         },
         Crosshair,
     ));
+
+    // Hitmarker ticks - diagonals around the crosshair, hidden until a hit lands
+    for (tick, x, y) in [("\\", -12.0, -14.0), ("/", 6.0, -14.0), ("/", -12.0, 2.0), ("\\", 6.0, 2.0)] {
+        commands.spawn((
+            Text::new(tick),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::srgba(1.0, 0.3, 0.2, 0.0)),
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(50.0),
+                top: Val::Percent(50.0),
+                margin: UiRect {
+                    left: Val::Px(x),
+                    top: Val::Px(y),
+                    ..default()
+                },
+                ..default()
+            },
+            HitmarkerTick,
+        ));
+    }
 }
 
 fn update_velocity_hud(
@@ -313,9 +666,11 @@ fn handle_window_focus(
 }
 
 fn player_input(
-    keyboard: Res<ButtonInput<KeyCode>>,
+    input: ActionInput,
     mut player_query: Query<(&mut WishDir, &mut PlayerState), With<Player>>,
     camera_query: Query<&PlayerCamera>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
 ) {
     let Ok((mut wish_dir, mut state)) = player_query.single_mut() else {
         return;
@@ -326,41 +681,82 @@ fn player_input(
     };
 
     // Get forward/right vectors from camera yaw (ignore pitch for movement)
-    let yaw_rot = Quat::from_rotation_y(camera.yaw);
+    let yaw_rot = gravity.orientation() * Quat::from_rotation_y(camera.yaw);
     let forward = yaw_rot * Vec3::NEG_Z;
     let right = yaw_rot * Vec3::X;
 
     let mut dir = Vec3::ZERO;
 
-    if keyboard.pressed(KeyCode::KeyW) {
+    if input.pressed(Action::MoveForward) {
         dir += forward;
     }
-    if keyboard.pressed(KeyCode::KeyS) {
+    if input.pressed(Action::MoveBack) {
         dir -= forward;
     }
-    if keyboard.pressed(KeyCode::KeyA) {
+    if input.pressed(Action::MoveLeft) {
         dir -= right;
     }
-    if keyboard.pressed(KeyCode::KeyD) {
+    if input.pressed(Action::MoveRight) {
         dir += right;
     }
 
-    // Keep direction horizontal
-    dir.y = 0.0;
+    // Keep direction along the floor (perpendicular to gravity)
+    dir = dir.reject_from_normalized(gravity.up());
     wish_dir.0 = if dir.length_squared() > 0.0 {
         dir.normalize()
     } else {
         Vec3::ZERO
     };
 
-    // Jump input - holding space = continuously want to jump (enables auto-bhop)
-    state.wish_jump = keyboard.pressed(KeyCode::Space);
+    // Jump input - holding jump = continuously want to jump (enables auto-bhop)
+    state.wish_jump = input.pressed(Action::Jump);
+    if input.just_pressed(Action::Jump) {
+        state.jump_buffer = config.jump_buffer_time;
+    }
+    state.wish_crouch = input.pressed(Action::Crouch);
+}
+
+/// Crouch while the key is held; stand back up only once there's room overhead
+#[allow(clippy::type_complexity)]
+fn update_crouch(
+    mut player_query: Query<(&Transform, &mut PlayerState), With<Player>>,
+    obstacle_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Slope>, Without<Player>)>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+) {
+    for (transform, mut state) in &mut player_query {
+        if state.wish_crouch {
+            state.crouching = true;
+            continue;
+        }
+        if !state.crouching {
+            continue;
+        }
+
+        // Headroom is checked in world Y, like mantling - other gravity directions just stand up
+        let feet_y = transform.translation.y - config.crouch_height / 2.0;
+        let standing_center = Vec3::new(
+            transform.translation.x,
+            feet_y + config.player_height / 2.0,
+            transform.translation.z,
+        );
+        let obstacles = obstacle_query
+            .iter()
+            .map(|(transform, collider)| (transform.translation, collider.half_extents));
+        if !gravity.is_downward()
+            || mantle_has_headroom(standing_center, config.player_height, config.player_radius, obstacles)
+        {
+            state.crouching = false;
+        }
+    }
 }
 
 fn player_look(
     mut mouse_motion: EventReader<MouseMotion>,
     mut camera_query: Query<(&mut Transform, &mut PlayerCamera)>,
     mut player_query: Query<&mut Transform, (With<Player>, Without<PlayerCamera>)>,
+    look: Res<LookConfig>,
+    gravity: Res<GravityConfig>,
 ) {
     let mut delta = Vec2::ZERO;
     for event in mouse_motion.read() {
@@ -380,159 +776,439 @@ fn player_look(
     };
 
     // Update yaw and pitch
-    camera.yaw -= delta.x * MOUSE_SENSITIVITY;
-    camera.pitch -= delta.y * MOUSE_SENSITIVITY;
+    let look_delta = look.look_delta(delta);
+    camera.yaw += look_delta.x;
+    camera.pitch += look_delta.y;
     camera.pitch = camera.pitch.clamp(-1.5, 1.5);
 
-    // Apply yaw to player (so they rotate)
-    player_transform.rotation = Quat::from_rotation_y(camera.yaw);
+    // Apply yaw to player (so they rotate), standing on whichever surface gravity pulls toward
+    player_transform.rotation = gravity.orientation() * Quat::from_rotation_y(camera.yaw);
 
     // Apply pitch to camera only (relative to player)
-    cam_transform.rotation = Quat::from_rotation_x(camera.pitch);
+    cam_transform.rotation = Quat::from_rotation_x(camera.view_pitch());
+}
+
+/// Sensitivity and Y inversion hotkeys
+fn adjust_look(keyboard: Res<ButtonInput<KeyCode>>, mut look: ResMut<LookConfig>) {
+    let mut changed = false;
+    if keyboard.just_pressed(KeyCode::PageUp) {
+        look.adjust_sensitivity(1.0);
+        changed = true;
+    }
+    if keyboard.just_pressed(KeyCode::PageDown) {
+        look.adjust_sensitivity(-1.0);
+        changed = true;
+    }
+    if keyboard.just_pressed(KeyCode::F7) {
+        look.invert_y = !look.invert_y;
+        changed = true;
+    }
+
+    if changed {
+        info!("Look: sensitivity={:.5} invert_y={}", look.sensitivity, look.invert_y);
+    }
+}
+
+/// Whether a player `lateral` away from a collider's center (across its top) is standing over it -
+/// pillars have a round top, everything else is a box
+fn over_top(lateral: Vec3, half_extents: Vec3, pillar: Option<&CylinderCollider>, player_radius: f32) -> bool {
+    match pillar {
+        Some(pillar) => lateral.length() < pillar.radius + player_radius,
+        None => lateral.abs().cmplt(half_extents + Vec3::splat(player_radius)).all(),
+    }
 }
 
+/// Find the surface under the player - the ground plane or the top of any collider they're over
+/// (platforms, pillars, boxes) - and whether they're standing on it
+#[allow(clippy::type_complexity)]
 fn ground_check(
-    mut query: Query<(&Transform, &mut PlayerState, &Velocity), With<Player>>,
-    floor_query: Query<(&Transform, &BoxCollider, Option<&Slope>), (Without<WallCollider>, Without<GroundFloor>, Without<Player>)>,
+    mut query: Query<(Entity, &Transform, &mut PlayerState, &Velocity), With<Player>>,
+    floor_query: Query<
+        (&Transform, &BoxCollider, Option<&Slope>, Option<&CylinderCollider>),
+        (Without<WallCollider>, Without<GroundFloor>, Without<Player>),
+    >,
+    wall_query: Query<(&Transform, &BoxCollider, Option<&CylinderCollider>), (With<WallCollider>, Without<Player>)>,
     config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
-    for (transform, mut state, velocity) in &mut query {
+    // Heights are measured along "up" (opposite gravity), so any box face can be a floor
+    let up = gravity.up();
+    let downward = gravity.is_downward();
+
+    for (entity, transform, mut state, velocity) in &mut query {
         let player_pos = transform.translation;
-        let feet_y = player_pos.y - config.player_height / 2.0;
+        let player_height = player_pos.dot(up);
+        let feet_y = player_height - config.height(state.crouching) / 2.0;
         let player_radius = config.player_radius;
 
-        // Start with base ground level
-        let mut ground_height = 0.0;
+        // Start with base ground level - the ground plane only counts under normal gravity
+        let mut ground_height = if downward { 0.0 } else { f32::NEG_INFINITY };
+        let mut ground_normal = up;
 
         // Step-up height - can walk onto surfaces this much higher than current feet
-        let max_step_up = 0.6;
+        let max_step_up = config.step_height;
 
         // Check all floor surfaces (platforms, stairs, slopes, etc.)
-        for (floor_transform, floor_collider, slope) in &floor_query {
+        for (floor_transform, floor_collider, slope, pillar) in &floor_query {
             let floor_pos = floor_transform.translation;
             let half = floor_collider.half_extents;
 
-            // Check if player is within XZ bounds of this floor
-            if (player_pos.x - floor_pos.x).abs() < half.x + player_radius
-                && (player_pos.z - floor_pos.z).abs() < half.z + player_radius
-            {
+            // Slope surfaces are only defined for the normal Y-up floor
+            if slope.is_some() && !downward {
+                continue;
+            }
+
+            // Check if player is within the floor's bounds across the surface (pillar tops face Y-up)
+            let lateral = (player_pos - floor_pos).reject_from_normalized(up);
+            if over_top(lateral, half, pillar.filter(|_| downward), player_radius) {
                 // Calculate floor height - slopes vary based on position
                 let floor_top = if let Some(slope) = slope {
                     slope.height_at(floor_pos, half, player_pos)
                 } else {
-                    floor_pos.y + half.y
+                    floor_pos.dot(up) + half.dot(up.abs())
                 };
 
                 // Can step up onto this surface, or land on it from above
                 let can_step_up = floor_top <= feet_y + max_step_up;
-                let is_below_player = floor_top < player_pos.y;
+                let is_below_player = floor_top < player_height;
 
                 if (can_step_up || is_below_player) && floor_top > ground_height {
                     ground_height = floor_top;
+                    ground_normal = slope.map_or(up, Slope::normal);
+                }
+            }
+        }
+
+        // Low obstacles act as steps - anything taller stays a wall for player_collision
+        for (wall_transform, wall_collider, pillar) in wall_query.iter().filter(|_| downward) {
+            let wall_pos = wall_transform.translation;
+            let half = wall_collider.half_extents;
+
+            let lateral = (player_pos - wall_pos).reject_from_normalized(up);
+            if over_top(lateral, half, pillar, player_radius) {
+                let wall_top = wall_pos.y + half.y;
+                if wall_top <= feet_y + max_step_up && wall_top > ground_height {
+                    ground_height = wall_top;
+                    ground_normal = up;
                 }
             }
         }
 
         // Update ground height in state
         state.ground_height = ground_height;
+        state.ground_normal = ground_normal;
 
-        // Check if grounded: feet at or below ground level, not moving up significantly
+        // Check if grounded: feet at or below ground level, not moving up significantly.
+        // Slopes too steep to stand on don't count - the player slides down them instead.
         let grounded_tolerance = 0.1;
-        state.grounded = feet_y <= ground_height + grounded_tolerance && velocity.0.y <= 0.1;
+        let was_grounded = state.grounded;
+        state.grounded = feet_y <= ground_height + grounded_tolerance
+            && velocity.0.dot(ground_normal) <= 0.1
+            && config.is_walkable(ground_normal, up);
+        if state.grounded {
+            state.coyote_timer = config.coyote_time;
+        }
+
+        // Hard landings hurt
+        if state.grounded && !was_grounded
+            && let Some(amount) = config.fall_damage(-velocity.0.dot(up))
+        {
+            damage_events.write(DamageEvent {
+                target: entity,
+                amount,
+                source: None,
+            });
+        }
+    }
+}
+
+/// Carry a grounded player along with the moving platform under their feet
+#[allow(clippy::type_complexity)]
+fn ride_moving_platforms(
+    mut player_query: Query<(&mut Transform, &PlayerState), (With<Player>, Without<Mantling>)>,
+    platform_query: Query<(&Transform, &BoxCollider, &MovingPlatform), Without<Player>>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+) {
+    // Platform tops are Y-up surfaces, like slopes
+    if !gravity.is_downward() {
+        return;
+    }
+
+    for (mut transform, state) in &mut player_query {
+        if !state.grounded {
+            continue;
+        }
+
+        // Compare against where the platform was when the player last stood on it
+        let feet = transform.translation - Vec3::Y * config.height(state.crouching) / 2.0;
+        let riding = platform_query.iter().find(|(platform_transform, collider, platform)| {
+            MovingPlatform::carries(
+                platform_transform.translation - platform.delta,
+                collider.half_extents,
+                feet,
+                config.player_radius,
+            )
+        });
+        if let Some((_, _, platform)) = riding {
+            transform.translation += platform.delta;
+        }
+    }
+}
+
+/// Stepping onto a jump pad launches the player - once per landing, not every frame spent on it
+#[allow(clippy::type_complexity)]
+fn apply_jump_pads(
+    mut player_query: Query<(&Transform, &mut Velocity, &mut PlayerState), (With<Player>, Without<Mantling>)>,
+    pad_query: Query<(&Transform, &BoxCollider, &JumpPad), Without<Player>>,
+    config: Res<MovementConfig>,
+) {
+    for (transform, mut velocity, mut state) in &mut player_query {
+        let feet = transform.translation - Vec3::Y * config.height(state.crouching) / 2.0;
+        let pad = pad_query
+            .iter()
+            .find(|(pad_transform, collider, _)| JumpPad::touches(pad_transform.translation, collider.half_extents, feet));
+
+        if let Some((_, _, pad)) = pad
+            && !state.on_jump_pad
+        {
+            velocity.0 = pad.launch(velocity.0);
+            // Airborne right away, so ground friction and coyote jumps don't eat the launch
+            state.grounded = false;
+            state.coyote_timer = 0.0;
+        }
+        state.on_jump_pad = pad.is_some();
+    }
+}
+
+/// Grab a ledge when airborne beside it and pushing toward it
+#[allow(clippy::type_complexity)]
+fn start_mantle(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &Transform, &mut Velocity, &PlayerState, &WishDir),
+        (With<Player>, Without<Mantling>),
+    >,
+    ledge_query: Query<
+        (&Transform, &BoxCollider),
+        (Without<WallCollider>, Without<GroundFloor>, Without<Slope>, Without<Player>),
+    >,
+    obstacle_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Player>)>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+) {
+    let Ok((entity, transform, mut velocity, state, wish_dir)) = player_query.single_mut() else {
+        return;
+    };
+
+    // Ledge grabs assume Y-up ledges
+    if !gravity.is_downward() || state.grounded || wish_dir.0.length_squared() == 0.0 {
+        return;
+    }
+
+    let player_pos = transform.translation;
+    let obstacles = || {
+        obstacle_query
+            .iter()
+            .map(|(t, collider)| (t.translation, collider.half_extents))
+    };
+
+    for (ledge_transform, ledge_collider) in &ledge_query {
+        let Some(target) = mantle_target(
+            player_pos,
+            wish_dir.0,
+            ledge_transform.translation,
+            ledge_collider.half_extents,
+            config.player_height,
+            config.player_radius,
+            config.mantle_max_height,
+        ) else {
+            continue;
+        };
+
+        // Need room to rise beside the ledge and to stand on top of it
+        let rise_point = Vec3::new(player_pos.x, target.y, player_pos.z);
+        if !mantle_has_headroom(rise_point, config.player_height, config.player_radius, obstacles())
+            || !mantle_has_headroom(target, config.player_height, config.player_radius, obstacles())
+        {
+            continue;
+        }
+
+        velocity.0 = Vec3::ZERO;
+        commands.entity(entity).insert(Mantling {
+            start: player_pos,
+            target,
+            timer: 0.0,
+        });
+        return;
+    }
+}
+
+/// Pull the player up and over the ledge, then hand control back
+fn update_mantle(
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &mut Transform, &mut Velocity, &mut PlayerState, &mut Mantling),
+        With<Player>,
+    >,
+    config: Res<MovementConfig>,
+    time: Res<Time>,
+) {
+    let Ok((entity, mut transform, mut velocity, mut state, mut mantling)) = player_query.single_mut() else {
+        return;
+    };
+
+    mantling.timer += time.delta_secs();
+    let t = (mantling.timer / config.mantle_duration).min(1.0);
+
+    // Rise along the ledge face first, then pull forward over the edge
+    let rise_t = (t * 2.0).min(1.0);
+    let pull_t = (t * 2.0 - 1.0).max(0.0);
+    let (start, target) = (mantling.start, mantling.target);
+    transform.translation = Vec3::new(
+        start.x.lerp(target.x, pull_t),
+        start.y.lerp(target.y, rise_t),
+        start.z.lerp(target.z, pull_t),
+    );
+    velocity.0 = Vec3::ZERO;
+
+    if t >= 1.0 {
+        state.grounded = true;
+        state.ground_height = target.y - config.player_height / 2.0;
+        commands.entity(entity).remove::<Mantling>();
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn player_movement(
-    mut query: Query<(&mut Velocity, &mut PlayerState, &WishDir), With<Player>>,
+    mut query: Query<(&mut Velocity, &mut PlayerState, &WishDir), (With<Player>, Without<Mantling>)>,
     config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
+    let crouched_config = config.crouched();
+
     for (mut velocity, mut state, wish_dir) in &mut query {
-        // Handle jumping - if grounded and holding jump, jump immediately
-        // This enables auto-bhop: hold space to jump the frame you land
-        if state.grounded && state.wish_jump {
-            velocity.0.y = config.sv_jumpspeed;
-            state.grounded = false;
-            // Don't clear wish_jump - input system handles it based on key state
+        let config = if state.crouching { &crouched_config } else { &*config };
+
+        // Coyote time lets a jump through just after leaving a ledge,
+        // and a buffered press fires on the frame we land
+        let jump = state.can_jump() && state.wants_jump();
+
+        // Don't clear wish_jump - input system handles it based on key state
+        let (new_velocity, grounded) = move_player(
+            velocity.0,
+            wish_dir.0,
+            state.grounded || jump,
+            jump,
+            config,
+            gravity.up(),
+            dt,
+        );
+        velocity.0 = new_velocity;
+        state.grounded = grounded;
+
+        if jump {
+            state.coyote_timer = 0.0;
+            state.jump_buffer = 0.0;
+        } else {
+            state.coyote_timer = (state.coyote_timer - dt).max(0.0);
+            state.jump_buffer = (state.jump_buffer - dt).max(0.0);
         }
+    }
+}
 
-        // Get horizontal velocity for movement calculations
-        let mut horiz_vel = Vec3::new(velocity.0.x, 0.0, velocity.0.z);
+/// Dash with Left Shift along the wish direction (or where you're looking if standing still).
+/// Runs before collision, so walls stop a dash like any other movement.
+#[allow(clippy::type_complexity)]
+fn player_dash(
+    input: ActionInput,
+    mut query: Query<(&mut Velocity, &WishDir, &mut DashState), (With<Player>, Without<Mantling>)>,
+    camera_query: Query<&PlayerCamera>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
 
-        if state.grounded {
-            // Ground movement: friction then acceleration
-            horiz_vel = apply_friction(
-                horiz_vel,
-                config.sv_friction,
-                config.sv_stopspeed,
-                dt,
-            );
+    for (mut velocity, wish_dir, mut dash) in &mut query {
+        dash.cooldown = (dash.cooldown - dt).max(0.0);
+        if !dash.ready() || !input.just_pressed(Action::Dash) {
+            continue;
+        }
 
-            if wish_dir.0.length_squared() > 0.0 {
-                horiz_vel = accelerate(
-                    horiz_vel,
-                    wish_dir.0,
-                    config.sv_maxspeed,
-                    config.sv_accelerate,
-                    dt,
-                );
-            }
+        let direction = if wish_dir.0 != Vec3::ZERO {
+            wish_dir.0
+        } else if let Ok(camera) = camera_query.single() {
+            gravity.orientation() * Quat::from_rotation_y(camera.yaw) * Vec3::NEG_Z
         } else {
-            // Air movement: CS surf/bhop style - responsive strafing with speed gain
-            if wish_dir.0.length_squared() > 0.0 {
-                horiz_vel = air_accelerate(
-                    horiz_vel,
-                    wish_dir.0,
-                    config.sv_maxspeed,
-                    config.sv_airaccelerate,
-                    config.sv_air_wishspeed_cap,
-                    config.sv_air_speed_cap,
-                    dt,
-                );
-            }
-        }
+            continue;
+        };
 
-        velocity.0.x = horiz_vel.x;
-        velocity.0.z = horiz_vel.z;
+        velocity.0 = dash_velocity(velocity.0, direction, gravity.up(), config.dash_speed);
+        dash.cooldown = config.dash_cooldown;
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn apply_gravity(
-    mut query: Query<(&mut Velocity, &PlayerState), With<Player>>,
-    config: Res<MovementConfig>,
+    mut query: Query<(&mut Velocity, &PlayerState), (With<Player>, Without<Mantling>)>,
+    gravity: Res<GravityConfig>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
 
     for (mut velocity, state) in &mut query {
         if !state.grounded {
-            velocity.0.y -= config.sv_gravity * dt;
+            velocity.0 += gravity.acceleration() * dt;
         }
     }
 }
 
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn player_collision(
-    mut player_query: Query<(&mut Transform, &mut Velocity, &PlayerState), With<Player>>,
+    mut commands: Commands,
+    mut player_query: Query<
+        (Entity, &mut Transform, &mut Velocity, &PlayerState, Option<&SwordLunge>),
+        (With<Player>, Without<Mantling>),
+    >,
     wall_query: Query<(&Transform, &BoxCollider), (With<WallCollider>, Without<Player>)>,
+    box_query: Query<(&Transform, &BoxCollider), (Without<GroundFloor>, Without<Slope>, Without<Player>)>,
+    cylinder_query: Query<(&Transform, &CylinderCollider), Without<Player>>,
     slope_query: Query<(&Transform, &BoxCollider, &Slope), Without<Player>>,
     config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
 ) {
-    for (mut player_transform, mut velocity, state) in &mut player_query {
-        let player_radius = config.player_radius;
-        let feet_y = player_transform.translation.y - config.player_height / 2.0;
+    let up = gravity.up();
+    let downward = gravity.is_downward();
 
-        // Apply floor collision using ground_height from ground_check
-        if feet_y < state.ground_height {
-            player_transform.translation.y = state.ground_height + config.player_height / 2.0;
-            if velocity.0.y < 0.0 {
-                velocity.0.y = 0.0;
+    for (entity, mut player_transform, mut velocity, state, lunge) in &mut player_query {
+        let player_radius = config.player_radius;
+        let mut hit_wall = false;
+        let half_height = config.height(state.crouching) / 2.0;
+        let feet_y = player_transform.translation.y - half_height;
+
+        // Apply floor collision using ground_height from ground_check (measured along up)
+        let feet_height = player_transform.translation.dot(up) - half_height;
+        if feet_height < state.ground_height {
+            player_transform.translation += up * (state.ground_height - feet_height);
+            // Only the push into the surface stops - on a steep slope gravity's pull along it remains
+            let into = velocity.0.dot(state.ground_normal);
+            if into < 0.0 {
+                velocity.0 -= state.ground_normal * into;
             }
         }
 
-        // Collide with slopes as solid volumes
-        for (slope_transform, collider, slope) in &slope_query {
+        // Walking on a slope follows its surface instead of launching off it or bouncing down it
+        if state.grounded && state.ground_normal != up {
+            velocity.0 = velocity.0.reject_from_normalized(state.ground_normal);
+        }
+
+        // Collide with slopes as solid volumes (Y-up only, like their surfaces)
+        for (slope_transform, collider, slope) in slope_query.iter().filter(|_| downward) {
             let slope_pos = slope_transform.translation;
             let half = collider.half_extents;
             let player_pos = player_transform.translation;
@@ -557,7 +1233,7 @@ fn player_collision(
                     // Check if coming from above (landing) vs from side (collision)
                     let height_diff = slope_height - player_bottom;
 
-                    if height_diff < 0.6 {
+                    if height_diff < config.step_height {
                         // Small height difference - treat as step-up (handled by ground_check)
                         continue;
                     }
@@ -577,11 +1253,12 @@ fn player_collision(
                     // Find smallest penetration to resolve
                     if pen_y < pen_x && pen_y < pen_z && pen_y < 2.0 {
                         // Push up onto slope
-                        player_transform.translation.y = slope_height + config.player_height / 2.0;
+                        player_transform.translation.y = slope_height + half_height;
                         if velocity.0.y < 0.0 {
                             velocity.0.y = 0.0;
                         }
                     } else if pen_x < pen_z {
+                        hit_wall = true;
                         // Push out on X
                         if diff_x > 0.0 {
                             player_transform.translation.x = slope_pos.x + combined_x;
@@ -591,6 +1268,7 @@ fn player_collision(
                             velocity.0.x = velocity.0.x.min(0.0);
                         }
                     } else {
+                        hit_wall = true;
                         // Push out on Z
                         if diff_z > 0.0 {
                             player_transform.translation.z = slope_pos.z + combined_z;
@@ -604,6 +1282,41 @@ fn player_collision(
             }
         }
 
+        // Resolve boxes on Y when that's the shallowest way out (Y-up only, like slopes):
+        // jumping into overhead geometry stops the rise, dropping onto a box lands on top of it
+        for (collider_transform, collider) in box_query.iter().filter(|_| downward) {
+            let collider_pos = collider_transform.translation;
+            let half = collider.half_extents;
+            let player_pos = player_transform.translation;
+
+            let pen_x = half.x + player_radius - (player_pos.x - collider_pos.x).abs();
+            let pen_z = half.z + player_radius - (player_pos.z - collider_pos.z).abs();
+            if pen_x <= 0.0 || pen_z <= 0.0 {
+                continue;
+            }
+
+            let bottom = collider_pos.y - half.y;
+            let top = collider_pos.y + half.y;
+            let head_y = player_pos.y + half_height;
+            let feet_y = player_pos.y - half_height;
+            if head_y <= bottom || feet_y >= top {
+                continue;
+            }
+
+            let pen_below = head_y - bottom;
+            let pen_above = top - feet_y;
+            let pen_lateral = pen_x.min(pen_z);
+            if pen_below < pen_above && pen_below < pen_lateral {
+                // Head in the underside - push down and stop rising
+                player_transform.translation.y = bottom - half_height;
+                velocity.0.y = velocity.0.y.min(0.0);
+            } else if pen_above < pen_lateral && velocity.0.y <= 0.0 {
+                // Fell into the top - put the feet on it, ground_check takes it from there
+                player_transform.translation.y = top + half_height;
+                velocity.0.y = 0.0;
+            }
+        }
+
         // Collide with walls/obstacles (only WallCollider entities)
         for (collider_transform, collider) in &wall_query {
             let collider_pos = collider_transform.translation;
@@ -612,6 +1325,17 @@ fn player_collision(
             // Player AABB (simplified as a box for XZ, point for Y)
             let player_pos = player_transform.translation;
 
+            // Standing on top of the obstacle (e.g. after mantling onto a pillar)
+            let feet_y = player_pos.y - half_height;
+            if feet_y >= collider_pos.y + half.y - 0.05 {
+                continue;
+            }
+
+            // Entirely overhead - the Y pass above already kept the head out of it
+            if player_pos.y + half_height <= collider_pos.y - half.y + 0.05 {
+                continue;
+            }
+
             // Check XZ collision (2D box vs box)
             let combined_x = half.x + player_radius;
             let combined_z = half.z + player_radius;
@@ -621,6 +1345,7 @@ fn player_collision(
 
             if diff_x.abs() < combined_x && diff_z.abs() < combined_z {
                 // We're colliding in XZ, push out on smallest penetration axis
+                hit_wall = true;
                 let pen_x = combined_x - diff_x.abs();
                 let pen_z = combined_z - diff_z.abs();
 
@@ -645,11 +1370,37 @@ fn player_collision(
                 }
             }
         }
+
+        // Collide with round obstacles - push out radially so the player slides around them
+        for (collider_transform, collider) in &cylinder_query {
+            let collider_pos = collider_transform.translation;
+
+            let feet_y = player_transform.translation.y - half_height;
+            if feet_y >= collider_pos.y + collider.half_height - 0.05 {
+                continue;
+            }
+
+            if let Some((pushed, normal)) = collider.push_out(collider_pos, player_transform.translation, player_radius) {
+                player_transform.translation = pushed;
+                hit_wall = true;
+                let into = velocity.0.dot(normal);
+                if into < 0.0 {
+                    velocity.0 -= normal * into;
+                }
+            }
+        }
+
+        // Running into a wall ends a sword lunge and takes back its push
+        if hit_wall && let Some(lunge) = lunge {
+            velocity.0 = lunge.cancel(velocity.0);
+            commands.entity(entity).remove::<SwordLunge>();
+        }
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn apply_velocity(
-    mut query: Query<(&mut Transform, &Velocity), With<Player>>,
+    mut query: Query<(&mut Transform, &Velocity), (With<Player>, Without<Mantling>)>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
@@ -659,17 +1410,99 @@ fn apply_velocity(
     }
 }
 
-fn update_view_sway(
-    player_query: Query<(&Velocity, &PlayerState), With<Player>>,
-    mut camera_query: Query<(&mut Transform, &mut ViewSway, &PlayerCamera), Without<Player>>,
+/// Entering a teleporter moves the player to its partner's exit, facing (and moving) out of it.
+/// Horizontal speed carries through; vertical velocity is kept as is.
+#[allow(clippy::type_complexity)]
+fn use_teleporters(
+    mut player_query: Query<(&mut Transform, &mut Velocity, &mut PlayerState), (With<Player>, Without<Mantling>)>,
+    mut camera_query: Query<&mut PlayerCamera>,
+    teleporter_query: Query<(&Transform, &Teleporter), Without<Player>>,
+    config: Res<MovementConfig>,
+    gravity: Res<GravityConfig>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut transform, mut velocity, mut state) in &mut player_query {
+        state.teleport_cooldown = (state.teleport_cooldown - dt).max(0.0);
+        if state.teleport_cooldown > 0.0 {
+            continue;
+        }
+
+        let Some(entered) = teleporter_query
+            .iter()
+            .find(|(teleporter_transform, _)| Teleporter::contains(teleporter_transform, transform.translation))
+            .map(|(_, teleporter)| teleporter)
+        else {
+            continue;
+        };
+        let Some((exit_transform, _)) = teleporter_query.iter().find(|(_, teleporter)| teleporter.id == entered.target)
+        else {
+            warn!("Teleporter {} has no partner {}", entered.id, entered.target);
+            continue;
+        };
+
+        let exit_direction = Teleporter::exit_direction(exit_transform);
+        let half_height = config.height(state.crouching) / 2.0;
+        transform.translation = Teleporter::exit_point(exit_transform) + Vec3::Y * half_height;
+
+        let horizontal_speed = Vec2::new(velocity.0.x, velocity.0.z).length();
+        velocity.0 = exit_direction * horizontal_speed + Vec3::Y * velocity.0.y;
+
+        // Look out of the exit: forward is (-sin yaw, 0, -cos yaw)
+        let yaw = (-exit_direction.x).atan2(-exit_direction.z);
+        transform.rotation = gravity.orientation() * Quat::from_rotation_y(yaw);
+        if let Ok(mut camera) = camera_query.single_mut() {
+            camera.yaw = yaw;
+        }
+
+        state.teleport_cooldown = Teleporter::COOLDOWN;
+    }
+}
+
+/// Aim down sights while the aim button is held - see WeaponInventory::can_aim for when ADS is allowed
+fn update_ads(
+    input: ActionInput,
+    mut player_query: Query<(&mut Ads, &WeaponInventory, Has<DeathCam>), With<Player>>,
+    mut camera_query: Query<(&mut Projection, Has<DeathCam>), With<PlayerCamera>>,
+    look: Res<LookConfig>,
+    time: Res<Time>,
+) {
+    let Ok((mut ads, inventory, _)) = player_query.single_mut() else {
+        return;
+    };
+
+    let Ok((mut projection, dead)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let can_aim = inventory.can_aim() && !dead;
+    ads.update(can_aim && input.pressed(Action::Aim), time.delta_secs());
+
+    // Pattern camera copies this projection, keeping per-object patterns aligned
+    if let Projection::Perspective(perspective) = &mut *projection {
+        let fov = ads.fov(look.fov_degrees);
+        if perspective.fov != fov {
+            perspective.fov = fov;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn update_view_sway(
+    player_query: Query<(&Velocity, &PlayerState, &Ads), With<Player>>,
+    mut camera_query: Query<(&mut Transform, &mut ViewSway, &mut PlayerCamera), Without<Player>>,
     mut viewmodel_query: Query<&mut Transform, (With<ViewModel>, Without<Player>, Without<PlayerCamera>)>,
+    config: Res<MovementConfig>,
+    mut footsteps: EventWriter<FootstepEvent>,
+    mut landings: EventWriter<LandingEvent>,
     time: Res<Time>,
 ) {
-    let Ok((velocity, player_state)) = player_query.single() else {
+    let Ok((velocity, player_state, ads)) = player_query.single() else {
         return;
     };
 
-    let Ok((mut cam_transform, mut sway, camera)) = camera_query.single_mut() else {
+    let Ok((mut cam_transform, mut sway, mut camera)) = camera_query.single_mut() else {
         return;
     };
 
@@ -693,17 +1526,25 @@ fn update_view_sway(
         // Blend toward target instead of instant snap for smoother feel
         let target = -base_impact * bhop_factor;
         sway.landing_offset = sway.landing_offset * 0.3 + target * 0.7;
+
+        landings.write(LandingEvent {
+            fall_speed: -sway.prev_velocity_y,
+        });
     }
     sway.prev_grounded = player_state.grounded;
     sway.prev_velocity_y = velocity.0.y;
 
     // Recover from landing impact
-    sway.landing_offset = sway.landing_offset * (1.0 - dt * 8.0).max(0.0);
+    sway.landing_offset *= (1.0 - dt * 8.0).max(0.0);
 
     // === View bob (only when grounded and moving) ===
     if player_state.grounded && horiz_speed > 0.5 {
         // Slower bob for larger step feel
+        let previous_step = (sway.bob_time / FOOTSTEP_PHASE).floor();
         sway.bob_time += dt * 5.0;
+        if (sway.bob_time / FOOTSTEP_PHASE).floor() > previous_step {
+            footsteps.write(FootstepEvent);
+        }
 
         let bob_x = (sway.bob_time).sin() * 0.003;
         let bob_y = (sway.bob_time * 2.0).sin().abs() * 0.004;
@@ -711,7 +1552,7 @@ fn update_view_sway(
         sway.bob_amount = Vec3::new(bob_x, bob_y, 0.0);
     } else {
         // Smooth return to center when not moving
-        sway.bob_amount = sway.bob_amount * (1.0 - dt * 8.0).max(0.0);
+        sway.bob_amount *= (1.0 - dt * 8.0).max(0.0);
     }
 
     // === Velocity tilt (lean into movement) ===
@@ -730,19 +1571,26 @@ fn update_view_sway(
     sway.velocity_tilt.x = sway.velocity_tilt.x + (target_roll - sway.velocity_tilt.x) * dt * 5.0;
     sway.velocity_tilt.y = sway.velocity_tilt.y + (target_pitch - sway.velocity_tilt.y) * dt * 5.0;
 
+    // === Crouch ===
+    // Ease the eye down/up toward the stance's eye height
+    let target_eye = config.height(player_state.crouching) / 2.0 - 0.1;
+    sway.eye_height += (target_eye - sway.eye_height) * (dt * 12.0).min(1.0);
+
+    // === Recoil ===
+    camera.settle_recoil(dt);
+
     // === Apply to camera transform ===
     // Base position with bob and landing
-    let base_y = 0.8; // Eye height offset
     cam_transform.translation = Vec3::new(
         sway.bob_amount.x,
-        base_y + sway.bob_amount.y + sway.landing_offset,
+        sway.eye_height + sway.bob_amount.y + sway.landing_offset,
         0.0,
     );
 
-    // Apply pitch (from look) + velocity tilt
+    // Apply pitch (from look and recoil) + velocity tilt
     cam_transform.rotation = Quat::from_euler(
         EulerRot::XYZ,
-        camera.pitch + sway.velocity_tilt.y,
+        camera.view_pitch() + sway.velocity_tilt.y,
         0.0,
         sway.velocity_tilt.x, // Roll
     );
@@ -754,9 +1602,12 @@ fn update_view_sway(
 
     for mut vm_transform in &mut viewmodel_query {
         // Get base position (set in spawn) and add sway
+        // ADS pulls the arms in toward the center of the screen
         let base_x = if vm_transform.translation.x > 0.0 { 0.15 } else { -0.15 };
-        vm_transform.translation.x = base_x + vm_offset_x;
-        vm_transform.translation.y = -0.12 + vm_offset_y;
+        let base_x = base_x * (1.0 - 0.6 * ads.t);
+        let base_y = (-0.12).lerp(-0.09, ads.t);
+        vm_transform.translation.x = base_x + vm_offset_x * (1.0 - ads.t);
+        vm_transform.translation.y = base_y + vm_offset_y;
 
         // Subtle rotation with movement
         vm_transform.rotation = Quat::from_euler(
@@ -793,6 +1644,41 @@ fn update_health_hud(
     }
 }
 
+/// Armor readout color - blue normally, orange while armor is being knocked down, grey when empty
+pub fn armor_hud_color(armor: f32, depleting: bool) -> Color {
+    if depleting {
+        Color::srgb(1.0, 0.55, 0.2)
+    } else if armor > 0.0 {
+        Color::srgb(0.4, 0.7, 1.0)
+    } else {
+        Color::srgb(0.5, 0.5, 0.5)
+    }
+}
+
+pub fn update_armor_hud(
+    player_query: Query<&Armor, With<Player>>,
+    mut hud_query: Query<(&mut Text, &mut TextColor, &mut ArmorHud)>,
+    time: Res<Time>,
+) {
+    let Ok(armor) = player_query.single() else {
+        return;
+    };
+
+    let Ok((mut text, mut color, mut hud)) = hud_query.single_mut() else {
+        return;
+    };
+
+    if armor.current < hud.previous {
+        hud.depleting = ArmorHud::DEPLETING_TIME;
+    } else {
+        hud.depleting = (hud.depleting - time.delta_secs()).max(0.0);
+    }
+    hud.previous = armor.current;
+
+    **text = format!("ARMOR: {:.0}", armor.current);
+    color.0 = armor_hud_color(armor.current, hud.depleting > 0.0);
+}
+
 fn update_weapon_hud(
     player_query: Query<&WeaponInventory, With<Player>>,
     mut hud_query: Query<&mut Text, With<WeaponHud>>,
@@ -822,8 +1708,36 @@ fn update_ammo_hud(
         return;
     };
 
-    let weapon = inventory.current();
-    **text = format!("AMMO: {}/{}", weapon.ammo, weapon.max_ammo);
+    **text = ammo_text(inventory.current());
+}
+
+/// Ammo HUD text: magazine/reserve, or unlimited for melee.
+/// The default UI font is ASCII-only, so unlimited is spelled out rather than using a glyph.
+pub fn ammo_text(weapon: &WeaponStats) -> String {
+    if weapon.weapon_type == WeaponType::Sword {
+        return "AMMO: INF".to_string();
+    }
+
+    if weapon.is_reloading() {
+        format!("AMMO: {}/{} RELOADING", weapon.ammo, weapon.reserve_ammo)
+    } else {
+        format!("AMMO: {}/{}", weapon.ammo, weapon.reserve_ammo)
+    }
+}
+
+fn update_ruleset_hud(
+    ruleset: Res<RuleSet>,
+    mut hud_query: Query<&mut Text, With<RuleSetHud>>,
+) {
+    if !ruleset.is_changed() {
+        return;
+    }
+
+    let Ok(mut text) = hud_query.single_mut() else {
+        return;
+    };
+
+    **text = format!("MODE: {}", ruleset.name());
 }
 
 const CROSSHAIR_CHARS: &[char] = &['+', 'x', '*', 'o', '.', ':', '#', '@', '%', '&'];
@@ -842,19 +1756,1703 @@ fn update_crosshair(
     **text = ch.to_string();
 }
 
+/// Flash the hitmarker when the player's damage lands on something other than themselves
+fn update_hitmarker(
+    mut damage_events: EventReader<DamageEvent>,
+    player_query: Query<Entity, With<Player>>,
+    mut hitmarker: ResMut<Hitmarker>,
+    mut ticks: Query<&mut TextColor, With<HitmarkerTick>>,
+    time: Res<Time>,
+) {
+    let player = player_query.single().ok();
+    let landed = damage_events
+        .read()
+        .any(|event| event.source.is_some() && event.source == player && Some(event.target) != player);
+
+    if landed {
+        hitmarker.timer = HITMARKER_DURATION;
+    } else {
+        hitmarker.timer = (hitmarker.timer - time.delta_secs()).max(0.0);
+    }
+
+    let alpha = hitmarker.alpha();
+    for mut color in &mut ticks {
+        if color.0.alpha() != alpha {
+            color.0.set_alpha(alpha);
+        }
+    }
+}
+
 // === Player Death ===
 
+/// Death cam length - pans over the first half, then holds on the view
+const DEATH_CAM_DURATION: f32 = 2.0;
+
+/// Death cam - pulls the camera away from the player's eyes to show what killed them
+#[derive(Component)]
+pub struct DeathCam {
+    pub timer: f32,
+    /// Entity credited with the kill, if any
+    pub killer: Option<Entity>,
+    /// Camera local transform at death, restored when the death cam ends
+    pub start: Transform,
+}
+
+fn not_in_death_cam(death_cam_query: Query<(), With<DeathCam>>) -> bool {
+    death_cam_query.is_empty()
+}
+
+/// World-space camera transform the death cam pans to.
+/// Looks down at the killer from over the player's body, or at the body itself
+/// if there is no killer to show.
+pub fn death_cam_view(player_pos: Vec3, killer_pos: Option<Vec3>) -> Transform {
+    match killer_pos {
+        Some(killer_pos) => {
+            // Rise up behind the body, away from the killer, and look at them
+            let to_killer = Vec3::new(killer_pos.x - player_pos.x, 0.0, killer_pos.z - player_pos.z)
+                .normalize_or(Vec3::NEG_Z);
+            Transform::from_translation(player_pos - to_killer * 3.0 + Vec3::Y * 2.5)
+                .looking_at(killer_pos, Vec3::Y)
+        }
+        // Hover over the fallen body, looking down at it
+        None => Transform::from_translation(player_pos + Vec3::new(0.0, 4.0, 2.0))
+            .looking_at(player_pos, Vec3::Y),
+    }
+}
+
 fn check_player_death(
-    player_query: Query<&Health, With<Player>>,
+    mut commands: Commands,
+    mut death_events: EventReader<DeathEvent>,
+    mut player_query: Query<(Entity, &Health, &mut WishDir, &mut PlayerState), With<Player>>,
+    mut camera_query: Query<(Entity, &Transform, Option<&mut DeathCam>), With<PlayerCamera>>,
     mut next_state: ResMut<NextState<GameState>>,
 ) {
-    let Ok(health) = player_query.single() else {
+    let Ok((player_entity, health, mut wish_dir, mut state)) = player_query.single_mut() else {
+        return;
+    };
+
+    let Ok((camera_entity, camera_transform, death_cam)) = camera_query.single_mut() else {
         return;
     };
 
+    let killer = death_events
+        .read()
+        .filter(|event| event.entity == player_entity)
+        // Blowing yourself up leaves no one to look at
+        .find_map(|event| event.killer.filter(|&killer| killer != player_entity));
+
+    if let Some(mut death_cam) = death_cam {
+        // The death event can arrive a frame after health hits zero
+        if death_cam.killer.is_none() {
+            death_cam.killer = killer;
+        }
+        return;
+    }
+
     if health.is_dead() {
-        // For now, just go back to menu on death
-        // Phase 5 will add proper GameOver state
-        next_state.set(GameState::Menu);
+        // Drop input so the body doesn't keep running during the death cam
+        wish_dir.0 = Vec3::ZERO;
+        state.wish_jump = false;
+
+        commands.entity(camera_entity).insert(DeathCam {
+            timer: 0.0,
+            killer,
+            start: *camera_transform,
+        });
+        next_state.set(GameState::GameOver);
+    }
+}
+
+/// Pan the camera to the death view behind the game over overlay
+fn update_death_cam(
+    mut camera_query: Query<(&mut Transform, &mut DeathCam), With<PlayerCamera>>,
+    player_query: Query<&Transform, (With<Player>, Without<PlayerCamera>)>,
+    killer_query: Query<&GlobalTransform>,
+    time: Res<Time>,
+) {
+    let Ok((mut camera_transform, mut death_cam)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    death_cam.timer = (death_cam.timer + time.delta_secs()).min(DEATH_CAM_DURATION);
+
+    // Killer may have been despawned since - fall back to the body view
+    let killer_pos = death_cam
+        .killer
+        .and_then(|killer| killer_query.get(killer).ok())
+        .map(|killer_transform| killer_transform.translation());
+    let view = death_cam_view(player_transform.translation, killer_pos);
+
+    // Camera is a child of the player - convert the world view into player-local space
+    let player_rot_inv = player_transform.rotation.inverse();
+    let local_translation = player_rot_inv * (view.translation - player_transform.translation);
+    let local_rotation = player_rot_inv * view.rotation;
+
+    // Ease in over the first half, then hold on the view
+    let t = (death_cam.timer / (DEATH_CAM_DURATION * 0.5)).min(1.0);
+    let t = t * t * (3.0 - 2.0 * t);
+    camera_transform.translation = death_cam.start.translation.lerp(local_translation, t);
+    camera_transform.rotation = death_cam.start.rotation.slerp(local_rotation, t);
+}
+
+/// Restore the first-person view when leaving game over, ready for the next run
+fn end_death_cam(
+    mut commands: Commands,
+    mut camera_query: Query<(Entity, &mut Transform, &DeathCam), With<PlayerCamera>>,
+) {
+    let Ok((camera_entity, mut camera_transform, death_cam)) = camera_query.single_mut() else {
+        return;
+    };
+
+    *camera_transform = death_cam.start;
+    commands.entity(camera_entity).remove::<DeathCam>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::combat::{process_damage_events, DamageEvent};
+
+    // ==================== Spawn Tests ====================
+
+    #[test]
+    fn test_player_camera_has_ascii_settings() {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .init_resource::<RuleSet>()
+            .insert_resource(AsciiPreset::Classic)
+            .add_systems(Startup, spawn_player);
+
+        app.update();
+
+        // The post-process node only runs on cameras carrying AsciiSettings
+        let settings: Vec<_> = app
+            .world_mut()
+            .query_filtered::<&AsciiSettings, (With<Camera3d>, With<PlayerCamera>)>()
+            .iter(app.world())
+            .map(|settings| settings.cell_size)
+            .collect();
+        assert_eq!(settings, vec![Vec2::new(8.0, 14.0)]);
+    }
+
+    #[test]
+    fn test_new_run_returns_player_to_spawn() {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .init_resource::<RuleSet>()
+            .init_resource::<AsciiPreset>()
+            .add_systems(Startup, spawn_player)
+            .add_systems(Update, reset_player_position);
+        app.update();
+        let spawn = player_spawn_transform(&MovementConfig::default()).translation;
+
+        // Wherever the last run ended, moving and turned around
+        let (mut transform, mut velocity) = app
+            .world_mut()
+            .query_filtered::<(&mut Transform, &mut Velocity), With<Player>>()
+            .single_mut(app.world_mut())
+            .unwrap();
+        transform.translation = Vec3::new(40.0, 3.0, -25.0);
+        velocity.0 = Vec3::new(12.0, 0.0, 0.0);
+        app.world_mut().query::<&mut PlayerCamera>().single_mut(app.world_mut()).unwrap().yaw = 2.0;
+
+        app.update();
+        let (transform, velocity) = app
+            .world_mut()
+            .query_filtered::<(&Transform, &Velocity), With<Player>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(transform.translation, spawn);
+        assert_eq!(velocity.0, Vec3::ZERO);
+        assert_eq!(app.world_mut().query::<&PlayerCamera>().single(app.world()).unwrap().yaw, 0.0);
+    }
+
+    #[test]
+    fn test_damage_event_hurts_player() {
+        let mut app = test_app();
+        app.add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .with_mesh_assets()
+            .init_resource::<RuleSet>()
+            .init_resource::<AsciiPreset>()
+            .add_systems(Startup, spawn_player)
+            .add_systems(Update, process_damage_events);
+        app.update();
+
+        let player = app
+            .world_mut()
+            .query_filtered::<Entity, With<Player>>()
+            .single(app.world())
+            .unwrap();
+        app.world_mut().send_event(DamageEvent {
+            target: player,
+            amount: 25.0,
+            source: None,
+        });
+        app.update();
+
+        let health = app.world().get::<Health>(player).unwrap();
+        assert_eq!(health.current, 75.0);
+        assert_eq!(health.max, 100.0);
+    }
+
+    #[test]
+    fn test_player_camera_fog_follows_config() {
+        let mut app = test_app();
+        app.with_mesh_assets()
+            .init_resource::<RuleSet>()
+            .init_resource::<AsciiPreset>()
+            .init_resource::<FogConfig>()
+            .add_systems(Startup, (spawn_player, sync_camera_fog).chain())
+            .add_systems(Update, sync_camera_fog);
+        app.update();
+
+        let fog_color = |app: &mut App| {
+            let world = app.world_mut();
+            world
+                .query_filtered::<&DistanceFog, With<PlayerCamera>>()
+                .single(world)
+                .map(|fog| fog.color)
+                .ok()
+        };
+        assert_eq!(fog_color(&mut app), Some(FogConfig::default().color));
+
+        let red = Color::srgb(1.0, 0.0, 0.0);
+        app.world_mut().resource_mut::<FogConfig>().color = red;
+        app.update();
+        assert_eq!(fog_color(&mut app), Some(red));
+    }
+
+    // ==================== Recoil Tests ====================
+
+    #[test]
+    fn test_machinegun_recoil_kicks_then_recovers() {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_millis(50))
+            .add_event::<crate::combat::DamageEvent>()
+            .add_event::<FootstepEvent>()
+            .add_event::<LandingEvent>()
+            .init_resource::<crate::combat::EffectsConfig>()
+            .with_mesh_assets()
+            .insert_resource(crate::rng::GameRng::new(1))
+            .init_resource::<crate::combat::CombatConfig>()
+            .init_resource::<crate::combat::SpatialGrid>()
+            .add_systems(PreUpdate, crate::combat::rebuild_spatial_grid)
+            .init_resource::<MovementConfig>()
+            .add_systems(
+                Update,
+                (
+                    crate::combat::update_weapon_cooldowns,
+                    crate::combat::handle_shooting,
+                    update_view_sway,
+                )
+                    .chain(),
+            );
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                WeaponInventory::default(),
+                Ads::default(),
+                Velocity(Vec3::ZERO),
+                PlayerState { grounded: true, ..default() },
+            ))
+            .id();
+        let camera = app
+            .world_mut()
+            .spawn((
+                PlayerCamera::default(),
+                ViewSway::default(),
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .id();
+
+        let view_pitch = |app: &App| {
+            let rotation = app.world().get::<Transform>(camera).unwrap().rotation;
+            rotation.to_euler(EulerRot::XYZ).0
+        };
+
+        // Hold the trigger for half a second - five rounds at 10/s
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Left);
+        app.insert_resource(mouse);
+        app.update(); // First update has no elapsed time
+        for _ in 0..10 {
+            app.update();
+        }
+        let fired = WeaponStats::machinegun().max_ammo
+            - app.world().get::<WeaponInventory>(player).unwrap().current().ammo;
+        assert!(fired >= 5);
+        let peak = view_pitch(&app);
+        assert!(peak > 0.0, "recoil should pitch the view up");
+
+        // Let go - the spring pulls the view back to the look pitch
+        app.insert_resource(ButtonInput::<MouseButton>::default());
+        for _ in 0..20 {
+            app.update();
+        }
+        assert!(view_pitch(&app) < peak * 0.05);
+        assert_eq!(app.world().get::<PlayerCamera>(camera).unwrap().pitch, 0.0);
+    }
+
+    #[test]
+    fn test_rocket_recoil_exceeds_machinegun() {
+        assert!(WeaponStats::rocket_launcher().recoil > WeaponStats::machinegun().recoil);
+        assert!(WeaponStats::machinegun().recoil > 0.0);
+    }
+
+    #[test]
+    fn test_recoil_spring_settles_without_overshoot() {
+        let mut camera = PlayerCamera::default();
+        camera.kick(0.1);
+        // Large steps stay stable
+        for _ in 0..20 {
+            camera.settle_recoil(0.25);
+            assert!(camera.recoil >= 0.0);
+            assert!(camera.recoil <= 0.1);
+        }
+        assert!(camera.recoil < 1e-4);
+
+        camera.kick(10.0);
+        assert_eq!(camera.recoil, MAX_RECOIL);
+    }
+
+    // ==================== ADS Tests ====================
+
+    #[test]
+    fn test_ads_blends_in_and_out() {
+        let mut ads = Ads::default();
+        assert!((ads.fov(BASE_FOV_DEGREES) - BASE_FOV_DEGREES.to_radians()).abs() < 0.0001);
+
+        // Fully aimed after 1/ADS_SPEED seconds
+        for _ in 0..10 {
+            ads.update(true, 0.016);
+        }
+        assert_eq!(ads.t, 1.0);
+        assert!((ads.fov(BASE_FOV_DEGREES) - ADS_FOV_DEGREES.to_radians()).abs() < 0.0001);
+        assert!(ads.spread_multiplier() < 0.5);
+
+        // And back to hip fire on release
+        for _ in 0..10 {
+            ads.update(false, 0.016);
+        }
+        assert_eq!(ads.t, 0.0);
+        assert_eq!(ads.spread_multiplier(), 1.0);
+    }
+
+    #[test]
+    fn test_holding_right_mouse_narrows_camera_fov() {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_millis(16))
+            .init_resource::<LookConfig>()
+            .add_systems(Update, update_ads);
+        app.world_mut().spawn((Player, Ads::default(), WeaponInventory::default()));
+        let camera = app
+            .world_mut()
+            .spawn((
+                PlayerCamera::default(),
+                Projection::Perspective(PerspectiveProjection {
+                    fov: BASE_FOV_DEGREES.to_radians(),
+                    ..default()
+                }),
+            ))
+            .id();
+
+        let fov = |app: &App| match app.world().get::<Projection>(camera).unwrap() {
+            Projection::Perspective(perspective) => perspective.fov,
+            _ => panic!("expected a perspective projection"),
+        };
+
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Right);
+        app.update();
+        let mut previous = fov(&app);
+        for _ in 0..10 {
+            app.update();
+            let current = fov(&app);
+            assert!(current <= previous);
+            previous = current;
+        }
+        assert!((previous - ADS_FOV_DEGREES.to_radians()).abs() < 0.0001);
+
+        // Releasing restores the hip-fire FOV
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(MouseButton::Right);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!((fov(&app) - BASE_FOV_DEGREES.to_radians()).abs() < 0.0001);
+    }
+
+    // ==================== Step Tests ====================
+
+    fn step_test_app(obstacle_height: f32) -> (App, Entity) {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(Update, (ground_check, player_collision).chain());
+
+        // Obstacle face at x = -0.5, player already pressing into it from the -X side
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, obstacle_height / 2.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(0.5, obstacle_height / 2.0, 2.0) },
+            WallCollider,
+        ));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(-0.8, 0.9, 0.0),
+                Velocity(Vec3::new(5.0, 0.0, 0.0)),
+                PlayerState {
+                    grounded: true,
+                    ..default()
+                },
+            ))
+            .id();
+        (app, player)
+    }
+
+    #[test]
+    fn test_walks_up_low_step() {
+        let (mut app, player) = step_test_app(0.4);
+        app.update();
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        let half_height = MovementConfig::default().player_height / 2.0;
+        assert!((transform.translation.y - (0.4 + half_height)).abs() < 0.001);
+        // Not shoved back out
+        assert_eq!(transform.translation.x, -0.8);
+    }
+
+    #[test]
+    fn test_tall_obstacle_still_blocks() {
+        let (mut app, player) = step_test_app(2.0);
+        app.update();
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert_eq!(transform.translation.y, 0.9);
+        assert!(transform.translation.x <= -0.9 + 0.001);
+    }
+
+    /// Airborne player at the origin moving at `velocity`, above the ground plane
+    fn vertical_collision_app(player_y: f32, velocity: Vec3) -> (App, Entity) {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(Update, (ground_check, player_collision).chain());
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, player_y, 0.0),
+                Velocity(velocity),
+                PlayerState::default(),
+            ))
+            .id();
+        (app, player)
+    }
+
+    /// Pillar built like the level's - a boxed, round-topped body with its top at 3.0
+    fn spawn_pillar(app: &mut App) {
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.5, 0.0),
+            BoxCollider { half_extents: Vec3::new(1.0, 1.5, 1.0) },
+            CylinderCollider { radius: 1.0, half_height: 1.5 },
+        ));
+    }
+
+    #[test]
+    fn test_player_stands_on_pillar_top() {
+        let half_height = MovementConfig::default().player_height / 2.0;
+        let (mut app, player) = vertical_collision_app(3.0 + half_height, Vec3::ZERO);
+        app.with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+        .add_systems(Update, (apply_gravity, apply_velocity).chain().after(player_collision));
+        spawn_pillar(&mut app);
+
+        for _ in 0..30 {
+            app.update();
+        }
+
+        let state = app.world().get::<PlayerState>(player).unwrap();
+        assert!(state.grounded);
+        assert!((state.ground_height - 3.0).abs() < 0.001);
+        let y = app.world().get::<Transform>(player).unwrap().translation.y;
+        assert!((y - (3.0 + half_height)).abs() < 0.001, "sank to {y}");
+    }
+
+    #[test]
+    fn test_pillar_top_is_round() {
+        // Over the corner of the pillar's bounding box, but past its rim
+        let half_height = MovementConfig::default().player_height / 2.0;
+        let (mut app, player) = vertical_collision_app(3.0 + half_height, Vec3::ZERO);
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 1.1;
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.z = 1.1;
+        spawn_pillar(&mut app);
+        app.update();
+
+        assert!(!app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    #[test]
+    fn test_jumping_into_ceiling_stops_rise() {
+        let (mut app, player) = vertical_collision_app(2.0, Vec3::new(0.0, 6.0, 0.0));
+        // Underside at 2.75 - the head (at 2.9) pokes into it
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 3.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(4.0, 0.25, 4.0) },
+        ));
+        app.update();
+
+        let half_height = MovementConfig::default().player_height / 2.0;
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!(transform.translation.y + half_height <= 2.75 + 0.001);
+        assert!(app.world().get::<Velocity>(player).unwrap().0.y <= 0.0);
+        // Pushed down, not out to the side
+        assert_eq!(transform.translation.xz(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_landing_on_box_top_grounds_player() {
+        // Feet at 1.2, well inside a box with its top at 2.0 - deeper than a step
+        let half_height = MovementConfig::default().player_height / 2.0;
+        let (mut app, player) = vertical_collision_app(1.2 + half_height, Vec3::new(0.0, -12.0, 0.0));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(3.0, 1.0, 3.0) },
+            WallCollider,
+        ));
+        app.update();
+        app.update();
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!((transform.translation.y - (2.0 + half_height)).abs() < 0.001);
+        assert_eq!(transform.translation.xz(), Vec2::ZERO);
+        let state = app.world().get::<PlayerState>(player).unwrap();
+        assert!(state.grounded);
+        assert!((state.ground_height - 2.0).abs() < 0.001);
+    }
+
+    // ==================== Slope Tests ====================
+
+    /// Player standing still on the middle of a ramp rising along +Z, stepping at 60fps
+    fn slope_test_app(rise_per_unit: f32) -> (App, Entity) {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(
+                Update,
+                (ground_check, player_movement, apply_gravity, player_collision, apply_velocity).chain(),
+            );
+
+        // Surface is 2.0 high at the center
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(3.0, 1.0, 3.0) },
+            Slope::rising_z(rise_per_unit),
+        ));
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 2.0 + MovementConfig::default().player_height / 2.0, 0.0),
+                Velocity::default(),
+                PlayerState::default(),
+                WishDir::default(),
+            ))
+            .id();
+        (app, player)
+    }
+
+    #[test]
+    fn test_slides_down_steep_slope() {
+        // ~56 degrees - past the walkable limit
+        let (mut app, player) = slope_test_app(1.5);
+        for _ in 0..30 {
+            app.update();
+        }
+
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!(velocity.z < -1.0, "velocity {velocity}");
+        assert!(app.world().get::<Transform>(player).unwrap().translation.z < 0.0);
+        assert!(!app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    #[test]
+    fn test_stands_still_on_gentle_slope() {
+        // ~11 degrees
+        let (mut app, player) = slope_test_app(0.2);
+        for _ in 0..30 {
+            app.update();
+        }
+
+        assert!(app.world().get::<Velocity>(player).unwrap().0.length() < 0.01);
+        assert!(app.world().get::<Transform>(player).unwrap().translation.z.abs() < 0.01);
+        assert!(app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    #[test]
+    fn test_cylinder_pushes_player_out_radially() {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_systems(Update, player_collision);
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, 0.0),
+            CylinderCollider { radius: 1.0, half_height: 2.0 },
+        ));
+        // Overlapping the pillar diagonally, running into it
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.6, 0.9, 0.6),
+                Velocity(Vec3::new(-4.0, 0.0, -4.0)),
+                PlayerState::default(),
+            ))
+            .id();
+        app.update();
+
+        let transform = app.world().get::<Transform>(player).unwrap();
+        let expected = 1.0 + MovementConfig::default().player_radius;
+        assert!((transform.translation.xz().length() - expected).abs() < 0.0001);
+        assert!((transform.translation.x - transform.translation.z).abs() < 0.0001);
+        // Velocity into the pillar is removed
+        assert!(app.world().get::<Velocity>(player).unwrap().0.length() < 0.0001);
+    }
+
+    // ==================== Fall Damage Tests ====================
+
+    /// Airborne player touching down this frame at `impact_speed`
+    fn landing_damage(impact_speed: f32) -> Vec<(Entity, f32)> {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(Update, ground_check);
+
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 0.9, 0.0),
+                Velocity(Vec3::new(3.0, -impact_speed, 0.0)),
+                PlayerState::default(),
+            ))
+            .id();
+        app.update();
+
+        let events = app.world().resource::<Events<DamageEvent>>();
+        let mut cursor = events.get_cursor();
+        let damage: Vec<_> = cursor.read(events).map(|event| (event.target, event.amount)).collect();
+        assert!(damage.iter().all(|(target, _)| *target == player));
+        damage
+    }
+
+    #[test]
+    fn test_hard_landing_damages_player() {
+        let config = MovementConfig::default();
+        let damage = landing_damage(config.fall_damage_speed + 10.0);
+
+        assert_eq!(damage.len(), 1);
+        assert_eq!(damage[0].1, 10.0 * config.fall_damage_scale);
+    }
+
+    #[test]
+    fn test_bhop_landing_is_free() {
+        // Landing from a jump comes down at jump speed
+        let damage = landing_damage(MovementConfig::default().sv_jumpspeed);
+        assert!(damage.is_empty());
+    }
+
+    // ==================== Look Tests ====================
+
+    fn look_test_app(invert_y: bool) -> (App, Entity) {
+        let mut app = test_app();
+        app.add_event::<MouseMotion>()
+            .init_resource::<GravityConfig>()
+            .insert_resource(LookConfig {
+                invert_y,
+                ..default()
+            })
+            .add_systems(Update, player_look);
+
+        app.world_mut().spawn((Player, Transform::default()));
+        let camera = app
+            .world_mut()
+            .spawn((PlayerCamera::default(), Transform::default()))
+            .id();
+        (app, camera)
+    }
+
+    fn pitch_after_mouse_down(invert_y: bool) -> f32 {
+        let (mut app, camera) = look_test_app(invert_y);
+        // Positive Y delta = mouse moved toward the player
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(0.0, 100.0),
+        });
+        app.update();
+        app.world().get::<PlayerCamera>(camera).unwrap().pitch
+    }
+
+    #[test]
+    fn test_mouse_down_looks_down() {
+        assert!(pitch_after_mouse_down(false) < 0.0);
+    }
+
+    #[test]
+    fn test_inverted_mouse_down_looks_up() {
+        assert!(pitch_after_mouse_down(true) > 0.0);
+    }
+
+    #[test]
+    fn test_sensitivity_stays_in_bounds() {
+        let mut look = LookConfig::default();
+        look.adjust_sensitivity(1.0);
+        assert!(look.sensitivity > LookConfig::default().sensitivity);
+
+        look.adjust_sensitivity(-1000.0);
+        assert_eq!(look.sensitivity, LookConfig::MIN_SENSITIVITY);
+        look.adjust_sensitivity(1000.0);
+        assert_eq!(look.sensitivity, LookConfig::MAX_SENSITIVITY);
+    }
+
+    #[test]
+    fn test_fov_stays_in_bounds() {
+        let mut look = LookConfig::default();
+        look.adjust_fov(-1.0);
+        assert_eq!(look.fov_degrees, BASE_FOV_DEGREES - LookConfig::FOV_STEP);
+
+        look.adjust_fov(-100.0);
+        assert_eq!(look.fov_degrees, LookConfig::MIN_FOV);
+        look.adjust_fov(100.0);
+        assert_eq!(look.fov_degrees, LookConfig::MAX_FOV);
+    }
+
+    // ==================== Weapon HUD Tests ====================
+
+    #[test]
+    fn test_ammo_text() {
+        assert_eq!(ammo_text(&WeaponStats::machinegun()), "AMMO: 50/150");
+        assert_eq!(ammo_text(&WeaponStats::sword()), "AMMO: INF");
+
+        let mut reloading = WeaponStats::rocket_launcher();
+        reloading.ammo = 2;
+        reloading.reload();
+        assert_eq!(ammo_text(&reloading), "AMMO: 2/15 RELOADING");
+    }
+
+    #[test]
+    fn test_ammo_hud_decrements_on_fire() {
+        let mut app = test_app();
+        app.with_input()
+            .add_event::<crate::combat::DamageEvent>()
+            .init_resource::<crate::combat::EffectsConfig>()
+            .with_mesh_assets()
+            .insert_resource(crate::rng::GameRng::new(1))
+            .init_resource::<crate::combat::CombatConfig>()
+            .init_resource::<crate::combat::SpatialGrid>()
+            .add_systems(PreUpdate, crate::combat::rebuild_spatial_grid)
+            .add_systems(
+                Update,
+                (crate::combat::handle_shooting, update_weapon_hud, update_ammo_hud).chain(),
+            );
+
+        app.world_mut().spawn((Player, WeaponInventory::default(), Ads::default()));
+        app.world_mut().spawn((PlayerCamera::default(), GlobalTransform::default()));
+        let ammo_hud = app.world_mut().spawn((Text::new(""), AmmoHud)).id();
+        let weapon_hud = app.world_mut().spawn((Text::new(""), WeaponHud)).id();
+
+        app.insert_resource(ButtonInput::<MouseButton>::default());
+        app.update();
+        assert_eq!(app.world().get::<Text>(ammo_hud).unwrap().0, "AMMO: 50/150");
+        assert_eq!(app.world().get::<Text>(weapon_hud).unwrap().0, "[1] MACHINEGUN");
+
+        let mut mouse = ButtonInput::<MouseButton>::default();
+        mouse.press(MouseButton::Left);
+        app.insert_resource(mouse);
+        app.update();
+        assert_eq!(app.world().get::<Text>(ammo_hud).unwrap().0, "AMMO: 49/150");
+    }
+
+    #[test]
+    fn test_armor_hud_highlights_while_depleting() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(100))
+            .add_systems(Update, update_armor_hud);
+
+        let player = app.world_mut().spawn((Player, Armor::new(50.0))).id();
+        let hud = app
+            .world_mut()
+            .spawn((Text::new(""), TextColor::default(), ArmorHud::default()))
+            .id();
+        app.update();
+        assert_eq!(app.world().get::<Text>(hud).unwrap().0, "ARMOR: 50");
+        assert_eq!(app.world().get::<TextColor>(hud).unwrap().0, armor_hud_color(50.0, false));
+
+        app.world_mut().get_mut::<Armor>(player).unwrap().current = 30.0;
+        app.update();
+        assert_eq!(app.world().get::<Text>(hud).unwrap().0, "ARMOR: 30");
+        assert_eq!(app.world().get::<TextColor>(hud).unwrap().0, armor_hud_color(30.0, true));
+
+        // The highlight wears off once the armor stops dropping
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<TextColor>(hud).unwrap().0, armor_hud_color(30.0, false));
+    }
+
+    #[test]
+    fn test_hitmarker_flashes_on_enemy_hit() {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_millis(50))
+            .add_event::<DamageEvent>()
+            .init_resource::<crate::combat::EffectsConfig>()
+            .with_mesh_assets()
+            .insert_resource(crate::rng::GameRng::new(1))
+            .init_resource::<crate::combat::CombatConfig>()
+            .init_resource::<crate::combat::SpatialGrid>()
+            .add_systems(PreUpdate, crate::combat::rebuild_spatial_grid)
+            .init_resource::<Hitmarker>()
+            .add_systems(Update, (crate::combat::handle_shooting, update_hitmarker).chain());
+
+        app.world_mut().spawn((Player, WeaponInventory::default(), Ads::default()));
+        app.world_mut().spawn((
+            PlayerCamera::default(),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, 0.0).looking_to(Vec3::NEG_Z, Vec3::Y)),
+        ));
+        app.world_mut().spawn((
+            crate::combat::Shootable,
+            Health::new(100.0),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, -10.0)),
+        ));
+        let tick = app
+            .world_mut()
+            .spawn((TextColor(Color::srgba(1.0, 0.3, 0.2, 0.0)), HitmarkerTick))
+            .id();
+
+        app.update();
+        assert_eq!(app.world().resource::<Hitmarker>().timer, 0.0);
+
+        // One shot into the enemy
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        assert_eq!(app.world().resource::<Hitmarker>().timer, HITMARKER_DURATION);
+        assert_eq!(app.world().get::<TextColor>(tick).unwrap().0.alpha(), 1.0);
+
+        // Fades out within ~0.15s once the trigger is released
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(MouseButton::Left);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<TextColor>(tick).unwrap().0.alpha(), 0.0);
+    }
+
+    #[test]
+    fn test_rocket_hit_flashes_hitmarker() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(16))
+            .with_mesh_assets()
+            .with_input()
+            .add_event::<DamageEvent>()
+            .init_resource::<crate::combat::EffectsConfig>()
+            .init_resource::<crate::combat::CombatConfig>()
+            .insert_resource(crate::rng::GameRng::new(1))
+            .init_resource::<Hitmarker>()
+            .init_resource::<crate::combat::SpatialGrid>()
+            .add_systems(PreUpdate, crate::combat::rebuild_spatial_grid)
+            .add_systems(
+                Update,
+                (
+                    crate::combat::handle_shooting,
+                    crate::combat::update_player_projectiles,
+                    crate::combat::update_explosions,
+                    update_hitmarker,
+                )
+                    .chain(),
+            );
+
+        let mut inventory = WeaponInventory::default();
+        inventory.switch_to(1);
+        assert_eq!(inventory.current().weapon_type, WeaponType::RocketLauncher);
+        app.world_mut().spawn((Player, inventory, Ads::default()));
+        app.world_mut().spawn((
+            PlayerCamera::default(),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, 0.0).looking_to(Vec3::NEG_Z, Vec3::Y)),
+        ));
+        app.world_mut().spawn((
+            crate::combat::Shootable,
+            Health::new(100.0),
+            GlobalTransform::from(Transform::from_xyz(0.0, 1.7, -10.0)),
+        ));
+
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+        app.update();
+        app.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(MouseButton::Left);
+
+        // The blast damage is credited to the player, so the hitmarker flashes when it lands
+        let mut flashed = false;
+        for _ in 0..40 {
+            app.update();
+            flashed |= app.world().resource::<Hitmarker>().timer == HITMARKER_DURATION;
+        }
+        assert!(flashed);
+    }
+
+    #[test]
+    fn test_self_damage_does_not_flash_hitmarker() {
+        let mut app = test_app();
+        app.add_event::<DamageEvent>()
+            .init_resource::<Hitmarker>()
+            .add_systems(Update, update_hitmarker);
+        let player = app.world_mut().spawn(Player).id();
+
+        app.world_mut().send_event(DamageEvent { target: player, amount: 10.0, source: Some(player) });
+        app.update();
+        assert_eq!(app.world().resource::<Hitmarker>().timer, 0.0);
+    }
+
+    // ==================== Death Cam Tests ====================
+
+    fn death_test_app() -> (App, Entity, Entity) {
+        let mut app = test_app();
+        app.with_game_state(GameState::Menu)
+            .add_event::<DeathEvent>()
+            .add_systems(Update, check_player_death.run_if(in_state(GameState::Playing)))
+            .add_systems(OnExit(GameState::GameOver), end_death_cam);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Playing);
+
+        let player = app
+            .world_mut()
+            .spawn((Player, Health::new(100.0), WishDir::default(), PlayerState::default()))
+            .id();
+        let camera = app
+            .world_mut()
+            .spawn((PlayerCamera::default(), Transform::from_xyz(0.0, 0.8, 0.0)))
+            .id();
+        app.update();
+        (app, player, camera)
+    }
+
+    fn state(app: &App) -> GameState {
+        *app.world().resource::<State<GameState>>().get()
+    }
+
+    #[test]
+    fn test_player_death_enters_game_over() {
+        let (mut app, player, camera) = death_test_app();
+        assert_eq!(state(&app), GameState::Playing);
+
+        app.world_mut().get_mut::<Health>(player).unwrap().take_damage(100.0);
+        app.world_mut().send_event(DeathEvent {
+            entity: player,
+            killer: None,
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(state(&app), GameState::GameOver);
+        assert!(app.world().get::<DeathCam>(camera).is_some());
+    }
+
+    #[test]
+    fn test_leaving_game_over_restores_camera() {
+        let (mut app, player, camera) = death_test_app();
+        app.world_mut().get_mut::<Health>(player).unwrap().take_damage(100.0);
+        app.update();
+        app.update();
+
+        // Death cam moved the camera away from the eyes
+        app.world_mut().get_mut::<Transform>(camera).unwrap().translation = Vec3::new(3.0, 4.0, 5.0);
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Menu);
+        app.update();
+
+        assert_eq!(state(&app), GameState::Menu);
+        assert!(app.world().get::<DeathCam>(camera).is_none());
+        assert_eq!(app.world().get::<Transform>(camera).unwrap().translation, Vec3::new(0.0, 0.8, 0.0));
+    }
+
+    #[test]
+    fn test_death_cam_looks_at_killer() {
+        let player_pos = Vec3::new(0.0, 0.9, 0.0);
+        let killer_pos = Vec3::new(10.0, 1.0, 0.0);
+        let view = death_cam_view(player_pos, Some(killer_pos));
+
+        // Above and behind the body, facing the killer
+        assert!(view.translation.y > player_pos.y);
+        assert!(view.translation.x < player_pos.x);
+        let to_killer = (killer_pos - view.translation).normalize();
+        assert!(view.forward().dot(to_killer) > 0.99);
+    }
+
+    #[test]
+    fn test_death_cam_without_killer_shows_body() {
+        let player_pos = Vec3::new(5.0, 0.9, -3.0);
+        let view = death_cam_view(player_pos, None);
+
+        let to_body = (player_pos - view.translation).normalize();
+        assert!(view.translation.y > player_pos.y);
+        assert!(view.forward().dot(to_body) > 0.99);
+    }
+
+    #[test]
+    fn test_death_cam_killer_directly_above() {
+        // Degenerate horizontal direction shouldn't produce NaNs
+        let player_pos = Vec3::new(0.0, 0.9, 0.0);
+        let view = death_cam_view(player_pos, Some(Vec3::new(0.0, 8.0, 0.0)));
+        assert!(view.translation.is_finite());
+        assert!(view.rotation.is_finite());
+    }
+
+    // ==================== Crouch Tests ====================
+
+    fn crouch_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_systems(Update, update_crouch);
+
+        // Crouched player standing on the floor
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, config.crouch_height / 2.0, 0.0),
+                PlayerState {
+                    crouching: true,
+                    wish_crouch: true,
+                    ..default()
+                },
+            ))
+            .id();
+        app.update();
+        (app, player)
+    }
+
+    fn release_crouch(app: &mut App, player: Entity) {
+        app.world_mut().get_mut::<PlayerState>(player).unwrap().wish_crouch = false;
+        app.update();
+    }
+
+    #[test]
+    fn test_stays_crouched_under_low_ceiling() {
+        let (mut app, player) = crouch_test_app();
+        // Ceiling underside at 1.4 - above the crouched capsule, below standing height
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.9, 0.0),
+            BoxCollider { half_extents: Vec3::new(2.0, 0.5, 2.0) },
+        ));
+        release_crouch(&mut app, player);
+
+        assert!(app.world().get::<PlayerState>(player).unwrap().crouching);
+    }
+
+    #[test]
+    fn test_stands_up_in_the_open() {
+        let (mut app, player) = crouch_test_app();
+        release_crouch(&mut app, player);
+
+        assert!(!app.world().get::<PlayerState>(player).unwrap().crouching);
+    }
+
+    #[test]
+    fn test_crouching_lowers_max_speed() {
+        let config = MovementConfig::default();
+        let crouched = config.crouched();
+
+        assert_eq!(crouched.sv_maxspeed, config.sv_maxspeed * config.crouch_speed_scale);
+        assert!(config.height(true) < config.height(false));
+    }
+
+    // ==================== Dash Tests ====================
+
+    /// Grounded player at the origin facing -Z, stepping at 60fps
+    fn dash_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_systems(
+                Update,
+                ((player_movement, player_dash).chain(), player_collision, apply_velocity).chain(),
+            );
+
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, config.player_height / 2.0, 0.0),
+                Velocity::default(),
+                PlayerState {
+                    grounded: true,
+                    ..default()
+                },
+                WishDir::default(),
+                DashState::default(),
+            ))
+            .id();
+        app.world_mut().spawn(PlayerCamera::default());
+        app.update();
+        (app, player)
+    }
+
+    /// Tap Left Shift for one frame
+    fn tap_dash(app: &mut App) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ShiftLeft);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::ShiftLeft);
+        keyboard.clear();
+    }
+
+    fn horizontal_speed(app: &App, player: Entity) -> f32 {
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        Vec2::new(velocity.x, velocity.z).length()
+    }
+
+    #[test]
+    fn test_dash_spikes_speed_past_maxspeed() {
+        let (mut app, player) = dash_test_app();
+        app.world_mut().get_mut::<WishDir>(player).unwrap().0 = Vec3::X;
+        tap_dash(&mut app);
+
+        let config = MovementConfig::default();
+        assert!(horizontal_speed(&app, player) > config.sv_maxspeed);
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!(velocity.x > 0.0);
+        assert!(app.world().get::<DashState>(player).unwrap().cooldown > 0.0);
+    }
+
+    #[test]
+    fn test_dash_without_input_uses_look_direction() {
+        let (mut app, player) = dash_test_app();
+        tap_dash(&mut app);
+
+        // Default camera yaw looks down -Z
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!(velocity.z < -MovementConfig::default().sv_maxspeed);
+        assert!(velocity.x.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_dash_waits_for_cooldown() {
+        let (mut app, player) = dash_test_app();
+        tap_dash(&mut app);
+
+        // Let friction bleed the dash off, then try again before the cooldown ends
+        for _ in 0..30 {
+            app.update();
+        }
+        let slowed = horizontal_speed(&app, player);
+        tap_dash(&mut app);
+        assert!(horizontal_speed(&app, player) <= slowed);
+
+        // Once the cooldown is over, dashing works again
+        for _ in 0..40 {
+            app.update();
+        }
+        tap_dash(&mut app);
+        assert!(horizontal_speed(&app, player) > MovementConfig::default().sv_maxspeed);
+    }
+
+    #[test]
+    fn test_dash_stops_at_wall() {
+        let (mut app, player) = dash_test_app();
+        // Wall face at x = 1.5
+        app.world_mut().spawn((
+            Transform::from_xyz(2.0, 1.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(0.5, 1.0, 2.0) },
+            WallCollider,
+        ));
+        app.world_mut().get_mut::<WishDir>(player).unwrap().0 = Vec3::X;
+        tap_dash(&mut app);
+
+        for _ in 0..30 {
+            app.update();
+            let x = app.world().get::<Transform>(player).unwrap().translation.x;
+            assert!(x < 2.0, "dashed through the wall to x = {x}");
+        }
+        let transform = app.world().get::<Transform>(player).unwrap();
+        // Pressed against the face, at most one frame of walking into it
+        assert!(transform.translation.x <= 1.1 + 0.2);
+    }
+
+    // ==================== Input Map Tests ====================
+
+    #[test]
+    fn test_rebound_jump_key_drives_wish_jump() {
+        let mut app = test_app();
+        app.with_input()
+            .insert_resource(Rebinding {
+                selected: Action::Jump,
+                capturing: true,
+            })
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_systems(Update, (capture_rebind, player_input).chain());
+        let player = app
+            .world_mut()
+            .spawn((Player, WishDir::default(), PlayerState::default()))
+            .id();
+        app.world_mut().spawn(PlayerCamera::default());
+        let wish_jump = |app: &App| app.world().get::<PlayerState>(player).unwrap().wish_jump;
+
+        // The next key pressed becomes the jump key
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyJ);
+        app.update();
+        assert_eq!(app.world().resource::<InputMap>().binding(Action::Jump), Binding::Key(KeyCode::KeyJ));
+        assert!(wish_jump(&app));
+
+        // Space no longer jumps
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::KeyJ);
+        keyboard.press(KeyCode::Space);
+        app.update();
+        assert!(!wish_jump(&app));
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyJ);
+        app.update();
+        assert!(wish_jump(&app));
+    }
+
+    // ==================== Noclip Tests ====================
+
+    #[test]
+    fn test_noclip_camera_flies_through_walls() {
+        let mut app = test_app();
+        app.add_plugins(bevy::transform::TransformPlugin)
+            .with_input()
+            .with_frame_time(Duration::from_millis(50))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .init_resource::<LookConfig>()
+            .add_event::<MouseMotion>()
+            .add_event::<DamageEvent>()
+            .add_systems(
+                Update,
+                (
+                    (toggle_noclip, fly_noclip_camera).chain(),
+                    player_input.run_if(not_in_noclip),
+                    ground_check,
+                    player_movement,
+                    apply_gravity,
+                    player_collision,
+                    apply_velocity,
+                )
+                    .chain(),
+            );
+
+        // Floor to stand on, and a wall 5m ahead
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, -1.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(20.0, 1.0, 20.0) },
+        ));
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 2.0, -5.0),
+            BoxCollider { half_extents: Vec3::new(5.0, 5.0, 0.5) },
+        ));
+        let config = MovementConfig::default();
+        let player_start = Vec3::new(0.0, config.player_height / 2.0, 0.0);
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_translation(player_start),
+                Velocity::default(),
+                PlayerState::default(),
+                WishDir::default(),
+            ))
+            .id();
+        let eye = Transform::from_xyz(0.0, config.player_height / 2.0 - 0.1, 0.0);
+        let camera = app
+            .world_mut()
+            .spawn((PlayerCamera::default(), eye, ChildOf(player)))
+            .id();
+        app.update();
+        app.update();
+
+        let tap_noclip = |app: &mut App| {
+            app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(NOCLIP_KEY);
+            app.update();
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(NOCLIP_KEY);
+            keyboard.clear();
+        };
+
+        tap_noclip(&mut app);
+        assert!(app.world().get::<NoclipCamera>(camera).is_some());
+        // Detached from the player, so player collision never sees it
+        assert!(app.world().get::<ChildOf>(camera).is_none());
+        assert!(app.world().get::<Player>(camera).is_none());
+
+        // Hold forward for a second - 15m at fly speed, straight through the wall
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::KeyW);
+        for _ in 0..20 {
+            app.update();
+        }
+        let camera_z = app.world().get::<Transform>(camera).unwrap().translation.z;
+        assert!(camera_z < -10.0, "noclip camera stopped at z = {camera_z}");
+
+        // The body stayed put while W flew the camera
+        let player_pos = app.world().get::<Transform>(player).unwrap().translation;
+        assert!(player_pos.xz().distance(player_start.xz()) < 1e-3);
+
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(KeyCode::KeyW);
+        tap_noclip(&mut app);
+        assert!(app.world().get::<NoclipCamera>(camera).is_none());
+        assert_eq!(app.world().get::<ChildOf>(camera).unwrap().parent(), player);
+        assert_eq!(*app.world().get::<Transform>(camera).unwrap(), eye);
+    }
+
+    // ==================== Jump Forgiveness Tests ====================
+
+    /// Player standing on a 2m high platform (x in -1..1), stepping at 60fps
+    fn jump_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.with_input()
+            .with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(
+                Update,
+                (player_input, ground_check, player_movement, apply_gravity, player_collision, apply_velocity)
+                    .chain(),
+            );
+
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, 1.0, 0.0),
+            BoxCollider { half_extents: Vec3::new(1.0, 1.0, 1.0) },
+        ));
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, 2.0 + config.player_height / 2.0, 0.0),
+                Velocity::default(),
+                PlayerState::default(),
+                WishDir::default(),
+            ))
+            .id();
+        app.world_mut().spawn(PlayerCamera::default());
+        app.update();
+        app.update();
+        assert!(app.world().get::<PlayerState>(player).unwrap().grounded);
+        (app, player)
+    }
+
+    /// Move the player just past the platform edge, as if they'd walked off it
+    fn step_off_ledge(app: &mut App, player: Entity) {
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 1.5;
+    }
+
+    fn tap_jump(app: &mut App) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Space);
+        app.update();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(KeyCode::Space);
+        keyboard.clear();
+    }
+
+    /// Lift the player to `height` above the platform, airborne for long enough that coyote time is spent
+    fn drop_from(app: &mut App, player: Entity, height: f32, fall_speed: f32) {
+        let config = MovementConfig::default();
+        let world = app.world_mut();
+        world.get_mut::<Transform>(player).unwrap().translation.y = 2.0 + height + config.player_height / 2.0;
+        world.get_mut::<Velocity>(player).unwrap().0.y = -fall_speed;
+        world.get_mut::<PlayerState>(player).unwrap().coyote_timer = 0.0;
+        app.update();
+        assert!(!app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    fn vertical_speed(app: &App, player: Entity) -> f32 {
+        app.world().get::<Velocity>(player).unwrap().0.y
+    }
+
+    #[test]
+    fn test_coyote_jump_just_after_leaving_ledge() {
+        let (mut app, player) = jump_test_app();
+        step_off_ledge(&mut app, player);
+
+        // 50ms of falling - inside the coyote window
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(!app.world().get::<PlayerState>(player).unwrap().grounded);
+        assert!(vertical_speed(&app, player) < 0.0);
+
+        tap_jump(&mut app);
+        assert!(vertical_speed(&app, player) > 0.0);
+    }
+
+    #[test]
+    fn test_no_coyote_jump_after_window() {
+        let (mut app, player) = jump_test_app();
+        step_off_ledge(&mut app, player);
+
+        // 200ms of falling - the grace period is long gone
+        for _ in 0..12 {
+            app.update();
+        }
+        tap_jump(&mut app);
+        assert!(vertical_speed(&app, player) < 0.0);
+    }
+
+    #[test]
+    fn test_buffered_jump_fires_on_landing() {
+        let (mut app, player) = jump_test_app();
+        // Just above the platform and dropping onto it
+        drop_from(&mut app, player, 0.3, 3.0);
+
+        // Pressed (and released) while still airborne
+        tap_jump(&mut app);
+        assert!(vertical_speed(&app, player) < 0.0);
+
+        let jumped = (0..5).any(|_| {
+            app.update();
+            vertical_speed(&app, player) > 0.0
+        });
+        assert!(jumped);
+    }
+
+    #[test]
+    fn test_stale_jump_press_is_dropped() {
+        let (mut app, player) = jump_test_app();
+        // Well above the platform - the press expires long before landing
+        drop_from(&mut app, player, 3.0, 0.0);
+        tap_jump(&mut app);
+
+        let jumped = (0..60).any(|_| {
+            app.update();
+            vertical_speed(&app, player) > 0.0
+        });
+        assert!(!jumped);
+        assert!(app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    // ==================== Jump Pad Tests ====================
+
+    /// Player standing on a straight-up jump pad at the origin
+    fn jump_pad_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(Update, (ground_check, apply_jump_pads).chain());
+
+        let half = JumpPad::HALF_EXTENTS;
+        app.world_mut().spawn((
+            Transform::from_xyz(0.0, half.y, 0.0),
+            BoxCollider { half_extents: half },
+            JumpPad { impulse: Vec3::new(0.0, 15.0, 0.0) },
+        ));
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, half.y * 2.0 + config.player_height / 2.0, 0.0),
+                Velocity::default(),
+                PlayerState {
+                    grounded: true,
+                    ..default()
+                },
+            ))
+            .id();
+        (app, player)
+    }
+
+    #[test]
+    fn test_jump_pad_launches_once() {
+        let (mut app, player) = jump_pad_test_app();
+        app.update();
+
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0.y, 15.0);
+        assert!(!app.world().get::<PlayerState>(player).unwrap().grounded);
+
+        // Still standing on the pad (nothing moves the player here) - no relaunch
+        app.world_mut().get_mut::<Velocity>(player).unwrap().0 = Vec3::ZERO;
+        for _ in 0..5 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_jump_pad_relaunches_after_leaving() {
+        let (mut app, player) = jump_pad_test_app();
+        app.update();
+        app.world_mut().get_mut::<Velocity>(player).unwrap().0 = Vec3::ZERO;
+
+        // Step off the pad, then back on
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 3.0;
+        app.update();
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0, Vec3::ZERO);
+
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 0.0;
+        app.update();
+        assert_eq!(app.world().get::<Velocity>(player).unwrap().0.y, 15.0);
+    }
+
+    // ==================== Teleporter Tests ====================
+
+    /// Teleporter A at the origin paired with B at (20, 0, 0) facing +X; player standing in A
+    fn teleporter_test_app() -> (App, Entity) {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_systems(Update, (apply_velocity, use_teleporters).chain());
+
+        app.world_mut().spawn((Transform::default(), Teleporter { id: 0, target: 1 }));
+        app.world_mut().spawn((
+            Transform::from_xyz(20.0, 0.0, 0.0).with_rotation(Quat::from_rotation_y(-std::f32::consts::FRAC_PI_2)),
+            Teleporter { id: 1, target: 0 },
+        ));
+        app.world_mut().spawn(PlayerCamera::default());
+
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.0, config.player_height / 2.0, 0.0),
+                // Running into A along -Z
+                Velocity(Vec3::new(0.0, 0.0, -10.0)),
+                PlayerState::default(),
+            ))
+            .id();
+        (app, player)
+    }
+
+    #[test]
+    fn test_teleporter_moves_player_to_partner_exit() {
+        let (mut app, player) = teleporter_test_app();
+        app.update();
+
+        let config = MovementConfig::default();
+        let expected = Vec3::new(20.0 + Teleporter::EXIT_DISTANCE, config.player_height / 2.0, 0.0);
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!((transform.translation - expected).length() < 0.001, "got {}", transform.translation);
+
+        // Same speed, now heading out of B
+        let velocity = app.world().get::<Velocity>(player).unwrap().0;
+        assert!((velocity - Vec3::new(10.0, 0.0, 0.0)).length() < 0.001, "got {velocity}");
+
+        let world = app.world_mut();
+        let camera = world.query::<&PlayerCamera>().single(world).unwrap();
+        assert!((camera.yaw + std::f32::consts::FRAC_PI_2).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_teleporter_cooldown_prevents_ping_pong() {
+        let (mut app, player) = teleporter_test_app();
+        app.update();
+
+        // Walk straight back into B right away - the cooldown holds
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.x = 20.0;
+        app.world_mut().get_mut::<Velocity>(player).unwrap().0 = Vec3::ZERO;
+        app.update();
+        assert_eq!(app.world().get::<Transform>(player).unwrap().translation.x, 20.0);
+
+        // Once it runs out, B sends the player back to A's exit
+        for _ in 0..40 {
+            app.update();
+        }
+        let transform = app.world().get::<Transform>(player).unwrap();
+        assert!((transform.translation.z + Teleporter::EXIT_DISTANCE).abs() < 0.001);
+        assert!(transform.translation.x.abs() < 0.001);
+    }
+
+    // ==================== Moving Platform Tests ====================
+
+    /// Platform shuttling along +X from the origin with the player standing on top
+    fn moving_platform_test_app() -> (App, Entity, Entity) {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_secs_f32(1.0 / 60.0))
+            .init_resource::<MovementConfig>()
+            .init_resource::<GravityConfig>()
+            .add_event::<DamageEvent>()
+            .add_systems(
+                Update,
+                (crate::level::move_platforms, ride_moving_platforms, ground_check, player_collision).chain(),
+            );
+
+        let half = Vec3::new(2.0, 0.25, 2.0);
+        let platform = app
+            .world_mut()
+            .spawn((
+                Transform::from_xyz(0.0, 1.0, 0.0),
+                BoxCollider { half_extents: half },
+                MovingPlatform::new(vec![Vec3::new(0.0, 1.0, 0.0), Vec3::new(10.0, 1.0, 0.0)], 3.0),
+            ))
+            .id();
+        let config = MovementConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                Transform::from_xyz(0.5, 1.0 + half.y + config.player_height / 2.0, 0.0),
+                Velocity::default(),
+                PlayerState {
+                    grounded: true,
+                    ..default()
+                },
+            ))
+            .id();
+        (app, platform, player)
+    }
+
+    #[test]
+    fn test_player_rides_moving_platform() {
+        let (mut app, platform, player) = moving_platform_test_app();
+        let platform_start = app.world().get::<Transform>(platform).unwrap().translation;
+        let player_start = app.world().get::<Transform>(player).unwrap().translation;
+
+        for _ in 0..60 {
+            app.update();
+        }
+
+        let platform_delta = app.world().get::<Transform>(platform).unwrap().translation - platform_start;
+        let player_delta = app.world().get::<Transform>(player).unwrap().translation - player_start;
+        assert!(platform_delta.x > 2.5, "platform should have moved, got {platform_delta}");
+        assert!((player_delta - platform_delta).length() < 0.001, "player moved {player_delta}, platform {platform_delta}");
+        assert!(app.world().get::<PlayerState>(player).unwrap().grounded);
+    }
+
+    #[test]
+    fn test_airborne_player_is_not_carried() {
+        let (mut app, _, player) = moving_platform_test_app();
+        // Hovering well above the platform
+        app.world_mut().get_mut::<Transform>(player).unwrap().translation.y += 3.0;
+        app.world_mut().get_mut::<PlayerState>(player).unwrap().grounded = false;
+        let start = app.world().get::<Transform>(player).unwrap().translation;
+
+        for _ in 0..30 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Transform>(player).unwrap().translation.x, start.x);
     }
 }
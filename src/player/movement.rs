@@ -2,19 +2,30 @@ use bevy::prelude::*;
 
 /// CS Surf/Bhop server style movement constants
 /// Inspired by CS:S/CS:GO community bhop and surf servers
-#[derive(Resource)]
+#[derive(Resource, Clone)]
 pub struct MovementConfig {
     pub sv_maxspeed: f32,           // Max ground speed (units/sec)
     pub sv_accelerate: f32,         // Ground acceleration
     pub sv_airaccelerate: f32,      // Air acceleration (high for surf/bhop)
     pub sv_friction: f32,           // Ground friction
-    pub sv_gravity: f32,            // Gravity (units/sec^2)
     pub sv_jumpspeed: f32,          // Jump velocity
     pub sv_stopspeed: f32,          // Speed below which friction stops you instantly
     pub sv_air_wishspeed_cap: f32,  // Wish speed cap in air (controls strafe tightness)
     pub sv_air_speed_cap: f32,      // Hard cap on air speed
     pub player_height: f32,         // Player capsule height
     pub player_radius: f32,         // Player capsule radius
+    pub crouch_height: f32,         // Capsule height while crouched
+    pub crouch_speed_scale: f32,    // sv_maxspeed multiplier while crouched
+    pub step_height: f32,           // Ledges this far above the feet are walked onto instead of blocking
+    pub max_walkable_angle: f32,    // Steepest slope (degrees) that counts as ground - steeper ones slide you down
+    pub mantle_max_height: f32,     // Highest ledge (above feet) the player can pull onto
+    pub mantle_duration: f32,       // Time to pull up onto a ledge (seconds)
+    pub fall_damage_speed: f32,     // Landing speed that starts to hurt (bhop landings stay well below)
+    pub fall_damage_scale: f32,     // Damage per unit/sec of landing speed past the threshold
+    pub dash_speed: f32,            // Horizontal speed a dash launches you at
+    pub dash_cooldown: f32,         // Seconds between dashes
+    pub coyote_time: f32,           // Grace period to still jump after walking off a ledge (seconds)
+    pub jump_buffer_time: f32,      // How early a jump press before landing still counts (seconds)
 }
 
 impl Default for MovementConfig {
@@ -26,17 +37,96 @@ impl Default for MovementConfig {
             sv_accelerate: 6.0,         // Moderate ground accel (less twitchy than Quake)
             sv_airaccelerate: 12.0,     // High air accel for responsive strafing
             sv_friction: 5.0,           // Higher friction = snappier ground stops
-            sv_gravity: 12.0,           // Lower gravity for more hangtime (surf feel)
             sv_jumpspeed: 6.2,          // Good jump height for bhop chains
             sv_stopspeed: 1.8,          // Threshold for instant stop
             sv_air_wishspeed_cap: 1.5,  // Tighter than Quake = smoother curves
             sv_air_speed_cap: 25.0,     // Soft cap on max speed
             player_height: 1.8,
             player_radius: 0.4,
+            crouch_height: 1.0,
+            crouch_speed_scale: 0.5,
+            step_height: 0.6,
+            max_walkable_angle: 45.0,
+            mantle_max_height: 1.6,     // Roughly jump apex - grab ledges just out of reach
+            mantle_duration: 0.3,
+            fall_damage_speed: 12.0,    // ~6m drop - jumping off the stair platform is still free
+            fall_damage_scale: 3.0,
+            dash_speed: 20.0,           // Well past sv_maxspeed - friction bleeds it off on the ground
+            dash_cooldown: 1.0,
+            coyote_time: 0.1,
+            jump_buffer_time: 0.1,
+        }
+    }
+}
+
+impl MovementConfig {
+    /// Capsule height for the current stance
+    pub fn height(&self, crouching: bool) -> f32 {
+        if crouching { self.crouch_height } else { self.player_height }
+    }
+
+    /// Damage for landing at `impact_speed`, or None for a harmless landing
+    pub fn fall_damage(&self, impact_speed: f32) -> Option<f32> {
+        let excess = impact_speed - self.fall_damage_speed;
+        (excess > 0.0).then_some(excess * self.fall_damage_scale)
+    }
+
+    /// Whether a surface facing `normal` is flat enough to stand on
+    pub fn is_walkable(&self, normal: Vec3, up: Vec3) -> bool {
+        normal.angle_between(up) <= self.max_walkable_angle.to_radians()
+    }
+
+    /// Config with the crouched speed limit applied
+    pub fn crouched(&self) -> Self {
+        Self {
+            sv_maxspeed: self.sv_maxspeed * self.crouch_speed_scale,
+            ..self.clone()
+        }
+    }
+}
+
+/// Direction and strength of gravity acting on the player.
+/// Pointing somewhere other than -Y makes walls or ceilings the floor for gimmick sections;
+/// the player's "up" (jumps, ground checks, view roll) is always opposite to gravity.
+#[derive(Resource)]
+pub struct GravityConfig {
+    /// Unit vector gravity pulls along
+    pub direction: Vec3,
+    /// Gravity strength (units/sec^2)
+    pub magnitude: f32,
+}
+
+impl Default for GravityConfig {
+    fn default() -> Self {
+        Self {
+            direction: Vec3::NEG_Y,
+            magnitude: 12.0, // Lower gravity for more hangtime (surf feel)
         }
     }
 }
 
+impl GravityConfig {
+    /// The player's up direction - away from the floor
+    pub fn up(&self) -> Vec3 {
+        -self.direction.normalize_or(Vec3::NEG_Y)
+    }
+
+    /// Acceleration applied while airborne
+    pub fn acceleration(&self) -> Vec3 {
+        self.direction.normalize_or(Vec3::NEG_Y) * self.magnitude
+    }
+
+    /// Whether gravity is the normal downward pull (level geometry like slopes assumes this)
+    pub fn is_downward(&self) -> bool {
+        self.up().dot(Vec3::Y) > 0.999
+    }
+
+    /// Rotation from the normal Y-up frame into the gravity frame
+    pub fn orientation(&self) -> Quat {
+        Quat::from_rotation_arc(Vec3::Y, self.up())
+    }
+}
+
 #[derive(Component, Default, Clone)]
 pub struct Velocity(pub Vec3);
 
@@ -44,8 +134,22 @@ pub struct Velocity(pub Vec3);
 pub struct PlayerState {
     pub grounded: bool,
     pub wish_jump: bool,
-    /// Current ground height (y position of the floor surface the player is on or above)
+    /// Crouch key held
+    pub wish_crouch: bool,
+    /// Actually crouched - stays true under low ceilings after the key is released
+    pub crouching: bool,
+    /// Current ground height (position along gravity "up" of the floor surface the player is on or above)
     pub ground_height: f32,
+    /// Surface normal of that floor - "up" unless it's a slope
+    pub ground_normal: Vec3,
+    /// Coyote time left - jumps still work this long after leaving the ground
+    pub coyote_timer: f32,
+    /// Buffered jump press - fires on landing if it hasn't expired
+    pub jump_buffer: f32,
+    /// Standing on a jump pad last frame (pads only launch when first stepped on)
+    pub on_jump_pad: bool,
+    /// Seconds until teleporters work again (stops bouncing straight back)
+    pub teleport_cooldown: f32,
 }
 
 impl Default for PlayerState {
@@ -53,15 +157,68 @@ impl Default for PlayerState {
         Self {
             grounded: false,
             wish_jump: false,
+            wish_crouch: false,
+            crouching: false,
             ground_height: 0.0,
+            ground_normal: Vec3::Y,
+            coyote_timer: 0.0,
+            jump_buffer: 0.0,
+            on_jump_pad: false,
+            teleport_cooldown: 0.0,
         }
     }
 }
 
+impl PlayerState {
+    /// On the ground, or only just left it
+    pub fn can_jump(&self) -> bool {
+        self.grounded || self.coyote_timer > 0.0
+    }
+
+    /// Jump held, or pressed recently enough to be buffered
+    pub fn wants_jump(&self) -> bool {
+        self.wish_jump || self.jump_buffer > 0.0
+    }
+}
+
+/// Ledge mantle in progress - player is pulled from start to target, movement is suspended
+#[derive(Component)]
+pub struct Mantling {
+    pub start: Vec3,
+    pub target: Vec3,
+    pub timer: f32,
+}
+
 /// Input wish direction (normalized horizontal direction player wants to move)
 #[derive(Component, Default)]
 pub struct WishDir(pub Vec3);
 
+/// Dash cooldown. Dashing grants no invulnerability - it's purely movement.
+#[derive(Component, Default)]
+pub struct DashState {
+    /// Seconds until the next dash is allowed (0 = ready)
+    pub cooldown: f32,
+}
+
+impl DashState {
+    pub fn ready(&self) -> bool {
+        self.cooldown <= 0.0
+    }
+}
+
+/// Velocity after dashing along `direction` (horizontal, relative to `up`).
+/// Horizontal speed jumps to at least `dash_speed` - faster momentum is kept, just redirected.
+/// Vertical velocity is untouched.
+pub fn dash_velocity(velocity: Vec3, direction: Vec3, up: Vec3, dash_speed: f32) -> Vec3 {
+    let direction = direction.reject_from_normalized(up).normalize_or_zero();
+    if direction == Vec3::ZERO {
+        return velocity;
+    }
+    let vertical = up * velocity.dot(up);
+    let horizontal_speed = (velocity - vertical).length();
+    vertical + direction * horizontal_speed.max(dash_speed)
+}
+
 /// Quake-style ground acceleration
 pub fn accelerate(
     velocity: Vec3,
@@ -111,16 +268,80 @@ pub fn air_accelerate(
     let accel_speed = (accel * wish_speed * dt).min(add_speed);
     let new_vel = velocity + wish_dir * accel_speed;
 
-    // Soft cap on horizontal speed - allows high speeds but with diminishing returns
+    // Soft cap on horizontal speed - strafing can't build speed past the cap,
+    // but momentum already above it (jump pads, explosions) is kept rather than clamped
+    let old_speed = Vec2::new(velocity.x, velocity.z).length();
+    let limit = air_speed_cap.max(old_speed);
     let horiz_speed = Vec2::new(new_vel.x, new_vel.z).length();
-    if horiz_speed > air_speed_cap {
-        let scale = air_speed_cap / horiz_speed;
+    if horiz_speed > limit {
+        let scale = limit / horiz_speed;
         return Vec3::new(new_vel.x * scale, new_vel.y, new_vel.z * scale);
     }
 
     new_vel
 }
 
+/// One frame of player movement input: jumping, then ground or air acceleration.
+/// Returns the new velocity and whether the player is still grounded.
+/// Jumping is handled before friction, so holding jump on landing carries full speed into the bhop.
+/// `up` is the player's up direction (opposite gravity) - movement is computed in that frame.
+pub fn move_player(
+    velocity: Vec3,
+    wish_dir: Vec3,
+    grounded: bool,
+    wish_jump: bool,
+    config: &MovementConfig,
+    up: Vec3,
+    dt: f32,
+) -> (Vec3, bool) {
+    // Work in a local frame where up is +Y, so the Quake math below stays Y-up
+    let to_local = Quat::from_rotation_arc(up, Vec3::Y);
+    let mut velocity = to_local * velocity;
+    let mut wish_dir = to_local * wish_dir;
+    wish_dir.y = 0.0;
+    wish_dir = wish_dir.normalize_or_zero();
+    let mut grounded = grounded;
+
+    // Handle jumping - if grounded and holding jump, jump immediately
+    // This enables auto-bhop: hold space to jump the frame you land
+    if grounded && wish_jump {
+        velocity.y = config.sv_jumpspeed;
+        grounded = false;
+    }
+
+    // Get horizontal velocity for movement calculations
+    let mut horiz_vel = Vec3::new(velocity.x, 0.0, velocity.z);
+
+    if grounded {
+        // Ground movement: friction then acceleration
+        horiz_vel = apply_friction(horiz_vel, config.sv_friction, config.sv_stopspeed, dt);
+
+        if wish_dir.length_squared() > 0.0 {
+            horiz_vel = accelerate(
+                horiz_vel,
+                wish_dir,
+                config.sv_maxspeed,
+                config.sv_accelerate,
+                dt,
+            );
+        }
+    } else if wish_dir.length_squared() > 0.0 {
+        // Air movement: CS surf/bhop style - responsive strafing with speed gain
+        horiz_vel = air_accelerate(
+            horiz_vel,
+            wish_dir,
+            config.sv_maxspeed,
+            config.sv_airaccelerate,
+            config.sv_air_wishspeed_cap,
+            config.sv_air_speed_cap,
+            dt,
+        );
+    }
+
+    let local_velocity = Vec3::new(horiz_vel.x, velocity.y, horiz_vel.z);
+    (to_local.inverse() * local_velocity, grounded)
+}
+
 /// Apply ground friction
 pub fn apply_friction(velocity: Vec3, friction: f32, stop_speed: f32, dt: f32) -> Vec3 {
     let speed = velocity.length();
@@ -140,6 +361,84 @@ pub fn apply_friction(velocity: Vec3, friction: f32, stop_speed: f32, dt: f32) -
     }
 }
 
+/// How far outside a ledge's footprint the player can still grab it
+const MANTLE_GRAB_REACH: f32 = 0.3;
+
+/// Compute where the player ends up when mantling onto a ledge (box top surface).
+/// Returns None if the ledge is out of reach, too high, below the feet,
+/// or the player isn't pushing toward it.
+pub fn mantle_target(
+    player_pos: Vec3,
+    wish_dir: Vec3,
+    ledge_center: Vec3,
+    ledge_half: Vec3,
+    player_height: f32,
+    player_radius: f32,
+    max_height: f32,
+) -> Option<Vec3> {
+    let feet_y = player_pos.y - player_height / 2.0;
+    let ledge_top = ledge_center.y + ledge_half.y;
+    let rise = ledge_top - feet_y;
+
+    // Step-up handles anything at or below the feet, ledge must be within reach
+    if rise <= 0.0 || rise > max_height {
+        return None;
+    }
+
+    let diff_x = player_pos.x - ledge_center.x;
+    let diff_z = player_pos.z - ledge_center.z;
+
+    // Must be beside the ledge (not over it) but close enough to grab the edge
+    let inside = diff_x.abs() < ledge_half.x && diff_z.abs() < ledge_half.z;
+    let reach_x = ledge_half.x + player_radius + MANTLE_GRAB_REACH;
+    let reach_z = ledge_half.z + player_radius + MANTLE_GRAB_REACH;
+    if inside || diff_x.abs() > reach_x || diff_z.abs() > reach_z {
+        return None;
+    }
+
+    // Must be pushing toward the ledge
+    let edge_x = player_pos.x.clamp(ledge_center.x - ledge_half.x, ledge_center.x + ledge_half.x);
+    let edge_z = player_pos.z.clamp(ledge_center.z - ledge_half.z, ledge_center.z + ledge_half.z);
+    let to_ledge = Vec3::new(edge_x - player_pos.x, 0.0, edge_z - player_pos.z);
+    if to_ledge.length_squared() < 0.0001 || wish_dir.dot(to_ledge.normalize()) < 0.5 {
+        return None;
+    }
+
+    // Land just inside the edge, inset by the player radius where the ledge is wide enough
+    let inset_x = player_radius.min(ledge_half.x);
+    let inset_z = player_radius.min(ledge_half.z);
+    let target_x = player_pos.x.clamp(
+        ledge_center.x - ledge_half.x + inset_x,
+        ledge_center.x + ledge_half.x - inset_x,
+    );
+    let target_z = player_pos.z.clamp(
+        ledge_center.z - ledge_half.z + inset_z,
+        ledge_center.z + ledge_half.z - inset_z,
+    );
+
+    Some(Vec3::new(target_x, ledge_top + player_height / 2.0, target_z))
+}
+
+/// Check that the player fits standing at the mantle target without
+/// clipping into any collider (center, half extents) above the ledge
+pub fn mantle_has_headroom(
+    target: Vec3,
+    player_height: f32,
+    player_radius: f32,
+    colliders: impl IntoIterator<Item = (Vec3, Vec3)>,
+) -> bool {
+    // Small margin so the ledge surface itself doesn't count as an obstruction
+    let feet_y = target.y - player_height / 2.0 + 0.05;
+    let head_y = target.y + player_height / 2.0;
+
+    colliders.into_iter().all(|(center, half)| {
+        let overlaps_x = (target.x - center.x).abs() < half.x + player_radius;
+        let overlaps_z = (target.z - center.z).abs() < half.z + player_radius;
+        let overlaps_y = center.y + half.y > feet_y && center.y - half.y < head_y;
+        !(overlaps_x && overlaps_z && overlaps_y)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -165,12 +464,13 @@ mod tests {
         assert!(approx_eq(config.sv_accelerate, 6.0));
         assert!(approx_eq(config.sv_airaccelerate, 12.0));
         assert!(approx_eq(config.sv_friction, 5.0));
-        assert!(approx_eq(config.sv_gravity, 12.0));
         assert!(approx_eq(config.sv_jumpspeed, 6.2));
         assert!(approx_eq(config.sv_air_wishspeed_cap, 1.5));
         assert!(approx_eq(config.sv_air_speed_cap, 25.0));
         assert!(approx_eq(config.player_height, 1.8));
         assert!(approx_eq(config.player_radius, 0.4));
+        assert!(approx_eq(config.mantle_max_height, 1.6));
+        assert!(approx_eq(config.mantle_duration, 0.3));
     }
 
     // ==================== Accelerate Tests ====================
@@ -270,6 +570,20 @@ mod tests {
         assert!(horiz_speed <= air_speed_cap + EPSILON);
     }
 
+    #[test]
+    fn test_air_accelerate_keeps_momentum_above_cap() {
+        // Launched well past the cap (e.g. off a jump pad), strafing sideways
+        let velocity = Vec3::new(0.0, 10.0, -35.0);
+        let wish_dir = Vec3::new(1.0, 0.0, 0.0);
+
+        let result = air_accelerate(velocity, wish_dir, 7.5, 12.0, 1.5, 25.0, 0.016);
+
+        // Not clamped down to the cap, and not allowed to grow either
+        let horiz_speed = Vec2::new(result.x, result.z).length();
+        assert!(horiz_speed > 25.0);
+        assert!(horiz_speed <= 35.0 + EPSILON);
+    }
+
     #[test]
     fn test_air_accelerate_preserves_vertical() {
         let velocity = Vec3::new(5.0, -10.0, 5.0); // Falling
@@ -351,6 +665,18 @@ mod tests {
 
         assert!(!state.grounded);
         assert!(!state.wish_jump);
+        assert!(!state.can_jump());
+        assert!(!state.wants_jump());
+    }
+
+    #[test]
+    fn test_player_state_coyote_and_buffer() {
+        let airborne = PlayerState { coyote_timer: 0.05, ..default() };
+        assert!(airborne.can_jump());
+
+        let buffered = PlayerState { jump_buffer: 0.05, ..default() };
+        assert!(buffered.wants_jump());
+        assert!(!buffered.can_jump());
     }
 
     // ==================== Velocity Tests ====================
@@ -379,7 +705,7 @@ mod tests {
         // The wish_dir should be nearly perpendicular to current velocity
         for _ in 0..30 {
             let wish_dir = Vec3::new(1.0, 0.0, 0.0); // Pure right strafe
-            velocity = air_accelerate(velocity, wish_dir, 8.0, accel, dt);
+            velocity = air_accelerate(velocity, wish_dir, 8.0, accel, 1.5, 25.0, dt);
         }
 
         let final_speed = Vec2::new(velocity.x, velocity.z).length();
@@ -406,4 +732,192 @@ mod tests {
         // Should cap at wish_speed
         assert!(speed <= wish_speed + EPSILON);
     }
+
+    // ==================== Mantle Tests ====================
+
+    // Pillar top like the level's: 2x2 footprint, surface at y=3.1
+    const LEDGE_CENTER: Vec3 = Vec3::new(0.0, 3.0, 0.0);
+    const LEDGE_HALF: Vec3 = Vec3::new(1.0, 0.1, 1.0);
+
+    fn mantle(player_pos: Vec3, wish_dir: Vec3) -> Option<Vec3> {
+        mantle_target(player_pos, wish_dir, LEDGE_CENTER, LEDGE_HALF, 1.8, 0.4, 1.6)
+    }
+
+    #[test]
+    fn test_mantle_target_lands_inside_edge() {
+        // Airborne beside the +X face, feet at 1.7 (ledge 1.4 above)
+        let player_pos = Vec3::new(1.5, 2.6, 0.2);
+        let target = mantle(player_pos, Vec3::NEG_X).expect("should grab ledge");
+
+        // Standing on top, inset by the player radius, keeping lateral position
+        assert!(approx_eq(target.x, 0.6));
+        assert!(approx_eq(target.y, 3.1 + 0.9));
+        assert!(approx_eq(target.z, 0.2));
+    }
+
+    #[test]
+    fn test_mantle_requires_pushing_toward_ledge() {
+        let player_pos = Vec3::new(1.5, 2.6, 0.0);
+
+        assert!(mantle(player_pos, Vec3::ZERO).is_none());
+        assert!(mantle(player_pos, Vec3::X).is_none());
+        assert!(mantle(player_pos, Vec3::Z).is_none());
+    }
+
+    #[test]
+    fn test_mantle_rejects_out_of_reach() {
+        // Ledge too high above the feet
+        assert!(mantle(Vec3::new(1.5, 1.0, 0.0), Vec3::NEG_X).is_none());
+        // Too far from the edge horizontally
+        assert!(mantle(Vec3::new(3.0, 2.6, 0.0), Vec3::NEG_X).is_none());
+        // Feet already above the ledge - step-up/landing handles this
+        assert!(mantle(Vec3::new(1.5, 4.1, 0.0), Vec3::NEG_X).is_none());
+    }
+
+    #[test]
+    fn test_mantle_headroom() {
+        let target = Vec3::new(0.6, 4.0, 0.0);
+        let ledge = (LEDGE_CENTER, LEDGE_HALF);
+
+        // The ledge itself is not an obstruction
+        assert!(mantle_has_headroom(target, 1.8, 0.4, [ledge]));
+
+        // A low ceiling over the ledge blocks the mantle
+        let ceiling = (Vec3::new(0.0, 4.5, 0.0), Vec3::new(2.0, 0.2, 2.0));
+        assert!(!mantle_has_headroom(target, 1.8, 0.4, [ledge, ceiling]));
+
+        // A ceiling high enough to stand under doesn't
+        let high_ceiling = (Vec3::new(0.0, 6.0, 0.0), Vec3::new(2.0, 0.2, 2.0));
+        assert!(mantle_has_headroom(target, 1.8, 0.4, [ledge, high_ceiling]));
+    }
+
+    // ==================== Momentum Transfer Tests ====================
+
+    #[test]
+    fn test_pad_launch_momentum_carries_into_bhop() {
+        let config = MovementConfig::default();
+        let gravity = GravityConfig::default();
+        let dt = 1.0 / 60.0;
+        let ground_y = 0.0;
+
+        // Jump pad launch: strong upward + forward boost, well above the air speed cap
+        let launch_speed = 30.0;
+        let mut velocity = Vec3::new(0.0, 12.0, -launch_speed);
+        let mut y = ground_y;
+        let mut grounded = false;
+        let mut landings = 0;
+
+        // Strafe-jump for a few seconds, holding jump the whole time (auto-bhop)
+        for frame in 0..300 {
+            // Alternate strafe direction like a real strafe-jump, slightly forward
+            let side = if (frame / 30) % 2 == 0 { 1.0 } else { -1.0 };
+            let horiz = Vec3::new(velocity.x, 0.0, velocity.z).normalize();
+            let wish_dir = (horiz.cross(Vec3::Y) * side + horiz * 0.1).normalize();
+
+            let (new_velocity, still_grounded) =
+                move_player(velocity, wish_dir, grounded, true, &config, Vec3::Y, dt);
+            velocity = new_velocity;
+            grounded = still_grounded;
+
+            // Gravity and integration (mirrors apply_gravity/player_collision)
+            if !grounded {
+                velocity += gravity.acceleration() * dt;
+            }
+            y += velocity.y * dt;
+            if y <= ground_y {
+                y = ground_y;
+                velocity.y = velocity.y.max(0.0);
+                grounded = true;
+                landings += 1;
+            }
+        }
+
+        // Landed and jumped several times without bleeding speed to friction or the cap
+        assert!(landings >= 2);
+        let horiz_speed = Vec2::new(velocity.x, velocity.z).length();
+        assert!(horiz_speed >= launch_speed - 0.5, "speed dropped to {horiz_speed}");
+    }
+
+    #[test]
+    fn test_landing_without_jump_applies_friction() {
+        let config = MovementConfig::default();
+        let velocity = Vec3::new(0.0, 0.0, -30.0);
+
+        let (result, grounded) =
+            move_player(velocity, Vec3::ZERO, true, false, &config, Vec3::Y, 0.016);
+
+        assert!(grounded);
+        assert!(result.length() < 30.0);
+    }
+
+    // ==================== Gravity Tests ====================
+
+    #[test]
+    fn test_gravity_config_defaults() {
+        let gravity = GravityConfig::default();
+
+        assert!(vec3_approx_eq(gravity.up(), Vec3::Y));
+        assert!(vec3_approx_eq(gravity.acceleration(), Vec3::new(0.0, -12.0, 0.0)));
+        assert!(gravity.is_downward());
+        assert!(vec3_approx_eq(gravity.orientation() * Vec3::Y, Vec3::Y));
+    }
+
+    #[test]
+    fn test_flipped_gravity_jumps_downward() {
+        let config = MovementConfig::default();
+        let flipped = GravityConfig {
+            direction: Vec3::Y,
+            ..default()
+        };
+        assert!(!flipped.is_downward());
+
+        let (normal, _) = move_player(Vec3::ZERO, Vec3::ZERO, true, true, &config, Vec3::Y, 0.016);
+        let (jump, grounded) =
+            move_player(Vec3::ZERO, Vec3::ZERO, true, true, &config, flipped.up(), 0.016);
+
+        // Same jump, opposite direction
+        assert!(!grounded);
+        assert!(approx_eq(normal.y, config.sv_jumpspeed));
+        assert!(approx_eq(jump.y, -config.sv_jumpspeed));
+        assert!(approx_eq(jump.x, 0.0) && approx_eq(jump.z, 0.0));
+
+        // And gravity pulls back toward the ceiling
+        assert!(flipped.acceleration().dot(jump) < 0.0);
+    }
+
+    #[test]
+    fn test_wall_gravity_moves_along_wall() {
+        let config = MovementConfig::default();
+        let up = Vec3::X;
+
+        // Running "forward" on a wall: the wish component along up is dropped
+        let wish_dir = Vec3::new(0.5, 0.0, -1.0).normalize();
+        let (velocity, grounded) = move_player(Vec3::ZERO, wish_dir, true, false, &config, up, 0.1);
+        assert!(grounded);
+        assert!(approx_eq(velocity.x, 0.0));
+        assert!(velocity.z < 0.0);
+
+        // Jumping pushes away from the wall
+        let (jump, _) = move_player(Vec3::ZERO, Vec3::ZERO, true, true, &config, up, 0.016);
+        assert!(approx_eq(jump.x, config.sv_jumpspeed));
+    }
+
+    // ==================== Dash Tests ====================
+
+    #[test]
+    fn test_dash_velocity_keeps_vertical_and_faster_momentum() {
+        let up = Vec3::Y;
+
+        // From a standstill mid-fall: horizontal snaps to dash speed, fall speed kept
+        let dashed = dash_velocity(Vec3::new(0.0, -3.0, 0.0), Vec3::NEG_Z, up, 20.0);
+        assert!(vec3_approx_eq(dashed, Vec3::new(0.0, -3.0, -20.0)));
+
+        // Already faster than a dash: speed kept, direction changed
+        let dashed = dash_velocity(Vec3::new(30.0, 0.0, 0.0), Vec3::NEG_Z, up, 20.0);
+        assert!(vec3_approx_eq(dashed, Vec3::new(0.0, 0.0, -30.0)));
+
+        // No horizontal direction - nothing happens
+        let velocity = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(dash_velocity(velocity, Vec3::Y, up, 20.0), velocity);
+    }
 }
@@ -0,0 +1,112 @@
+use bevy::ecs::hierarchy::ChildOf;
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+
+use super::input::{Action, ActionInput};
+use super::movement::{PlayerState, WishDir};
+use super::{LookConfig, Player, PlayerCamera};
+
+/// Debug toggle for the noclip camera - works in any game state
+pub const NOCLIP_KEY: KeyCode = KeyCode::F11;
+
+/// Noclip fly speed (units/sec)
+pub const NOCLIP_SPEED: f32 = 15.0;
+
+/// Player camera detached into a free-flying noclip view. Unparented from the player,
+/// so it ignores collision and leaves the body where it stood.
+#[derive(Component)]
+pub struct NoclipCamera {
+    /// Camera local transform when noclip started, restored when it ends
+    pub start: Transform,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+pub fn not_in_noclip(noclip_query: Query<(), With<NoclipCamera>>) -> bool {
+    noclip_query.is_empty()
+}
+
+/// F11 detaches the player camera where it is, and snaps it back to the player's eyes
+pub fn toggle_noclip(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut camera_query: Query<(Entity, &mut Transform, &GlobalTransform, Option<&NoclipCamera>), With<PlayerCamera>>,
+    mut player_query: Query<(Entity, &mut WishDir, &mut PlayerState), With<Player>>,
+) {
+    if !keyboard.just_pressed(NOCLIP_KEY) {
+        return;
+    }
+
+    let Ok((camera, mut transform, global_transform, noclip)) = camera_query.single_mut() else {
+        return;
+    };
+    let Ok((player, mut wish_dir, mut player_state)) = player_query.single_mut() else {
+        return;
+    };
+
+    match noclip {
+        Some(noclip) => {
+            *transform = noclip.start;
+            commands.entity(camera).remove::<NoclipCamera>().insert(ChildOf(player));
+            info!("Noclip: OFF");
+        }
+        None => {
+            // Drop held input so the body doesn't keep walking while the camera is away
+            wish_dir.0 = Vec3::ZERO;
+            player_state.wish_jump = false;
+
+            let world_transform = global_transform.compute_transform();
+            let (yaw, pitch, _) = world_transform.rotation.to_euler(EulerRot::YXZ);
+            commands.entity(camera).remove::<ChildOf>().insert(NoclipCamera {
+                start: *transform,
+                yaw,
+                pitch,
+            });
+            *transform = world_transform;
+            info!("Noclip: ON");
+        }
+    }
+}
+
+/// Fly the noclip camera - mouse look, movement keys along the view, E/Q up/down
+pub fn fly_noclip_camera(
+    input: ActionInput,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut camera_query: Query<(&mut Transform, &mut NoclipCamera)>,
+    look: Res<LookConfig>,
+    time: Res<Time>,
+) {
+    let delta: Vec2 = mouse_motion.read().map(|event| event.delta).sum();
+
+    let Ok((mut transform, mut noclip)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let look_delta = look.look_delta(delta);
+    noclip.yaw += look_delta.x;
+    noclip.pitch = (noclip.pitch + look_delta.y).clamp(-1.5, 1.5);
+    transform.rotation = Quat::from_euler(EulerRot::YXZ, noclip.yaw, noclip.pitch, 0.0);
+
+    let mut direction = Vec3::ZERO;
+    if input.pressed(Action::MoveForward) {
+        direction += *transform.forward();
+    }
+    if input.pressed(Action::MoveBack) {
+        direction -= *transform.forward();
+    }
+    if input.pressed(Action::MoveRight) {
+        direction += *transform.right();
+    }
+    if input.pressed(Action::MoveLeft) {
+        direction -= *transform.right();
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        direction += Vec3::Y;
+    }
+    if keyboard.pressed(KeyCode::KeyQ) {
+        direction -= Vec3::Y;
+    }
+
+    transform.translation += direction.normalize_or_zero() * NOCLIP_SPEED * time.delta_secs();
+}
@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+
+use super::movement::MovementConfig;
+
+/// Runtime movement tuning - F6 toggles it, then bracket/semicolon/comma keys
+/// nudge the bhop-critical `MovementConfig` values without recompiling
+#[derive(Resource, Default)]
+pub struct MovementTuning {
+    pub enabled: bool,
+}
+
+/// One tweakable `MovementConfig` field
+pub struct TuningKnob {
+    pub decrease: KeyCode,
+    pub increase: KeyCode,
+    pub step: f32,
+    /// Sane bounds - values are clamped to this range
+    pub min: f32,
+    pub max: f32,
+    pub field: fn(&mut MovementConfig) -> &mut f32,
+}
+
+impl TuningKnob {
+    /// Step the field by `steps` increments, staying within bounds
+    pub fn adjust(&self, config: &mut MovementConfig, steps: f32) {
+        let value = (self.field)(config);
+        *value = (*value + self.step * steps).clamp(self.min, self.max);
+    }
+}
+
+pub const TUNING_KNOBS: [TuningKnob; 3] = [
+    TuningKnob {
+        decrease: KeyCode::BracketLeft,
+        increase: KeyCode::BracketRight,
+        step: 1.0,
+        min: 0.0,
+        max: 150.0,
+        field: |config| &mut config.sv_airaccelerate,
+    },
+    TuningKnob {
+        decrease: KeyCode::Semicolon,
+        increase: KeyCode::Quote,
+        step: 0.5,
+        min: 0.0,
+        max: 20.0,
+        field: |config| &mut config.sv_friction,
+    },
+    TuningKnob {
+        decrease: KeyCode::Comma,
+        increase: KeyCode::Period,
+        step: 0.5,
+        min: 1.0,
+        max: 30.0,
+        field: |config| &mut config.sv_maxspeed,
+    },
+];
+
+fn log_tuning(config: &MovementConfig) {
+    info!(
+        "Movement tuning: sv_airaccelerate={:.1} sv_friction={:.1} sv_maxspeed={:.1}",
+        config.sv_airaccelerate, config.sv_friction, config.sv_maxspeed
+    );
+}
+
+/// Toggle tuning with F6 and apply knob keys while it's on
+pub fn tune_movement(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut tuning: ResMut<MovementTuning>,
+    mut config: ResMut<MovementConfig>,
+) {
+    if keyboard.just_pressed(KeyCode::F6) {
+        tuning.enabled = !tuning.enabled;
+        info!(
+            "Movement tuning {}",
+            if tuning.enabled { "ON - [ ] airaccel, ; ' friction, , . maxspeed" } else { "OFF" }
+        );
+        if tuning.enabled {
+            log_tuning(&config);
+        }
+    }
+
+    if !tuning.enabled {
+        return;
+    }
+
+    let mut changed = false;
+    for knob in &TUNING_KNOBS {
+        if keyboard.just_pressed(knob.decrease) {
+            knob.adjust(&mut config, -1.0);
+            changed = true;
+        }
+        if keyboard.just_pressed(knob.increase) {
+            knob.adjust(&mut config, 1.0);
+            changed = true;
+        }
+    }
+
+    if changed {
+        log_tuning(&config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+
+    fn tuning_test_app() -> App {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<MovementConfig>()
+            .init_resource::<MovementTuning>()
+            .add_systems(Update, tune_movement);
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        keyboard.release_all();
+        keyboard.press(key);
+        app.update();
+    }
+
+    #[test]
+    fn test_keys_adjust_config_when_enabled() {
+        let mut app = tuning_test_app();
+        let default = MovementConfig::default();
+
+        press(&mut app, KeyCode::F6);
+        press(&mut app, KeyCode::BracketRight);
+        press(&mut app, KeyCode::Semicolon);
+
+        let config = app.world().resource::<MovementConfig>();
+        assert_eq!(config.sv_airaccelerate, default.sv_airaccelerate + 1.0);
+        assert_eq!(config.sv_friction, default.sv_friction - 0.5);
+        assert_eq!(config.sv_maxspeed, default.sv_maxspeed);
+    }
+
+    #[test]
+    fn test_keys_ignored_when_disabled() {
+        let mut app = tuning_test_app();
+        press(&mut app, KeyCode::BracketRight);
+
+        let config = app.world().resource::<MovementConfig>();
+        assert_eq!(config.sv_airaccelerate, MovementConfig::default().sv_airaccelerate);
+    }
+
+    #[test]
+    fn test_knobs_stay_in_bounds() {
+        let mut config = MovementConfig::default();
+        for knob in &TUNING_KNOBS {
+            knob.adjust(&mut config, -1000.0);
+            assert_eq!(*(knob.field)(&mut config), knob.min);
+            knob.adjust(&mut config, 1000.0);
+            assert_eq!(*(knob.field)(&mut config), knob.max);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// User-supplied character ramp for AsciiPattern::Custom
+// Characters are looked up in a small 5x7 bitmap font and packed into the AsciiSettings uniform
+
+use bevy::prelude::*;
+
+use super::AsciiSettings;
+
+/// Most glyphs a custom ramp can hold (size of the uniform array)
+pub const MAX_RAMP_GLYPHS: usize = 16;
+
+/// Ramp used when none (or an unusable one) is given - same as the Standard pattern
+pub const DEFAULT_RAMP: &str = " .:-=+*#%@";
+
+/// Custom density ramp, darkest to brightest - e.g. `" .:oO#@"`.
+/// Insert or change this resource to switch every ASCII camera to it at runtime.
+#[derive(Resource, Clone, Debug, PartialEq)]
+pub struct CustomRamp {
+    text: String,
+}
+
+impl CustomRamp {
+    /// Validate a ramp: characters without a glyph are dropped, it's truncated to
+    /// MAX_RAMP_GLYPHS, and anything shorter than two glyphs falls back to the default
+    pub fn new(text: &str) -> Self {
+        let mut valid: String = text.chars().filter(|&c| glyph_rows(c).is_some()).collect();
+        if valid.chars().count() != text.chars().count() {
+            warn!("Custom ASCII ramp {text:?}: dropped characters without a glyph");
+        }
+
+        valid = valid.chars().take(MAX_RAMP_GLYPHS).collect();
+        if valid.chars().count() < 2 {
+            warn!("Custom ASCII ramp {text:?} is too short, using the default ramp");
+            valid = DEFAULT_RAMP.to_string();
+        }
+
+        Self { text: valid }
+    }
+
+    /// The validated ramp
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Number of glyphs in the ramp
+    pub fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// GPU layout: one UVec4 per glyph, x = rows 0-5 (5 bits each, row 0 lowest), y = row 6
+    pub fn uniform_data(&self) -> [UVec4; MAX_RAMP_GLYPHS] {
+        let mut data = [UVec4::ZERO; MAX_RAMP_GLYPHS];
+        for (slot, c) in data.iter_mut().zip(self.text.chars()) {
+            *slot = pack_glyph(glyph_rows(c).unwrap_or_default());
+        }
+        data
+    }
+}
+
+impl Default for CustomRamp {
+    fn default() -> Self {
+        Self::new(DEFAULT_RAMP)
+    }
+}
+
+/// Every character with a glyph in the bitmap font
+pub const GLYPH_CHARS: &str = " .,:;'\"`-_~^=+*#%@&$!?|/\\()[]<>0123456789cilovxBHMNOWX";
+
+/// Recover the ramp text from packed uniform data (e.g. a camera set up with with_custom_ramp)
+pub fn ramp_text_from_uniform(len: f32, data: &[UVec4; MAX_RAMP_GLYPHS]) -> String {
+    data.iter()
+        .take(len as usize)
+        .filter_map(|&packed| {
+            GLYPH_CHARS
+                .chars()
+                .find(|&c| glyph_rows(c).map(pack_glyph) == Some(packed))
+        })
+        .collect()
+}
+
+/// Pack a 5x7 glyph into the uniform layout
+pub fn pack_glyph(rows: [u8; 7]) -> UVec4 {
+    let low = rows[..6]
+        .iter()
+        .enumerate()
+        .fold(0u32, |bits, (row, &value)| bits | (value as u32 & 31) << (row * 5));
+    UVec4::new(low, rows[6] as u32 & 31, 0, 0)
+}
+
+/// 5x7 bitmap for a character - each row's bit 4 is the leftmost pixel
+pub fn glyph_rows(c: char) -> Option<[u8; 7]> {
+    let rows = match c {
+        ' ' => [0, 0, 0, 0, 0, 0, 0],
+        '.' => [0, 0, 0, 0, 0, 4, 4],
+        ',' => [0, 0, 0, 0, 4, 4, 8],
+        ':' => [0, 4, 4, 0, 4, 4, 0],
+        ';' => [0, 4, 4, 0, 4, 4, 8],
+        '\'' => [4, 4, 0, 0, 0, 0, 0],
+        '"' => [10, 10, 0, 0, 0, 0, 0],
+        '`' => [8, 4, 0, 0, 0, 0, 0],
+        '-' => [0, 0, 0, 31, 0, 0, 0],
+        '_' => [0, 0, 0, 0, 0, 0, 31],
+        '~' => [0, 0, 8, 21, 2, 0, 0],
+        '^' => [4, 10, 17, 0, 0, 0, 0],
+        '=' => [0, 0, 31, 0, 31, 0, 0],
+        '+' => [0, 4, 4, 31, 4, 4, 0],
+        '*' => [0, 21, 14, 31, 14, 21, 0],
+        '#' => [10, 31, 10, 10, 31, 10, 0],
+        '%' => [19, 19, 4, 4, 25, 25, 0],
+        '@' => [14, 17, 23, 21, 23, 16, 14],
+        '&' => [12, 18, 20, 8, 21, 18, 13],
+        '$' => [4, 15, 20, 14, 5, 30, 4],
+        '!' => [4, 4, 4, 4, 4, 0, 4],
+        '?' => [14, 17, 1, 2, 4, 0, 4],
+        '|' => [4, 4, 4, 4, 4, 4, 4],
+        '/' => [1, 1, 2, 4, 8, 16, 16],
+        '\\' => [16, 16, 8, 4, 2, 1, 1],
+        '(' => [2, 4, 8, 8, 8, 4, 2],
+        ')' => [8, 4, 2, 2, 2, 4, 8],
+        '[' => [14, 8, 8, 8, 8, 8, 14],
+        ']' => [14, 2, 2, 2, 2, 2, 14],
+        '<' => [2, 4, 8, 16, 8, 4, 2],
+        '>' => [8, 4, 2, 1, 2, 4, 8],
+        '0' => [14, 17, 19, 21, 25, 17, 14],
+        '1' => [4, 12, 4, 4, 4, 4, 14],
+        '2' => [14, 17, 1, 2, 4, 8, 31],
+        '3' => [31, 2, 4, 2, 1, 17, 14],
+        '4' => [2, 6, 10, 18, 31, 2, 2],
+        '5' => [31, 16, 30, 1, 1, 17, 14],
+        '6' => [6, 8, 16, 30, 17, 17, 14],
+        '7' => [31, 1, 2, 4, 8, 8, 8],
+        '8' => [14, 17, 17, 14, 17, 17, 14],
+        '9' => [14, 17, 17, 15, 1, 2, 12],
+        'c' => [0, 0, 14, 16, 16, 17, 14],
+        'i' => [4, 0, 12, 4, 4, 4, 14],
+        'l' => [12, 4, 4, 4, 4, 4, 14],
+        'o' => [0, 0, 14, 17, 17, 17, 14],
+        'v' => [0, 0, 17, 17, 17, 10, 4],
+        'x' => [0, 0, 17, 10, 4, 10, 17],
+        'B' => [30, 17, 17, 30, 17, 17, 30],
+        'H' => [17, 17, 17, 31, 17, 17, 17],
+        'M' => [17, 27, 21, 21, 17, 17, 17],
+        'N' => [17, 17, 25, 21, 19, 17, 17],
+        'O' => [14, 17, 17, 17, 17, 17, 14],
+        'W' => [17, 17, 17, 21, 21, 21, 10],
+        'X' => [17, 17, 10, 4, 10, 17, 17],
+        _ => return None,
+    };
+    Some(rows)
+}
+
+/// Push CustomRamp changes into every ASCII camera and show them as the global pattern
+pub fn sync_custom_ramp(ramp: Res<CustomRamp>, mut settings: Query<&mut AsciiSettings>) {
+    info!("Custom ASCII ramp: {:?}", ramp.text());
+    for mut setting in &mut settings {
+        *setting = setting.with_custom_ramp(ramp.text());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::*;
+    use crate::rendering::AsciiPattern;
+
+    #[test]
+    fn test_ramp_is_truncated_to_max_glyphs() {
+        let ramp = CustomRamp::new(" .,:;-=+*#%@&$0123456789");
+        assert_eq!(ramp.len(), MAX_RAMP_GLYPHS);
+        assert_eq!(ramp.text(), " .,:;-=+*#%@&$01");
+    }
+
+    #[test]
+    fn test_ramp_drops_unknown_characters() {
+        let ramp = CustomRamp::new(" .:\u{2588}oO#@");
+        assert_eq!(ramp.text(), " .:oO#@");
+    }
+
+    #[test]
+    fn test_short_ramp_falls_back_to_default() {
+        assert_eq!(CustomRamp::new("#").text(), DEFAULT_RAMP);
+        assert_eq!(CustomRamp::new("").text(), DEFAULT_RAMP);
+    }
+
+    #[test]
+    fn test_uniform_data_matches_ramp() {
+        let ramp = CustomRamp::new(" .:oO#@");
+        let data = ramp.uniform_data();
+
+        for (i, c) in " .:oO#@".chars().enumerate() {
+            assert_eq!(data[i], pack_glyph(glyph_rows(c).unwrap()));
+        }
+        // Unused slots stay empty
+        assert!(data[7..].iter().all(|slot| *slot == UVec4::ZERO));
+
+        // '.' is the two bottom-center pixels: row 5 in the low word, row 6 in the high word
+        assert_eq!(data[1], UVec4::new(4 << 25, 4, 0, 0));
+    }
+
+    #[test]
+    fn test_glyph_chars_all_have_glyphs() {
+        assert!(GLYPH_CHARS.chars().all(|c| glyph_rows(c).is_some()));
+    }
+
+    #[test]
+    fn test_ramp_text_round_trips_through_uniform() {
+        let settings = AsciiSettings::default().with_custom_ramp(" .:oO#@");
+        assert_eq!(ramp_text_from_uniform(settings.custom_ramp_len, &settings.custom_ramp), " .:oO#@");
+    }
+
+    #[test]
+    fn test_with_custom_ramp_fills_settings() {
+        let settings = AsciiSettings::default().with_custom_ramp(" .:oO#@");
+        let ramp = CustomRamp::new(" .:oO#@");
+
+        assert_eq!(settings.global_pattern, AsciiPattern::Custom.as_id() as f32);
+        assert_eq!(settings.custom_ramp_len, 7.0);
+        assert_eq!(settings.custom_ramp, ramp.uniform_data());
+    }
+
+    #[test]
+    fn test_changing_resource_updates_cameras() {
+        let mut app = test_app();
+        app.add_systems(
+            Update,
+            sync_custom_ramp.run_if(resource_exists_and_changed::<CustomRamp>),
+        );
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+        app.update();
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().custom_ramp_len, 10.0);
+
+        app.insert_resource(CustomRamp::new("-=#"));
+        app.update();
+        let settings = app.world().get::<AsciiSettings>(camera).unwrap();
+        assert_eq!(settings.custom_ramp_len, 3.0);
+        assert_eq!(settings.custom_ramp, CustomRamp::new("-=#").uniform_data());
+        assert_eq!(settings.global_pattern, AsciiPattern::Custom.as_id() as f32);
+    }
+}
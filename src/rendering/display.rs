@@ -0,0 +1,158 @@
+// Display options - windowed/borderless fullscreen toggle (Alt+Enter)
+
+use bevy::prelude::*;
+use bevy::window::{Monitor, MonitorSelection, PrimaryMonitor, WindowMode};
+
+/// Window resolution when not fullscreen
+pub const WINDOWED_RESOLUTION: Vec2 = Vec2::new(1280.0, 720.0);
+
+/// Display options, applied to the primary window whenever they change
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct DisplayOptions {
+    pub fullscreen: bool,
+    /// Logical window size used in windowed mode
+    pub windowed_resolution: Vec2,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            windowed_resolution: WINDOWED_RESOLUTION,
+        }
+    }
+}
+
+impl DisplayOptions {
+    pub fn window_mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        }
+    }
+}
+
+/// Alt+Enter toggles between windowed and borderless fullscreen
+pub fn toggle_fullscreen(keyboard: Res<ButtonInput<KeyCode>>, mut options: ResMut<DisplayOptions>) {
+    let alt = keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    if alt && keyboard.just_pressed(KeyCode::Enter) {
+        options.fullscreen = !options.fullscreen;
+        info!("Fullscreen: {}", if options.fullscreen { "ON" } else { "OFF" });
+    }
+}
+
+/// Push the display options to the window. Going fullscreen takes the primary monitor's size
+/// right away, so the pattern render target and ASCII grid resize on the same frame instead of
+/// waiting for the OS to report the new size.
+pub fn apply_display_options(
+    options: Res<DisplayOptions>,
+    mut windows: Query<&mut Window>,
+    monitors: Query<&Monitor, With<PrimaryMonitor>>,
+) {
+    let Ok(mut window) = windows.single_mut() else {
+        return;
+    };
+
+    let mode = options.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+
+    if options.fullscreen {
+        if let Ok(monitor) = monitors.single() {
+            window
+                .resolution
+                .set_physical_resolution(monitor.physical_width, monitor.physical_height);
+        }
+    } else {
+        window
+            .resolution
+            .set(options.windowed_resolution.x, options.windowed_resolution.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{update_ascii_resolution, update_pattern_render_target_size, AsciiSettings, PatternRenderTarget};
+    use super::*;
+    use crate::test_utils::*;
+    use bevy::render::render_resource::Extent3d;
+
+    fn press_alt_enter(app: &mut App) {
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(KeyCode::AltLeft);
+        keyboard.press(KeyCode::Enter);
+    }
+
+    fn pattern_target_size(app: &App) -> Extent3d {
+        let handle = &app.world().resource::<PatternRenderTarget>().image;
+        app.world().resource::<Assets<Image>>().get(handle).unwrap().texture_descriptor.size
+    }
+
+    #[test]
+    fn test_fullscreen_toggle_resizes_pattern_target() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<DisplayOptions>()
+            .init_resource::<Assets<Image>>()
+            .add_systems(
+                Update,
+                (
+                    toggle_fullscreen,
+                    apply_display_options.run_if(resource_changed::<DisplayOptions>),
+                    update_pattern_render_target_size,
+                    update_ascii_resolution,
+                )
+                    .chain(),
+            );
+
+        let mut image = Image::default();
+        image.resize(Extent3d {
+            width: 1280,
+            height: 720,
+            depth_or_array_layers: 1,
+        });
+        let image = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+        app.insert_resource(PatternRenderTarget { image });
+
+        let window = app
+            .world_mut()
+            .spawn(Window {
+                resolution: (1280.0, 720.0).into(),
+                ..default()
+            })
+            .id();
+        let settings = app.world_mut().spawn(AsciiSettings::default()).id();
+        app.world_mut().spawn((
+            Monitor {
+                name: None,
+                physical_width: 1920,
+                physical_height: 1080,
+                physical_position: IVec2::ZERO,
+                refresh_rate_millihertz: None,
+                scale_factor: 1.0,
+                video_modes: Vec::new(),
+            },
+            PrimaryMonitor,
+        ));
+
+        press_alt_enter(&mut app);
+        app.update();
+        assert!(matches!(
+            app.world().get::<Window>(window).unwrap().mode,
+            WindowMode::BorderlessFullscreen(_)
+        ));
+        let size = pattern_target_size(&app);
+        assert_eq!((size.width, size.height), (1920, 1080));
+        assert_eq!(app.world().get::<AsciiSettings>(settings).unwrap().resolution, Vec2::new(1920.0, 1080.0));
+
+        press_alt_enter(&mut app);
+        app.update();
+        assert_eq!(app.world().get::<Window>(window).unwrap().mode, WindowMode::Windowed);
+        let size = pattern_target_size(&app);
+        assert_eq!((size.width, size.height), (1280, 720));
+    }
+}
@@ -1,7 +1,10 @@
 // ASCII Post-Processing Effect with Per-Object Pattern Support
 // Renders the scene as ASCII art with optional per-object character patterns
 
+mod custom_ramp;
+mod display;
 mod pattern_material;
+mod text_export;
 
 use bevy::{
     core_pipeline::fullscreen_vertex_shader::fullscreen_shader_vertex_state,
@@ -10,7 +13,7 @@ use bevy::{
     render::{
         camera::RenderTarget,
         extract_component::{
-            ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+            ComponentUniforms, DynamicUniformIndex, ExtractComponentPlugin,
             UniformComponentPlugin,
         },
         extract_resource::{ExtractResource, ExtractResourcePlugin},
@@ -31,7 +34,12 @@ use bevy::{
     core_pipeline::core_3d::graph::{Core3d, Node3d},
 };
 
+pub use custom_ramp::{CustomRamp, MAX_RAMP_GLYPHS};
 pub use pattern_material::{PatternIdMaterial, PatternMaterialPlugin};
+pub use display::{DisplayOptions, WINDOWED_RESOLUTION};
+use custom_ramp::sync_custom_ramp;
+use display::{apply_display_options, toggle_fullscreen};
+use text_export::export_ascii_frame;
 
 const ASCII_SHADER_PATH: &str = "shaders/ascii.wgsl";
 
@@ -49,11 +57,35 @@ impl Plugin for AsciiRenderPlugin {
             PatternMaterialPlugin,
         ))
         .init_resource::<PatternRenderTarget>()
+        .init_resource::<RenderQuality>()
+        .init_resource::<PerObjectFilter>()
+        .init_resource::<AsciiPreset>()
+        .init_resource::<DisplayOptions>()
         .add_systems(Startup, setup_pattern_camera)
         .add_systems(Update, (
+            (toggle_fullscreen, apply_display_options.run_if(resource_changed::<DisplayOptions>))
+                .chain()
+                .before(update_pattern_render_target_size)
+                .before(update_ascii_resolution),
+            apply_render_quality,
             sync_pattern_meshes,
             sync_pattern_camera_transform,
             update_pattern_render_target_size,
+        ))
+        // Runtime controls (F1-F5, F9, F10, F12, -/=) and per-frame uniforms
+        .add_systems(Update, (
+            update_ascii_resolution,
+            update_ascii_time,
+            (cycle_ascii_preset, apply_ascii_preset.run_if(resource_changed::<AsciiPreset>)).chain(),
+            adjust_cell_size,
+            toggle_ascii_monochrome,
+            toggle_per_object_mode,
+            cycle_global_pattern,
+            cycle_render_quality,
+            toggle_edge_outlines,
+            toggle_crt_overlay,
+            export_ascii_frame,
+            sync_custom_ramp.run_if(resource_exists_and_changed::<CustomRamp>),
         ));
 
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
@@ -102,12 +134,17 @@ pub struct PatternMesh {
     pub source: Entity,
 }
 
-/// Setup the pattern camera that renders to a texture
+/// Marker for source objects that already have a pattern mesh
+#[derive(Component)]
+pub struct HasPatternMesh;
+
+/// Setup the pattern render target, and the pattern camera unless running low-spec
 fn setup_pattern_camera(
     mut commands: Commands,
     mut images: ResMut<Assets<Image>>,
     mut pattern_target: ResMut<PatternRenderTarget>,
     windows: Query<&Window>,
+    quality: Res<RenderQuality>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -139,14 +176,17 @@ fn setup_pattern_camera(
     let image_handle = images.add(image);
     pattern_target.image = image_handle.clone();
 
-    // Spawn pattern camera - renders only layer 1
-    // Must match main camera projection for correct alignment
+    if quality.pattern_pass_enabled() {
+        spawn_pattern_camera(&mut commands, image_handle);
+    }
+}
+
+/// Spawn pattern camera - renders only layer 1
+fn spawn_pattern_camera(commands: &mut Commands, image_handle: Handle<Image>) {
+    // Projection is copied from the main camera every frame by sync_pattern_camera_transform
     commands.spawn((
         Camera3d::default(),
-        Projection::Perspective(PerspectiveProjection {
-            fov: 100.0_f32.to_radians(), // Match main camera FOV
-            ..default()
-        }),
+        Projection::Perspective(PerspectiveProjection::default()),
         Camera {
             order: -1, // Render before main camera
             target: RenderTarget::Image(image_handle.into()),
@@ -159,18 +199,69 @@ fn setup_pattern_camera(
     ));
 }
 
-/// Sync pattern camera transform with main camera
+/// Switch the pattern pass on/off when RenderQuality changes.
+/// Low-spec despawns the pattern camera and all pattern meshes and forces the global pattern.
+#[allow(clippy::too_many_arguments)]
+fn apply_render_quality(
+    mut commands: Commands,
+    quality: Res<RenderQuality>,
+    filter: Res<PerObjectFilter>,
+    pattern_target: Res<PatternRenderTarget>,
+    pattern_cameras: Query<Entity, With<PatternCamera>>,
+    pattern_meshes: Query<Entity, With<PatternMesh>>,
+    linked_sources: Query<Entity, With<HasPatternMesh>>,
+    mut settings: Query<&mut AsciiSettings>,
+) {
+    // Startup already spawned the right camera setup
+    if !quality.is_changed() || quality.is_added() {
+        return;
+    }
+
+    if quality.pattern_pass_enabled() {
+        if pattern_cameras.is_empty() {
+            spawn_pattern_camera(&mut commands, pattern_target.image.clone());
+        }
+        // Pattern meshes are recreated by sync_pattern_meshes
+        for mut setting in &mut settings {
+            setting.apply_per_object_filter(*filter);
+        }
+    } else {
+        for entity in pattern_cameras.iter().chain(pattern_meshes.iter()) {
+            commands.entity(entity).despawn();
+        }
+        for entity in &linked_sources {
+            commands.entity(entity).remove::<HasPatternMesh>();
+        }
+        for mut setting in &mut settings {
+            setting.per_object_mode = 0.0;
+        }
+    }
+}
+
+/// Sync pattern camera transform and projection (FOV, aspect, clip planes) with main camera,
+/// so the pattern texture lines up with the scene even when the FOV changes (e.g. ADS zoom)
+#[allow(clippy::type_complexity)]
 fn sync_pattern_camera_transform(
-    main_camera: Query<&GlobalTransform, (With<Camera3d>, Without<PatternCamera>)>,
-    mut pattern_camera: Query<&mut Transform, With<PatternCamera>>,
+    main_camera: Query<(&GlobalTransform, &Projection), (With<Camera3d>, Without<PatternCamera>)>,
+    mut pattern_camera: Query<(&mut Transform, &mut Projection), With<PatternCamera>>,
 ) {
-    let Ok(main_transform) = main_camera.single() else {
+    let Ok((main_transform, main_projection)) = main_camera.single() else {
         return;
     };
-    let Ok(mut pattern_transform) = pattern_camera.single_mut() else {
+    let Ok((mut pattern_transform, mut pattern_projection)) = pattern_camera.single_mut() else {
         return;
     };
 
+    if let (Projection::Perspective(main), Projection::Perspective(pattern)) =
+        (main_projection, &mut *pattern_projection)
+        && (pattern.fov != main.fov
+            || pattern.aspect_ratio != main.aspect_ratio
+            || pattern.near != main.near
+            || pattern.far != main.far)
+    {
+        *pattern = main.clone();
+    }
+
     // Copy the global transform to local (pattern camera has no parent)
     let (scale, rotation, translation) = main_transform.to_scale_rotation_translation();
     pattern_transform.translation = translation;
@@ -193,10 +284,10 @@ fn update_pattern_render_target_size(
         depth_or_array_layers: 1,
     };
 
-    if let Some(image) = images.get_mut(&pattern_target.image) {
-        if image.texture_descriptor.size != new_size {
-            image.resize(new_size);
-        }
+    if let Some(image) = images.get_mut(&pattern_target.image)
+        && image.texture_descriptor.size != new_size
+    {
+        image.resize(new_size);
     }
 }
 
@@ -212,17 +303,25 @@ fn sync_pattern_meshes(
     // New objects that need pattern meshes created
     new_pattern_objects: Query<
         (Entity, &Mesh3d, &GlobalTransform, &AsciiPatternId),
-        Added<AsciiPatternId>,
+        Without<HasPatternMesh>,
     >,
     // Existing pattern meshes
     mut pattern_meshes: Query<(Entity, &PatternMesh, &mut Transform)>,
     // All pattern objects (for cleanup check)
     all_pattern_objects: Query<Entity, With<AsciiPatternId>>,
+    quality: Res<RenderQuality>,
 ) {
+    // Low-spec has no pattern camera, so there's nothing to render pattern meshes
+    if !quality.pattern_pass_enabled() {
+        return;
+    }
+
     // Create pattern meshes for new objects
     for (entity, mesh, transform, pattern_id) in &new_pattern_objects {
         let pattern_material = materials.add(PatternIdMaterial {
             pattern_id: pattern_id.pattern.as_id() as f32,
+            category: pattern_id.category.as_id() as f32,
+            force_color: if pattern_id.force_color { 1.0 } else { 0.0 },
         });
 
         let (scale, rotation, translation) = transform.to_scale_rotation_translation();
@@ -238,6 +337,7 @@ fn sync_pattern_meshes(
             RenderLayers::layer(PATTERN_RENDER_LAYER),
             PatternMesh { source: entity },
         ));
+        commands.entity(entity).insert(HasPatternMesh);
     }
 
     // Update transforms for existing pattern meshes
@@ -430,21 +530,45 @@ impl FromWorld for AsciiPipeline {
 // PUBLIC API
 // ============================================================================
 
-/// ASCII rendering settings - attach to camera to enable effect
-#[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
-pub struct AsciiSettings {
-    /// Character cell size in pixels (width, height)
-    pub cell_size: Vec2,
-    /// Screen resolution (set automatically)
-    pub resolution: Vec2,
-    /// 0.0 = colored, 1.0 = monochrome green
-    pub monochrome: f32,
-    /// 0.0 = global pattern, 1.0 = per-object patterns
-    pub per_object_mode: f32,
-    /// Global pattern ID (0-4) used when per_object_mode is 0
-    pub global_pattern: f32,
-    /// Animation time in seconds
-    pub time: f32,
+pub use ascii_settings::AsciiSettings;
+
+// ShaderType's derive emits per-field layout checks that are never called, which dead_code flags
+#[allow(dead_code)]
+mod ascii_settings {
+    use bevy::prelude::*;
+    use bevy::render::{extract_component::ExtractComponent, render_resource::ShaderType};
+    use super::MAX_RAMP_GLYPHS;
+
+    /// ASCII rendering settings - attach to camera to enable effect
+    #[derive(Component, Clone, Copy, ExtractComponent, ShaderType)]
+    pub struct AsciiSettings {
+        /// Character cell size in pixels (width, height)
+        pub cell_size: Vec2,
+        /// Screen resolution (set automatically)
+        pub resolution: Vec2,
+        /// 0.0 = colored, 1.0 = monochrome (tinted with mono_color)
+        pub monochrome: f32,
+        /// Linear RGB tint for monochrome mode - terminal green by default
+        pub mono_color: Vec3,
+        /// 0.0 = global pattern, 1.0 = per-object patterns
+        pub per_object_mode: f32,
+        /// Bitmask of AsciiCategory bits that use their own pattern in per-object mode
+        pub per_object_mask: f32,
+        /// Global pattern ID (0-6) used when per_object_mode is 0
+        pub global_pattern: f32,
+        /// Animation time in seconds
+        pub time: f32,
+        /// How strongly Sobel edges brighten outlines - 0.0 skips edge detection entirely
+        pub edge_strength: f32,
+        /// CRT scanline darkening on every other screen pixel row - 0.0 = off
+        pub scanline_intensity: f32,
+        /// CRT barrel distortion amount - 0.0 = flat screen
+        pub curvature: f32,
+        /// Number of glyphs in custom_ramp (AsciiPattern::Custom)
+        pub custom_ramp_len: f32,
+        /// Packed 5x7 glyphs for AsciiPattern::Custom - see CustomRamp::uniform_data
+        pub custom_ramp: [UVec4; MAX_RAMP_GLYPHS],
+    }
 }
 
 impl Default for AsciiSettings {
@@ -453,34 +577,36 @@ impl Default for AsciiSettings {
             cell_size: Vec2::new(5.0, 9.0), // HighRes as default
             resolution: Vec2::new(1280.0, 720.0),
             monochrome: 0.0,
+            mono_color: Vec3::new(0.0, 1.0, 0.3), // Terminal green
             per_object_mode: 1.0, // Per-object patterns enabled by default
+            per_object_mask: PerObjectFilter::All.mask() as f32,
             global_pattern: 0.0,
             time: 0.0,
+            edge_strength: 0.0,
+            scanline_intensity: 0.0,
+            curvature: 0.0,
+            custom_ramp_len: 0.0,
+            custom_ramp: [UVec4::ZERO; MAX_RAMP_GLYPHS],
         }
+        .with_ramp_data(&CustomRamp::default())
     }
 }
 
 impl AsciiSettings {
-    /// Create settings with custom cell size
-    pub fn new(cell_width: f32, cell_height: f32) -> Self {
-        Self {
-            cell_size: Vec2::new(cell_width, cell_height),
-            ..default()
-        }
-    }
-
-    /// Create monochrome (green terminal) settings
-    pub fn monochrome() -> Self {
+    /// Retro terminal look: chunky green-on-black monochrome with the standard ramp everywhere
+    pub fn terminal() -> Self {
         Self {
-            monochrome: 1.0,
+            per_object_mode: 0.0,
+            global_pattern: AsciiPattern::Standard.as_id() as f32,
             ..default()
         }
+        .with_preset(AsciiPreset::Terminal)
     }
 
-    /// Enable per-object pattern mode
-    pub fn with_per_object_patterns(mut self) -> Self {
-        self.per_object_mode = 1.0;
-        self
+    /// Apply a per-object filter preset
+    pub fn apply_per_object_filter(&mut self, filter: PerObjectFilter) {
+        self.per_object_mode = if filter == PerObjectFilter::Off { 0.0 } else { 1.0 };
+        self.per_object_mask = filter.mask() as f32;
     }
 
     /// Set monochrome mode
@@ -489,6 +615,48 @@ impl AsciiSettings {
         self
     }
 
+    /// Emphasize object outlines (0.0 = off, 1.0 = full)
+    pub fn with_edge_strength(mut self, strength: f32) -> Self {
+        self.edge_strength = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Retro CRT overlay: scanline darkening and barrel curvature (both 0.0-1.0, 0.0 = off)
+    pub fn with_crt(mut self, scanline_intensity: f32, curvature: f32) -> Self {
+        self.scanline_intensity = scanline_intensity.clamp(0.0, 1.0);
+        self.curvature = curvature.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether the CRT overlay is active
+    pub fn crt_enabled(&self) -> bool {
+        self.scanline_intensity > 0.0 || self.curvature > 0.0
+    }
+
+    /// Use a custom character ramp (darkest to brightest) as the global pattern, e.g. `" .:oO#@"`
+    pub fn with_custom_ramp(mut self, ramp: &str) -> Self {
+        self.set_custom_ramp(&CustomRamp::new(ramp));
+        self.global_pattern = AsciiPattern::Custom.as_id() as f32;
+        self
+    }
+
+    /// Upload a custom ramp's glyphs (used by AsciiPattern::Custom)
+    pub fn set_custom_ramp(&mut self, ramp: &CustomRamp) {
+        self.custom_ramp_len = ramp.len() as f32;
+        self.custom_ramp = ramp.uniform_data();
+    }
+
+    fn with_ramp_data(mut self, ramp: &CustomRamp) -> Self {
+        self.set_custom_ramp(ramp);
+        self
+    }
+
+    /// Start from a preset look (e.g. when spawning a camera)
+    pub fn with_preset(mut self, preset: AsciiPreset) -> Self {
+        self.apply_preset(preset);
+        self
+    }
+
     /// Apply a preset to these settings
     pub fn apply_preset(&mut self, preset: AsciiPreset) {
         match preset {
@@ -501,13 +669,32 @@ impl AsciiSettings {
             AsciiPreset::Classic => {
                 self.cell_size = Vec2::new(8.0, 14.0);
             }
-            AsciiPreset::Chunky => {
+            AsciiPreset::Chunky | AsciiPreset::Terminal => {
                 self.cell_size = Vec2::new(12.0, 20.0);
             }
         }
+        self.monochrome = if preset.monochrome() { 1.0 } else { 0.0 };
+    }
+
+    /// Nudge the cell size by a number of CELL_SIZE_STEPs (negative shrinks), clamped to the allowed range
+    pub fn adjust_cell_size(&mut self, steps: f32) {
+        self.cell_size = (self.cell_size + CELL_SIZE_STEP * steps).clamp(MIN_CELL_SIZE, MAX_CELL_SIZE);
+    }
+
+    /// Cell size the shader actually draws - stretched so a whole number of cells fills the screen
+    pub fn aligned_cell_size(&self) -> Vec2 {
+        let cells = (self.resolution / self.cell_size).floor().max(Vec2::ONE);
+        self.resolution / cells
     }
 }
 
+/// Cell size change per -/= press (keeps the 5:9 High-Res aspect)
+pub const CELL_SIZE_STEP: Vec2 = Vec2::new(0.5, 0.9);
+/// Smallest cell size reachable with -/= (Ultra preset)
+pub const MIN_CELL_SIZE: Vec2 = Vec2::new(3.0, 5.0);
+/// Largest cell size reachable with -/=
+pub const MAX_CELL_SIZE: Vec2 = Vec2::new(16.0, 28.0);
+
 /// Visual presets for ASCII rendering
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Resource)]
 pub enum AsciiPreset {
@@ -520,6 +707,8 @@ pub enum AsciiPreset {
     Classic,
     /// Chunky retro - large characters (12x20)
     Chunky,
+    /// Chunky characters in monochrome, like an old terminal (12x20)
+    Terminal,
 }
 
 impl AsciiPreset {
@@ -529,7 +718,19 @@ impl AsciiPreset {
             AsciiPreset::Ultra => AsciiPreset::HighRes,
             AsciiPreset::HighRes => AsciiPreset::Classic,
             AsciiPreset::Classic => AsciiPreset::Chunky,
-            AsciiPreset::Chunky => AsciiPreset::Ultra,
+            AsciiPreset::Chunky => AsciiPreset::Terminal,
+            AsciiPreset::Terminal => AsciiPreset::Ultra,
+        }
+    }
+
+    /// Cycle to the previous preset
+    pub fn prev(self) -> Self {
+        match self {
+            AsciiPreset::Ultra => AsciiPreset::Terminal,
+            AsciiPreset::HighRes => AsciiPreset::Ultra,
+            AsciiPreset::Classic => AsciiPreset::HighRes,
+            AsciiPreset::Chunky => AsciiPreset::Classic,
+            AsciiPreset::Terminal => AsciiPreset::Chunky,
         }
     }
 
@@ -540,8 +741,114 @@ impl AsciiPreset {
             AsciiPreset::HighRes => "High-Res (5x9)",
             AsciiPreset::Classic => "Classic (8x14)",
             AsciiPreset::Chunky => "Chunky (12x20)",
+            AsciiPreset::Terminal => "Terminal (12x20 mono)",
         }
     }
+
+    /// Whether this preset renders in monochrome
+    pub fn monochrome(&self) -> bool {
+        *self == AsciiPreset::Terminal
+    }
+}
+
+/// Rendering quality - low-spec skips the pattern camera's extra scene render
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Resource)]
+pub enum RenderQuality {
+    /// Full quality with per-object patterns
+    #[default]
+    High,
+    /// No pattern camera - global pattern only, per-object patterns unavailable
+    Low,
+}
+
+impl RenderQuality {
+    /// Cycle to the next quality level
+    pub fn next(self) -> Self {
+        match self {
+            RenderQuality::High => RenderQuality::Low,
+            RenderQuality::Low => RenderQuality::High,
+        }
+    }
+
+    /// Get display name for this quality level
+    pub fn name(&self) -> &'static str {
+        match self {
+            RenderQuality::High => "High",
+            RenderQuality::Low => "Low-Spec (global pattern only)",
+        }
+    }
+
+    /// Whether the pattern camera pass (and per-object patterns) is available
+    pub fn pattern_pass_enabled(&self) -> bool {
+        *self == RenderQuality::High
+    }
+}
+
+/// Which object categories use per-object patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Resource)]
+pub enum PerObjectFilter {
+    /// Every object uses its own pattern
+    #[default]
+    All,
+    /// Enemies use their pattern, the world uses the global pattern
+    EnemiesOnly,
+    /// Everything uses the global pattern
+    Off,
+}
+
+impl PerObjectFilter {
+    /// Cycle to the next filter
+    pub fn next(self) -> Self {
+        match self {
+            PerObjectFilter::All => PerObjectFilter::EnemiesOnly,
+            PerObjectFilter::EnemiesOnly => PerObjectFilter::Off,
+            PerObjectFilter::Off => PerObjectFilter::All,
+        }
+    }
+
+    /// Get display name for this filter
+    pub fn name(&self) -> &'static str {
+        match self {
+            PerObjectFilter::All => "All",
+            PerObjectFilter::EnemiesOnly => "Enemies Only",
+            PerObjectFilter::Off => "Off",
+        }
+    }
+
+    /// Category bitmask for this filter
+    pub fn mask(&self) -> u32 {
+        match self {
+            PerObjectFilter::All => {
+                AsciiCategory::World.bit() | AsciiCategory::Enemy.bit() | AsciiCategory::Effect.bit()
+            }
+            PerObjectFilter::EnemiesOnly => AsciiCategory::Enemy.bit(),
+            PerObjectFilter::Off => 0,
+        }
+    }
+}
+
+/// Object categories for filtering per-object patterns
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AsciiCategory {
+    /// Level geometry
+    #[default]
+    World = 0,
+    /// Enemies
+    Enemy = 1,
+    /// Projectiles, pickups and other effects
+    Effect = 2,
+}
+
+impl AsciiCategory {
+    /// Get the category ID as a u8 for GPU encoding
+    pub fn as_id(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Bit for this category in AsciiSettings::per_object_mask
+    pub fn bit(&self) -> u32 {
+        1 << self.as_id()
+    }
 }
 
 /// ASCII pattern types for per-object rendering
@@ -560,6 +867,8 @@ pub enum AsciiPattern {
     MatrixCycle = 4,
     /// Matrix Fall - true falling columns with fading trails
     MatrixFall = 5,
+    /// User-supplied ramp from CustomRamp / AsciiSettings::with_custom_ramp
+    Custom = 6,
 }
 
 impl AsciiPattern {
@@ -575,11 +884,31 @@ impl AsciiPattern {
 #[derive(Component, Clone, Copy, Default)]
 pub struct AsciiPatternId {
     pub pattern: AsciiPattern,
+    pub category: AsciiCategory,
+    /// Keep this object's colors in monochrome mode. Read from the pattern texture,
+    /// so like per-object patterns it needs per-object mode (not available in low-spec)
+    pub force_color: bool,
 }
 
 impl AsciiPatternId {
     pub fn new(pattern: AsciiPattern) -> Self {
-        Self { pattern }
+        Self {
+            pattern,
+            category: AsciiCategory::World,
+            force_color: false,
+        }
+    }
+
+    /// Set the category used by AsciiSettings::per_object_mask
+    pub fn with_category(mut self, category: AsciiCategory) -> Self {
+        self.category = category;
+        self
+    }
+
+    /// Render this object in color even when monochrome mode is on
+    pub fn with_force_color(mut self) -> Self {
+        self.force_color = true;
+        self
     }
 
     pub fn standard() -> Self {
@@ -622,7 +951,7 @@ pub fn update_ascii_resolution(windows: Query<&Window>, mut settings: Query<&mut
     }
 }
 
-/// System to update animation time for animated patterns
+/// System to update animation time for animated patterns (Binary, Matrix Cycle, Matrix Fall)
 pub fn update_ascii_time(time: Res<Time>, mut settings: Query<&mut AsciiSettings>) {
     let elapsed = time.elapsed_secs();
     for mut setting in &mut settings {
@@ -634,15 +963,37 @@ pub fn update_ascii_time(time: Res<Time>, mut settings: Query<&mut AsciiSettings
 pub fn cycle_ascii_preset(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut preset: ResMut<AsciiPreset>,
-    mut settings: Query<&mut AsciiSettings>,
 ) {
     if keyboard.just_pressed(KeyCode::F1) {
         *preset = preset.next();
         info!("ASCII Preset: {}", preset.name());
+    }
+}
 
-        for mut setting in &mut settings {
-            setting.apply_preset(*preset);
-        }
+/// Apply the current preset to every ASCII camera (after F1 or the settings menu change it)
+pub fn apply_ascii_preset(preset: Res<AsciiPreset>, mut settings: Query<&mut AsciiSettings>) {
+    for mut setting in &mut settings {
+        setting.apply_preset(*preset);
+    }
+}
+
+/// System to fine-tune the cell size with - and = keys (beyond the F1 presets)
+pub fn adjust_cell_size(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: Query<&mut AsciiSettings>,
+) {
+    // Brackets are taken by movement tuning (F6)
+    let steps = if keyboard.just_pressed(KeyCode::Equal) || keyboard.just_pressed(KeyCode::NumpadAdd) {
+        1.0
+    } else if keyboard.just_pressed(KeyCode::Minus) || keyboard.just_pressed(KeyCode::NumpadSubtract) {
+        -1.0
+    } else {
+        return;
+    };
+
+    for mut setting in &mut settings {
+        setting.adjust_cell_size(steps);
+        info!("ASCII Cell Size: {:.1}x{:.1}", setting.cell_size.x, setting.cell_size.y);
     }
 }
 
@@ -653,30 +1004,31 @@ pub fn toggle_ascii_monochrome(
 ) {
     if keyboard.just_pressed(KeyCode::F2) {
         for mut setting in &mut settings {
-            let new_mono = if setting.monochrome > 0.5 { 0.0 } else { 1.0 };
-            setting.monochrome = new_mono;
-            info!("Monochrome: {}", if new_mono > 0.5 { "ON" } else { "OFF" });
+            let enable = setting.monochrome < 0.5;
+            *setting = setting.with_monochrome(enable);
+            info!("Monochrome: {}", if enable { "ON" } else { "OFF" });
         }
     }
 }
 
-/// System to toggle per-object pattern mode with F3 key
+/// System to cycle per-object pattern filter (All / Enemies Only / Off) with F3 key
 pub fn toggle_per_object_mode(
     keyboard: Res<ButtonInput<KeyCode>>,
+    mut filter: ResMut<PerObjectFilter>,
+    quality: Res<RenderQuality>,
     mut settings: Query<&mut AsciiSettings>,
 ) {
     if keyboard.just_pressed(KeyCode::F3) {
+        if !quality.pattern_pass_enabled() {
+            info!("Per-Object Patterns: unavailable in low-spec mode");
+            return;
+        }
+
+        *filter = filter.next();
+        info!("Per-Object Patterns: {}", filter.name());
+
         for mut setting in &mut settings {
-            let new_mode = if setting.per_object_mode > 0.5 {
-                0.0
-            } else {
-                1.0
-            };
-            setting.per_object_mode = new_mode;
-            info!(
-                "Per-Object Patterns: {}",
-                if new_mode > 0.5 { "ON" } else { "OFF" }
-            );
+            setting.apply_per_object_filter(*filter);
         }
     }
 }
@@ -689,7 +1041,7 @@ pub fn cycle_global_pattern(
     if keyboard.just_pressed(KeyCode::F4) {
         for mut setting in &mut settings {
             let current = setting.global_pattern as u32;
-            let next = (current + 1) % 6;
+            let next = (current + 1) % 7;
             setting.global_pattern = next as f32;
             let name = match next {
                 0 => "Standard",
@@ -698,6 +1050,7 @@ pub fn cycle_global_pattern(
                 3 => "Binary",
                 4 => "Matrix Cycle",
                 5 => "Matrix Fall",
+                6 => "Custom",
                 _ => "Unknown",
             };
             info!("Global Pattern: {} ({})", next, name);
@@ -705,6 +1058,57 @@ pub fn cycle_global_pattern(
     }
 }
 
+/// Edge strength F9 switches on
+pub const DEFAULT_EDGE_STRENGTH: f32 = 0.6;
+
+/// System to toggle edge outlines with F9 key
+pub fn toggle_edge_outlines(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: Query<&mut AsciiSettings>,
+) {
+    if keyboard.just_pressed(KeyCode::F9) {
+        for mut setting in &mut settings {
+            let strength = if setting.edge_strength > 0.0 { 0.0 } else { DEFAULT_EDGE_STRENGTH };
+            *setting = setting.with_edge_strength(strength);
+            info!("Edge Outlines: {}", if setting.edge_strength > 0.0 { "ON" } else { "OFF" });
+        }
+    }
+}
+
+/// Scanline intensity F10 switches on
+pub const DEFAULT_SCANLINE_INTENSITY: f32 = 0.3;
+/// Curvature F10 switches on - subtle, so the screen corners stay readable
+pub const DEFAULT_CURVATURE: f32 = 0.1;
+
+/// System to toggle the CRT scanline/curvature overlay with F10 key
+pub fn toggle_crt_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut settings: Query<&mut AsciiSettings>,
+) {
+    if keyboard.just_pressed(KeyCode::F10) {
+        for mut setting in &mut settings {
+            let enable = !setting.crt_enabled();
+            *setting = if enable {
+                setting.with_crt(DEFAULT_SCANLINE_INTENSITY, DEFAULT_CURVATURE)
+            } else {
+                setting.with_crt(0.0, 0.0)
+            };
+            info!("CRT Overlay: {}", if enable { "ON" } else { "OFF" });
+        }
+    }
+}
+
+/// System to toggle low-spec rendering with F5 key
+pub fn cycle_render_quality(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut quality: ResMut<RenderQuality>,
+) {
+    if keyboard.just_pressed(KeyCode::F5) {
+        *quality = quality.next();
+        info!("Render Quality: {}", quality.name());
+    }
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -712,6 +1116,7 @@ pub fn cycle_global_pattern(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::*;
 
     #[test]
     fn test_ascii_settings_default() {
@@ -724,20 +1129,270 @@ mod tests {
 
     #[test]
     fn test_ascii_settings_monochrome() {
-        let settings = AsciiSettings::monochrome();
+        assert_eq!(AsciiSettings::default().with_monochrome(true).monochrome, 1.0);
+        assert_eq!(AsciiSettings::default().with_monochrome(false).monochrome, 0.0);
+    }
+
+    #[test]
+    fn test_ascii_settings_mono_color() {
+        assert_eq!(AsciiSettings::default().mono_color, Vec3::new(0.0, 1.0, 0.3));
+
+        let amber = AsciiSettings {
+            mono_color: Vec3::new(1.0, 0.6, 0.0),
+            ..default()
+        }
+        .with_monochrome(true);
+        assert_eq!(amber.monochrome, 1.0);
+        assert_eq!(amber.mono_color, Vec3::new(1.0, 0.6, 0.0));
+
+        // The uniform carries the tint right after the monochrome flag (vec3 aligned to 16 bytes)
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&amber).unwrap();
+        let bytes = buffer.into_inner();
+        let rgb: Vec<f32> = bytes[32..44]
+            .chunks(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(rgb, vec![1.0, 0.6, 0.0]);
+    }
+
+    #[test]
+    fn test_ascii_settings_edge_strength() {
+        assert_eq!(AsciiSettings::default().edge_strength, 0.0);
+        assert_eq!(AsciiSettings::default().with_edge_strength(0.4).edge_strength, 0.4);
+        assert_eq!(AsciiSettings::default().with_edge_strength(3.0).edge_strength, 1.0);
+    }
+
+    #[test]
+    fn test_pattern_camera_follows_main_camera_fov() {
+        let mut app = test_app();
+        app.add_systems(Update, sync_pattern_camera_transform);
+        let main = app
+            .world_mut()
+            .spawn((
+                Camera3d::default(),
+                Projection::Perspective(PerspectiveProjection {
+                    fov: 100.0_f32.to_radians(),
+                    aspect_ratio: 16.0 / 9.0,
+                    ..default()
+                }),
+                GlobalTransform::default(),
+            ))
+            .id();
+        let pattern = app
+            .world_mut()
+            .spawn((Camera3d::default(), Projection::default(), Transform::default(), PatternCamera))
+            .id();
+
+        let perspective = |app: &App, entity: Entity| match app.world().get::<Projection>(entity).unwrap() {
+            Projection::Perspective(perspective) => (perspective.fov, perspective.aspect_ratio),
+            _ => panic!("expected a perspective projection"),
+        };
+
+        app.update();
+        assert_eq!(perspective(&app, pattern), (100.0_f32.to_radians(), 16.0 / 9.0));
+
+        // Zoom the main camera - the pattern camera follows next frame
+        if let Projection::Perspective(projection) =
+            &mut *app.world_mut().get_mut::<Projection>(main).unwrap()
+        {
+            projection.fov = 55.0_f32.to_radians();
+        }
+        app.update();
+        assert_eq!(perspective(&app, pattern), perspective(&app, main));
+        assert_eq!(perspective(&app, pattern).0, 55.0_f32.to_radians());
+    }
+
+    #[test]
+    fn test_animation_time_advances_and_is_uploaded() {
+        let mut app = test_app();
+        app.with_frame_time(Duration::from_millis(250))
+            .add_systems(Update, update_ascii_time);
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+
+        app.update();
+        let first = app.world().get::<AsciiSettings>(camera).unwrap().time;
+        app.update();
+        app.update();
+        let settings = *app.world().get::<AsciiSettings>(camera).unwrap();
+        assert!((settings.time - first - 0.5).abs() < 0.001);
+
+        // Uploaded right after global_pattern, where the shader reads it
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&settings).unwrap();
+        let bytes = buffer.into_inner();
+        assert_eq!(f32::from_le_bytes(bytes[56..60].try_into().unwrap()), settings.time);
+    }
+
+    #[test]
+    fn test_ascii_settings_crt() {
+        let settings = AsciiSettings::default();
+        assert_eq!((settings.scanline_intensity, settings.curvature), (0.0, 0.0));
+        assert!(!settings.crt_enabled());
+
+        let crt = AsciiSettings::default().with_crt(0.5, 2.0);
+        assert_eq!((crt.scanline_intensity, crt.curvature), (0.5, 1.0));
+
+        // Both land in the uniform right after edge_strength
+        let mut buffer = encase::UniformBuffer::new(Vec::<u8>::new());
+        buffer.write(&crt).unwrap();
+        let bytes = buffer.into_inner();
+        let values: Vec<f32> = bytes[64..72]
+            .chunks(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect();
+        assert_eq!(values, vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_f10_toggles_crt_overlay() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, toggle_crt_overlay);
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+
+        let press_f10 = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            keyboard.release(KeyCode::F10);
+            keyboard.press(KeyCode::F10);
+            app.update();
+        };
+
+        press_f10(&mut app);
+        let settings = app.world().get::<AsciiSettings>(camera).unwrap();
+        assert_eq!(settings.scanline_intensity, DEFAULT_SCANLINE_INTENSITY);
+        assert_eq!(settings.curvature, DEFAULT_CURVATURE);
+
+        press_f10(&mut app);
+        assert!(!app.world().get::<AsciiSettings>(camera).unwrap().crt_enabled());
+    }
+
+    #[test]
+    fn test_f9_toggles_edge_outlines() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, toggle_edge_outlines);
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+
+        let press_f9 = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            keyboard.release(KeyCode::F9);
+            keyboard.press(KeyCode::F9);
+            app.update();
+        };
+
+        press_f9(&mut app);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().edge_strength, DEFAULT_EDGE_STRENGTH);
+
+        press_f9(&mut app);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().edge_strength, 0.0);
+    }
+
+    #[test]
+    fn test_ascii_settings_with_preset() {
+        let settings = AsciiSettings::default().with_preset(AsciiPreset::Chunky);
+        assert_eq!(settings.cell_size, Vec2::new(12.0, 20.0));
+
+        // Default preset matches default settings
+        let settings = AsciiSettings::default().with_preset(AsciiPreset::default());
+        assert_eq!(settings.cell_size, AsciiSettings::default().cell_size);
+    }
+
+    #[test]
+    fn test_ascii_settings_terminal() {
+        let settings = AsciiSettings::terminal();
+        assert_eq!(settings.cell_size, Vec2::new(12.0, 20.0)); // Chunky cells
         assert_eq!(settings.monochrome, 1.0);
+        assert_eq!(settings.mono_color, Vec3::new(0.0, 1.0, 0.3)); // Terminal green
+        assert_eq!(settings.global_pattern, AsciiPattern::Standard.as_id() as f32);
+        assert_eq!(settings.per_object_mode, 0.0);
+
+        // Leaving the terminal preset turns monochrome back off
+        let settings = settings.with_preset(AsciiPreset::Chunky);
+        assert_eq!(settings.monochrome, 0.0);
     }
 
     #[test]
-    fn test_ascii_settings_per_object() {
-        let settings = AsciiSettings::default().with_per_object_patterns();
-        assert_eq!(settings.per_object_mode, 1.0);
+    fn test_preset_prev_undoes_next() {
+        for preset in [
+            AsciiPreset::Ultra,
+            AsciiPreset::HighRes,
+            AsciiPreset::Classic,
+            AsciiPreset::Chunky,
+            AsciiPreset::Terminal,
+        ] {
+            assert_eq!(preset.next().prev(), preset);
+        }
+    }
+
+    #[test]
+    fn test_f2_toggles_monochrome() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, toggle_ascii_monochrome);
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+
+        let press_f2 = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            keyboard.release(KeyCode::F2);
+            keyboard.press(KeyCode::F2);
+            app.update();
+        };
+
+        press_f2(&mut app);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().monochrome, 1.0);
+
+        press_f2(&mut app);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().monochrome, 0.0);
     }
 
     #[test]
-    fn test_ascii_settings_custom_cell_size() {
-        let settings = AsciiSettings::new(10.0, 16.0);
-        assert_eq!(settings.cell_size, Vec2::new(10.0, 16.0));
+    fn test_equal_key_grows_cell_size() {
+        let mut app = test_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, adjust_cell_size);
+        let camera = app.world_mut().spawn(AsciiSettings::default()).id();
+
+        let press = |app: &mut App, key: KeyCode| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            keyboard.release_all();
+            keyboard.press(key);
+            app.update();
+        };
+
+        let before = app.world().get::<AsciiSettings>(camera).unwrap().cell_size;
+        press(&mut app, KeyCode::Equal);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().cell_size, before + CELL_SIZE_STEP);
+
+        press(&mut app, KeyCode::Minus);
+        assert_eq!(app.world().get::<AsciiSettings>(camera).unwrap().cell_size, before);
+    }
+
+    #[test]
+    fn test_cell_size_adjustment_is_clamped() {
+        let mut settings = AsciiSettings::default();
+        settings.adjust_cell_size(-100.0);
+        assert_eq!(settings.cell_size, MIN_CELL_SIZE);
+        settings.adjust_cell_size(100.0);
+        assert_eq!(settings.cell_size, MAX_CELL_SIZE);
+    }
+
+    #[test]
+    fn test_aligned_cell_size_tiles_screen() {
+        let mut settings = AsciiSettings {
+            resolution: Vec2::new(1280.0, 720.0),
+            ..default()
+        };
+        settings.adjust_cell_size(1.0); // 5.5 x 9.9
+
+        // Stretched to whole cells: 232 columns, 72 rows
+        let aligned = settings.aligned_cell_size();
+        assert!((settings.resolution / aligned).abs_diff_eq(Vec2::new(232.0, 72.0), 1e-3));
+        assert!(aligned.cmpge(settings.cell_size).all());
     }
 
     #[test]
@@ -755,4 +1410,106 @@ mod tests {
         assert_eq!(AsciiPatternId::slashes().pattern, AsciiPattern::Slashes);
         assert_eq!(AsciiPatternId::binary().pattern, AsciiPattern::Binary);
     }
+
+    #[test]
+    fn test_ascii_pattern_id_category() {
+        assert_eq!(AsciiPatternId::blocks().category, AsciiCategory::World);
+
+        let enemy = AsciiPatternId::binary().with_category(AsciiCategory::Enemy);
+        assert_eq!(enemy.pattern, AsciiPattern::Binary);
+        assert_eq!(enemy.category, AsciiCategory::Enemy);
+    }
+
+    /// Whether objects of this category currently render with their own pattern
+    fn uses_per_object_pattern(settings: &AsciiSettings, category: AsciiCategory) -> bool {
+        settings.per_object_mode > 0.5 && (settings.per_object_mask as u32) & category.bit() != 0
+    }
+
+    #[test]
+    fn test_force_color_reaches_pattern_material() {
+        assert!(!AsciiPatternId::blocks().force_color);
+
+        let mut app = test_app();
+        app.init_resource::<Assets<PatternIdMaterial>>()
+            .insert_resource(RenderQuality::High)
+            .add_systems(Update, sync_pattern_meshes);
+        let colored = app
+            .world_mut()
+            .spawn((
+                Mesh3d(Handle::default()),
+                GlobalTransform::default(),
+                AsciiPatternId::blocks().with_category(AsciiCategory::Effect).with_force_color(),
+            ))
+            .id();
+        let plain = app
+            .world_mut()
+            .spawn((Mesh3d(Handle::default()), GlobalTransform::default(), AsciiPatternId::blocks()))
+            .id();
+        app.update();
+
+        let world = app.world_mut();
+        let pattern_meshes: Vec<_> = world
+            .query::<(&PatternMesh, &MeshMaterial3d<PatternIdMaterial>)>()
+            .iter(world)
+            .map(|(mesh, material)| (mesh.source, material.0.clone()))
+            .collect();
+        let materials = app.world().resource::<Assets<PatternIdMaterial>>();
+        // (force_color, category) uniforms of the pattern material mirroring `source`
+        let uniforms = |source: Entity| {
+            let (_, handle) = pattern_meshes.iter().find(|(mesh_source, _)| *mesh_source == source).unwrap();
+            let material = materials.get(handle).unwrap();
+            (material.force_color, material.category)
+        };
+
+        assert_eq!(uniforms(colored), (1.0, AsciiCategory::Effect.as_id() as f32));
+        assert_eq!(uniforms(plain), (0.0, AsciiCategory::World.as_id() as f32));
+    }
+
+    #[test]
+    fn test_per_object_filter_all_is_default() {
+        let settings = AsciiSettings::default();
+        assert!(uses_per_object_pattern(&settings, AsciiCategory::World));
+        assert!(uses_per_object_pattern(&settings, AsciiCategory::Enemy));
+        assert!(uses_per_object_pattern(&settings, AsciiCategory::Effect));
+    }
+
+    #[test]
+    fn test_per_object_filter_enemies_only() {
+        let mut settings = AsciiSettings::default();
+        settings.apply_per_object_filter(PerObjectFilter::EnemiesOnly);
+
+        assert_eq!(settings.per_object_mode, 1.0);
+        assert!(uses_per_object_pattern(&settings, AsciiCategory::Enemy));
+        assert!(!uses_per_object_pattern(&settings, AsciiCategory::World));
+        assert!(!uses_per_object_pattern(&settings, AsciiCategory::Effect));
+    }
+
+    #[test]
+    fn test_per_object_filter_off() {
+        let mut settings = AsciiSettings::default();
+        settings.apply_per_object_filter(PerObjectFilter::Off);
+
+        assert_eq!(settings.per_object_mode, 0.0);
+        assert!(!uses_per_object_pattern(&settings, AsciiCategory::Enemy));
+    }
+
+    #[test]
+    fn test_per_object_filter_cycle() {
+        assert_eq!(PerObjectFilter::All.next(), PerObjectFilter::EnemiesOnly);
+        assert_eq!(PerObjectFilter::EnemiesOnly.next(), PerObjectFilter::Off);
+        assert_eq!(PerObjectFilter::Off.next(), PerObjectFilter::All);
+    }
+
+    #[test]
+    fn test_render_quality_default_is_high() {
+        assert_eq!(RenderQuality::default(), RenderQuality::High);
+        assert!(RenderQuality::High.pattern_pass_enabled());
+    }
+
+    #[test]
+    fn test_render_quality_low_disables_pattern_pass() {
+        assert!(!RenderQuality::Low.pattern_pass_enabled());
+        assert_eq!(RenderQuality::High.next(), RenderQuality::Low);
+        assert_eq!(RenderQuality::Low.next(), RenderQuality::High);
+    }
 }
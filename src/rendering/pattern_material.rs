@@ -1,5 +1,6 @@
 // Custom material for rendering pattern IDs to a texture
-// This material outputs the pattern ID in the red channel
+// This material outputs the pattern ID in the red channel, the object category in green
+// and the force-color flag in blue
 
 use bevy::{
     prelude::*,
@@ -12,6 +13,12 @@ use bevy::{
 pub struct PatternIdMaterial {
     #[uniform(0)]
     pub pattern_id: f32,
+    /// Object category ID (see AsciiCategory) - lets the ASCII shader filter by category
+    #[uniform(0)]
+    pub category: f32,
+    /// 1.0 = keep this object's colors even in monochrome mode (see AsciiPatternId::force_color)
+    #[uniform(0)]
+    pub force_color: f32,
 }
 
 impl Material for PatternIdMaterial {
@@ -0,0 +1,184 @@
+// Export the ASCII frame as plain text (F12)
+// Screenshots the final frame, then picks each cell's character on the CPU with the shader's ramp logic
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+
+use super::custom_ramp::ramp_text_from_uniform;
+use super::{AsciiPattern, AsciiSettings};
+
+/// Plain-text stand-in for each pattern's glyphs, darkest to brightest
+pub fn pattern_ramp(settings: &AsciiSettings) -> Vec<char> {
+    let ramp = match settings.global_pattern as u8 {
+        id if id == AsciiPattern::Blocks.as_id() => " .:+=%O#@@".to_string(),
+        id if id == AsciiPattern::Slashes.as_id() => " ./\\x=+#X@".to_string(),
+        // Binary and both Matrix patterns draw digits
+        id if (AsciiPattern::Binary.as_id()..=AsciiPattern::MatrixFall.as_id()).contains(&id) => {
+            " !0123458#".to_string()
+        }
+        id if id == AsciiPattern::Custom.as_id() => {
+            ramp_text_from_uniform(settings.custom_ramp_len, &settings.custom_ramp)
+        }
+        _ => " .:-=+*#%@".to_string(),
+    };
+    ramp.chars().collect()
+}
+
+/// Character for a cell of this scene brightness - same curve and ramp spread as ascii.wgsl
+pub fn ramp_glyph(brightness: f32, ramp: &[char]) -> char {
+    let Some(&darkest) = ramp.first() else {
+        return ' ';
+    };
+
+    // Gamma lift, then one of 10 levels
+    let boosted = brightness.max(0.0).powf(0.7);
+    let level = (boosted * 10.0).clamp(0.0, 9.0) as usize;
+    // Spread the 10 levels over however many glyphs the ramp has
+    let index = (level * ramp.len() / 9).min(ramp.len() - 1);
+    ramp.get(index).copied().unwrap_or(darkest)
+}
+
+fn luminance(color: LinearRgba) -> f32 {
+    0.299 * color.red + 0.587 * color.green + 0.114 * color.blue
+}
+
+/// Turn a captured ASCII frame back into text, one line per cell row.
+/// Character pixels are drawn at a fixed gain over the scene color, so each cell's
+/// brightest pixel recovers the brightness the shader picked its glyph from.
+pub fn frame_to_text(image: &Image, settings: &AsciiSettings, ramp: &[char]) -> String {
+    let size = image.size();
+    // Screenshots are in physical pixels, cell sizes in logical ones
+    let scale = size.x as f32 / settings.resolution.x.max(1.0);
+    let cell = (settings.aligned_cell_size() * scale).max(Vec2::ONE);
+    let gain = if settings.monochrome > 0.5 {
+        1.5 * luminance(LinearRgba::rgb(settings.mono_color.x, settings.mono_color.y, settings.mono_color.z))
+    } else {
+        2.0
+    }
+    .max(0.001);
+
+    let columns = (size.x as f32 / cell.x) as u32;
+    let rows = (size.y as f32 / cell.y) as u32;
+    let mut text = String::with_capacity(((columns + 1) * rows) as usize);
+
+    for row in 0..rows {
+        let (y0, y1) = ((row as f32 * cell.y) as u32, (((row + 1) as f32 * cell.y) as u32).min(size.y));
+        for column in 0..columns {
+            let (x0, x1) = ((column as f32 * cell.x) as u32, (((column + 1) as f32 * cell.x) as u32).min(size.x));
+
+            let mut brightest = 0.0f32;
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    if let Ok(color) = image.get_color_at(x, y) {
+                        brightest = brightest.max(luminance(color.to_linear()));
+                    }
+                }
+            }
+            text.push(ramp_glyph(brightest / gain, ramp));
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/// System to save the current ASCII frame as a .txt file with F12 key
+pub fn export_ascii_frame(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    settings: Query<&AsciiSettings>,
+) {
+    if !keyboard.just_pressed(KeyCode::F12) {
+        return;
+    }
+    let Some(&settings) = settings.iter().next() else {
+        return;
+    };
+
+    // The GPU readback is async - the observer fires once the frame has been copied back
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(move |trigger: Trigger<ScreenshotCaptured>| {
+            let text = frame_to_text(&trigger.event().0, &settings, &pattern_ramp(&settings));
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or_default();
+            let path = format!("ascii_frame_{timestamp}.txt");
+
+            match std::fs::write(&path, text) {
+                Ok(()) => info!("ASCII frame saved to {path}"),
+                Err(err) => warn!("Failed to save ASCII frame to {path}: {err}"),
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::render::render_asset::RenderAssetUsages;
+    use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+
+    const STANDARD: &str = " .:-=+*#%@";
+
+    fn standard() -> Vec<char> {
+        STANDARD.chars().collect()
+    }
+
+    #[test]
+    fn test_ramp_glyph_ends() {
+        assert_eq!(ramp_glyph(0.0, &standard()), ' ');
+        assert_eq!(ramp_glyph(1.0, &standard()), '@');
+        // Out-of-range input stays on the ramp
+        assert_eq!(ramp_glyph(-1.0, &standard()), ' ');
+        assert_eq!(ramp_glyph(5.0, &standard()), '@');
+    }
+
+    #[test]
+    fn test_ramp_glyph_matches_shader_levels() {
+        // Level = floor(brightness^0.7 * 10)
+        let brightness_for_level = |level: f32| ((level + 0.5) / 10.0).powf(1.0 / 0.7);
+        for (level, expected) in STANDARD.chars().enumerate() {
+            assert_eq!(ramp_glyph(brightness_for_level(level as f32), &standard()), expected);
+        }
+    }
+
+    #[test]
+    fn test_ramp_glyph_spreads_short_ramps() {
+        let ramp: Vec<char> = " #".chars().collect();
+        assert_eq!(ramp_glyph(0.01, &ramp), ' ');
+        assert_eq!(ramp_glyph(0.9, &ramp), '#');
+    }
+
+    #[test]
+    fn test_pattern_ramp_uses_custom_ramp() {
+        let settings = AsciiSettings::default().with_custom_ramp(" .:oO#@");
+        assert_eq!(pattern_ramp(&settings), " .:oO#@".chars().collect::<Vec<_>>());
+        assert_eq!(pattern_ramp(&AsciiSettings::default()), standard());
+    }
+
+    #[test]
+    fn test_frame_to_text_grid() {
+        // 4x2 cells of 5x9 pixels: left half black, right half full-bright characters
+        let settings = AsciiSettings {
+            resolution: Vec2::new(20.0, 18.0),
+            ..default()
+        };
+        let mut image = Image::new_fill(
+            Extent3d { width: 20, height: 18, depth_or_array_layers: 1 },
+            TextureDimension::D2,
+            &[0, 0, 0, 255],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        for y in 0..18 {
+            for x in 10..20 {
+                image.set_color_at(x, y, Color::WHITE).unwrap();
+            }
+        }
+
+        // White characters are twice the scene color clipped to 1.0, i.e. brightness 0.5
+        assert_eq!(frame_to_text(&image, &settings, &standard()), "  **\n  **\n");
+    }
+}
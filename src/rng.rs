@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+/// Shared gameplay random number generator (xorshift64*).
+/// Small and dependency-free - not for anything security related.
+#[derive(Resource)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    /// Create a generator with a fixed seed (same seed = same sequence)
+    pub fn new(seed: u64) -> Self {
+        // Zero state would get stuck at zero
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Random float in [0, 1)
+    pub fn f32(&mut self) -> f32 {
+        // Top 24 bits fit exactly in an f32 mantissa
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Random float in [min, max)
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.f32()
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        // Seed from the clock so each run plays out differently
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x9E37_79B9_7F4A_7C15);
+        Self::new(seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = GameRng::new(42);
+        let mut b = GameRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_f32_in_unit_range() {
+        let mut rng = GameRng::new(7);
+        for _ in 0..10_000 {
+            let value = rng.f32();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        let mut rng = GameRng::new(0);
+        for _ in 0..10_000 {
+            let value = rng.range(-2.0, 3.0);
+            assert!((-2.0..3.0).contains(&value));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Shared setup for headless app tests - a bare app plus the opt-in pieces many tests need
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+pub use std::time::Duration;
+
+use crate::player::InputMap;
+use crate::GameState;
+
+/// Headless app with Bevy's minimal plugins - tests add only the resources and systems they exercise
+pub fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app
+}
+
+/// Opt-in setup shared by many system tests
+pub trait TestAppExt {
+    /// Advance time by `step` every update (the first update still has no delta)
+    fn with_frame_time(&mut self, step: Duration) -> &mut Self;
+    /// Default bindings and keyboard/mouse state, for systems reading `ActionInput`
+    fn with_input(&mut self) -> &mut Self;
+    /// Mesh and material storage, for systems that spawn visuals
+    fn with_mesh_assets(&mut self) -> &mut Self;
+    /// `GameState`, starting in `state`
+    fn with_game_state(&mut self, state: GameState) -> &mut Self;
+}
+
+impl TestAppExt for App {
+    fn with_frame_time(&mut self, step: Duration) -> &mut Self {
+        self.insert_resource(TimeUpdateStrategy::ManualDuration(step))
+    }
+
+    fn with_input(&mut self) -> &mut Self {
+        self.init_resource::<InputMap>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+    }
+
+    fn with_mesh_assets(&mut self) -> &mut Self {
+        self.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+    }
+
+    fn with_game_state(&mut self, state: GameState) -> &mut Self {
+        self.add_plugins(StatesPlugin).insert_state(state)
+    }
+}